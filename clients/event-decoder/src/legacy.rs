@@ -0,0 +1,161 @@
+//! Layouts of the events emitted before the envelope was introduced,
+//! as a bare Borsh-serialized enum.
+//!
+//! Only the accounts embedded in these events changed layout since, the other types are
+//! re-exported from the program.
+use anchor_lang::prelude::borsh::{self, BorshDeserialize, BorshSerialize};
+use anchor_lang::prelude::Pubkey;
+
+pub use squads_smart_account_program::{SmartAccountCompiledInstruction, SmartAccountSigner};
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum SmartAccountEvent {
+    CreateSmartAccountEvent(CreateSmartAccountEvent),
+    SynchronousTransactionEvent(SynchronousTransactionEvent),
+    SynchronousSettingsTransactionEvent(SynchronousSettingsTransactionEvent),
+    AddSpendingLimitEvent(AddSpendingLimitEvent),
+    RemoveSpendingLimitEvent(RemoveSpendingLimitEvent),
+    UseSpendingLimitEvent(UseSpendingLimitEvent),
+    AuthoritySettingsEvent(AuthoritySettingsEvent),
+    AuthorityChangeEvent(AuthorityChangeEvent),
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Settings {
+    pub seed: u128,
+    pub settings_authority: Pubkey,
+    pub threshold: u16,
+    pub time_lock: u32,
+    pub transaction_index: u64,
+    pub stale_transaction_index: u64,
+    pub archival_authority: Option<Pubkey>,
+    pub archivable_after: u64,
+    pub bump: u8,
+    pub signers: Vec<SmartAccountSigner>,
+    pub account_utilization: u8,
+    pub _reserved1: u8,
+    pub _reserved2: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum Period {
+    OneTime,
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct SpendingLimit {
+    pub settings: Pubkey,
+    pub seed: Pubkey,
+    pub account_index: u8,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub period: Period,
+    pub remaining_amount: u64,
+    pub last_reset: i64,
+    pub bump: u8,
+    pub signers: Vec<Pubkey>,
+    pub destinations: Vec<Pubkey>,
+    pub expiration: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum SettingsAction {
+    AddSigner {
+        new_signer: SmartAccountSigner,
+    },
+    RemoveSigner {
+        old_signer: Pubkey,
+    },
+    ChangeThreshold {
+        new_threshold: u16,
+    },
+    SetTimeLock {
+        new_time_lock: u32,
+    },
+    AddSpendingLimit {
+        seed: Pubkey,
+        account_index: u8,
+        mint: Pubkey,
+        amount: u64,
+        period: Period,
+        signers: Vec<Pubkey>,
+        destinations: Vec<Pubkey>,
+        expiration: i64,
+    },
+    RemoveSpendingLimit {
+        spending_limit: Pubkey,
+    },
+    SetArchivalAuthority {
+        new_archival_authority: Option<Pubkey>,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct CreateSmartAccountEvent {
+    pub new_settings_pubkey: Pubkey,
+    pub new_settings_content: Settings,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct SynchronousTransactionEvent {
+    pub settings_pubkey: Pubkey,
+    pub account_index: u8,
+    pub signers: Vec<Pubkey>,
+    pub instructions: Vec<SmartAccountCompiledInstruction>,
+    pub instruction_accounts: Vec<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct SynchronousSettingsTransactionEvent {
+    pub settings_pubkey: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub settings: Settings,
+    pub changes: Vec<SettingsAction>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct AddSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub spending_limit: SpendingLimit,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct RemoveSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UseSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub smart_account: Pubkey,
+    pub smart_account_token_account: Pubkey,
+    pub destination: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub signer: Pubkey,
+    pub mint: Pubkey,
+    pub mint_decimals: u8,
+    pub amount: u64,
+    pub spending_limit: SpendingLimit,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct AuthoritySettingsEvent {
+    pub settings: Settings,
+    pub settings_pubkey: Pubkey,
+    pub authority: Pubkey,
+    pub change: SettingsAction,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct AuthorityChangeEvent {
+    pub settings: Settings,
+    pub settings_pubkey: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+}
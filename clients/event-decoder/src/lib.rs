@@ -0,0 +1,387 @@
+//! Off-chain decoder for the events the Squads Smart Account Program logs through its
+//! `log_event` instruction.
+//!
+//! Handles every event format the program has emitted:
+//! - version `0`: bare Borsh-serialized `SmartAccountEvent`, emitted before the envelope was
+//!   introduced.
+//! - version `1`: `SmartAccountEventEnvelope` with the inner event struct as the payload.
+//!
+//! The layouts of the legacy events are frozen in the `legacy` module. When
+//! `EVENT_SCHEMA_VERSION` is bumped, the types whose layout changes must be copied to a new
+//! module for the previous version before being changed.
+use anchor_lang::prelude::borsh::BorshDeserialize;
+use anchor_lang::Discriminator;
+use squads_smart_account_program::{
+    LogEventArgs, SmartAccountEvent, SmartAccountEventEnvelope, SmartAccountEventKind,
+    EVENT_ENVELOPE_MARKER, EVENT_SCHEMA_VERSION,
+};
+use std::io;
+
+pub mod legacy;
+
+/// Schema version assigned to events emitted before the envelope was introduced.
+pub const LEGACY_EVENT_SCHEMA_VERSION: u8 = 0;
+
+/// An event decoded with the layouts of the schema version it was emitted with.
+#[allow(clippy::large_enum_variant)]
+pub enum VersionedSmartAccountEvent {
+    Legacy(legacy::SmartAccountEvent),
+    /// Event of the current `EVENT_SCHEMA_VERSION`.
+    Current(SmartAccountEvent),
+}
+
+impl VersionedSmartAccountEvent {
+    pub fn kind(&self) -> SmartAccountEventKind {
+        match self {
+            Self::Legacy(event) => match event {
+                legacy::SmartAccountEvent::CreateSmartAccountEvent(_) => {
+                    SmartAccountEventKind::CreateSmartAccount
+                }
+                legacy::SmartAccountEvent::SynchronousTransactionEvent(_) => {
+                    SmartAccountEventKind::SynchronousTransaction
+                }
+                legacy::SmartAccountEvent::SynchronousSettingsTransactionEvent(_) => {
+                    SmartAccountEventKind::SynchronousSettingsTransaction
+                }
+                legacy::SmartAccountEvent::AddSpendingLimitEvent(_) => {
+                    SmartAccountEventKind::AddSpendingLimit
+                }
+                legacy::SmartAccountEvent::RemoveSpendingLimitEvent(_) => {
+                    SmartAccountEventKind::RemoveSpendingLimit
+                }
+                legacy::SmartAccountEvent::UseSpendingLimitEvent(_) => {
+                    SmartAccountEventKind::UseSpendingLimit
+                }
+                legacy::SmartAccountEvent::AuthoritySettingsEvent(_) => {
+                    SmartAccountEventKind::AuthoritySettings
+                }
+                legacy::SmartAccountEvent::AuthorityChangeEvent(_) => {
+                    SmartAccountEventKind::AuthorityChange
+                }
+            },
+            Self::Current(event) => event.kind(),
+        }
+    }
+}
+
+pub struct DecodedSmartAccountEvent {
+    /// Schema version the event was emitted with.
+    pub version: u8,
+    /// Position of the event among all events logged for the settings.
+    /// `None` for legacy events and for events logged without the `EventSequence` of the settings.
+    pub sequence: Option<u64>,
+    pub event: VersionedSmartAccountEvent,
+}
+
+/// Decodes the `event` bytes of `LogEventArgs`.
+pub fn decode_event(data: &[u8]) -> io::Result<DecodedSmartAccountEvent> {
+    match data.first() {
+        Some(&EVENT_ENVELOPE_MARKER) => {
+            let envelope = SmartAccountEventEnvelope::from_bytes(data)?;
+            decode_envelope(&envelope)
+        }
+        Some(_) => Ok(DecodedSmartAccountEvent {
+            version: LEGACY_EVENT_SCHEMA_VERSION,
+            sequence: None,
+            event: VersionedSmartAccountEvent::Legacy(legacy::SmartAccountEvent::try_from_slice(
+                data,
+            )?),
+        }),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "empty event data",
+        )),
+    }
+}
+
+/// Decodes the payload of an already parsed envelope.
+pub fn decode_envelope(
+    envelope: &SmartAccountEventEnvelope,
+) -> io::Result<DecodedSmartAccountEvent> {
+    let kind = SmartAccountEventKind::try_from(envelope.kind)?;
+    let event = match envelope.version {
+        EVENT_SCHEMA_VERSION => VersionedSmartAccountEvent::Current(
+            SmartAccountEvent::from_payload(kind, &envelope.payload)?,
+        ),
+        version => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported event schema version {}", version),
+            ))
+        }
+    };
+
+    Ok(DecodedSmartAccountEvent {
+        version: envelope.version,
+        sequence: envelope.sequence,
+        event,
+    })
+}
+
+/// Decodes the data of a `log_event` instruction, including the instruction discriminator.
+pub fn decode_log_event_instruction(data: &[u8]) -> io::Result<DecodedSmartAccountEvent> {
+    let discriminator = squads_smart_account_program::instruction::LogEvent::DISCRIMINATOR;
+    let args = data
+        .strip_prefix(discriminator.as_slice())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a log_event instruction"))?;
+    let args = LogEventArgs::try_from_slice(args)?;
+    decode_event(&args.event)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::prelude::borsh::BorshSerialize;
+    use anchor_lang::prelude::Pubkey;
+    use squads_smart_account_program::{
+        AccountExtension, AddSpendingLimitEvent, CreateSmartAccountEvent, Period, Permissions,
+        RemoveSpendingLimitEvent, Settings, SettingsBuilder, SmartAccountSigner, SpendingLimit,
+        SpendingLimitExtension, SpendingLimitUpdate, TimeLockBypass, Transaction,
+        TransactionContent, TransactionEvent, TransactionEventType, TransactionSummary,
+        UpdateSpendingLimitEvent,
+    };
+
+    fn event() -> SmartAccountEvent {
+        SmartAccountEvent::RemoveSpendingLimitEvent(RemoveSpendingLimitEvent {
+            settings_pubkey: Pubkey::new_unique(),
+            spending_limit_pubkey: Pubkey::new_unique(),
+        })
+    }
+
+    fn current(decoded: DecodedSmartAccountEvent) -> SmartAccountEvent {
+        match decoded.event {
+            VersionedSmartAccountEvent::Current(event) => event,
+            _ => panic!("not an event of the current version"),
+        }
+    }
+
+    fn legacy_settings() -> legacy::Settings {
+        legacy::Settings {
+            seed: 1,
+            settings_authority: Pubkey::default(),
+            threshold: 1,
+            time_lock: 0,
+            transaction_index: 0,
+            stale_transaction_index: 0,
+            archival_authority: Some(Pubkey::default()),
+            archivable_after: 0,
+            bump: 255,
+            signers: vec![SmartAccountSigner {
+                key: Pubkey::new_unique(),
+                permissions: Permissions { mask: 7 },
+            }],
+            account_utilization: 0,
+            _reserved1: 0,
+            _reserved2: 0,
+        }
+    }
+
+    fn legacy_spending_limit() -> legacy::SpendingLimit {
+        legacy::SpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 0,
+            mint: Pubkey::default(),
+            amount: 100,
+            period: legacy::Period::Day,
+            remaining_amount: 100,
+            last_reset: 0,
+            bump: 255,
+            signers: vec![Pubkey::new_unique()],
+            destinations: vec![],
+            expiration: i64::MAX,
+        }
+    }
+
+    fn settings() -> Settings {
+        SettingsBuilder::new()
+            .signer(Pubkey::new_unique(), Permissions { mask: 7 })
+            .time_lock_bypass(TimeLockBypass::Disabled)
+            .build()
+    }
+
+    fn spending_limit() -> SpendingLimit {
+        SpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 0,
+            mint: Pubkey::default(),
+            amount: 100,
+            period: Period::Day,
+            remaining_amount: 100,
+            last_reset: 0,
+            bump: 255,
+            signers: vec![Pubkey::new_unique()],
+            destinations: vec![],
+            expiration: i64::MAX,
+            extension: AccountExtension::new(SpendingLimitExtension::default()),
+        }
+    }
+
+    #[test]
+    fn test_decode_legacy_event() {
+        let data = event().try_to_vec().unwrap();
+
+        let decoded = decode_event(&data).unwrap();
+
+        assert_eq!(decoded.version, LEGACY_EVENT_SCHEMA_VERSION);
+        assert_eq!(decoded.sequence, None);
+        assert_eq!(
+            decoded.event.kind(),
+            SmartAccountEventKind::RemoveSpendingLimit
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_events() {
+        let events = [
+            legacy::SmartAccountEvent::CreateSmartAccountEvent(legacy::CreateSmartAccountEvent {
+                new_settings_pubkey: Pubkey::new_unique(),
+                new_settings_content: legacy_settings(),
+            }),
+            legacy::SmartAccountEvent::AddSpendingLimitEvent(legacy::AddSpendingLimitEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                spending_limit_pubkey: Pubkey::new_unique(),
+                spending_limit: legacy_spending_limit(),
+            }),
+            legacy::SmartAccountEvent::SynchronousTransactionEvent(
+                legacy::SynchronousTransactionEvent {
+                    settings_pubkey: Pubkey::new_unique(),
+                    account_index: 0,
+                    signers: vec![Pubkey::new_unique()],
+                    instructions: vec![],
+                    instruction_accounts: vec![],
+                },
+            ),
+        ];
+
+        for event in events {
+            let data = event.try_to_vec().unwrap();
+
+            let decoded = decode_event(&data).unwrap();
+
+            assert_eq!(decoded.version, 0);
+            let VersionedSmartAccountEvent::Legacy(decoded) = decoded.event else {
+                panic!("not a legacy event");
+            };
+            assert_eq!(decoded.try_to_vec().unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_events_of_current_version() {
+        let events = [
+            SmartAccountEvent::CreateSmartAccountEvent(CreateSmartAccountEvent {
+                new_settings_pubkey: Pubkey::new_unique(),
+                new_settings_content: settings(),
+            }),
+            SmartAccountEvent::AddSpendingLimitEvent(AddSpendingLimitEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                spending_limit_pubkey: Pubkey::new_unique(),
+                spending_limit: spending_limit(),
+            }),
+            // Spending limits created before `SpendingLimitExtension` are logged without it.
+            SmartAccountEvent::UpdateSpendingLimitEvent(UpdateSpendingLimitEvent {
+                settings_pubkey: Pubkey::new_unique(),
+                spending_limit_pubkey: Pubkey::new_unique(),
+                update: SpendingLimitUpdate::default(),
+                memo: Some("memo".to_string()),
+                spending_limit: SpendingLimit::try_from_slice(
+                    &legacy_spending_limit().try_to_vec().unwrap(),
+                )
+                .unwrap(),
+            }),
+            SmartAccountEvent::TransactionEvent(TransactionEvent {
+                event_type: TransactionEventType::Create,
+                settings_pubkey: Pubkey::new_unique(),
+                transaction_pubkey: Pubkey::new_unique(),
+                transaction_index: 1,
+                signer: Some(Pubkey::new_unique()),
+                memo: None,
+                transaction_content: Some(TransactionContent::Transaction(
+                    TransactionSummary::new(&Transaction::default()).unwrap(),
+                )),
+            }),
+        ];
+
+        for event in events {
+            let data = SmartAccountEventEnvelope::new(&event, Some(3))
+                .unwrap()
+                .to_bytes()
+                .unwrap();
+
+            let decoded = decode_event(&data).unwrap();
+
+            assert_eq!(
+                (decoded.version, decoded.sequence),
+                (EVENT_SCHEMA_VERSION, Some(3))
+            );
+            assert_eq!(
+                current(decoded).try_to_vec().unwrap(),
+                event.try_to_vec().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_enveloped_event() {
+        let event = event();
+        let data = SmartAccountEventEnvelope::new(&event, Some(7))
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+
+        let decoded = decode_event(&data).unwrap();
+
+        assert_eq!(decoded.version, EVENT_SCHEMA_VERSION);
+        assert_eq!(decoded.sequence, Some(7));
+        assert_eq!(
+            decoded.event.kind(),
+            SmartAccountEventKind::RemoveSpendingLimit
+        );
+        assert_eq!(
+            current(decoded).try_to_vec().unwrap(),
+            event.try_to_vec().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_log_event_instruction() {
+        let args = LogEventArgs {
+            account_seeds: vec![],
+            bump: 255,
+            event: SmartAccountEventEnvelope::new(&event(), None)
+                .unwrap()
+                .to_bytes()
+                .unwrap(),
+        };
+        let mut data = squads_smart_account_program::instruction::LogEvent::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&args.try_to_vec().unwrap());
+
+        let decoded = decode_log_event_instruction(&data).unwrap();
+
+        assert_eq!(
+            decoded.event.kind(),
+            SmartAccountEventKind::RemoveSpendingLimit
+        );
+        assert!(decode_log_event_instruction(&data[1..]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_version_and_kind_are_rejected_but_skippable() {
+        let mut envelope = SmartAccountEventEnvelope::new(&event(), None).unwrap();
+        envelope.version = EVENT_SCHEMA_VERSION + 1;
+        let data = envelope.to_bytes().unwrap();
+
+        assert!(decode_event(&data).is_err());
+        // The header is still readable, so consumers can skip the event.
+        assert_eq!(
+            SmartAccountEventEnvelope::from_bytes(&data).unwrap(),
+            envelope
+        );
+
+        envelope.version = EVENT_SCHEMA_VERSION;
+        envelope.kind = u8::MAX;
+        assert!(decode_event(&envelope.to_bytes().unwrap()).is_err());
+    }
+}
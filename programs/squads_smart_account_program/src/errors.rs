@@ -90,7 +90,7 @@ pub enum SmartAccountError {
     InvalidInstructionArgs,
     #[msg("Final message buffer hash doesnt match the expected hash")]
     FinalBufferHashMismatch,
-    #[msg("Final buffer size cannot exceed 4000 bytes")]
+    #[msg("Final buffer size cannot exceed 7014 bytes")]
     FinalBufferSizeExceeded,
     #[msg("Final buffer size mismatch")]
     FinalBufferSizeMismatch,
@@ -110,4 +110,38 @@ pub enum SmartAccountError {
     TimeLockNotZero,
     #[msg("Feature not implemented")]
     NotImplemented,
+    #[msg("Invalid time lock bypass threshold, must be greater than the threshold and not exceed the number of voters")]
+    InvalidTimeLockBypassThreshold,
+    #[msg("Transaction moved assets out of the smart account that are not covered by the spending limit")]
+    SpendingLimitInvalidOutflow,
+    #[msg("Invalid spending limit period")]
+    SpendingLimitInvalidPeriod,
+    #[msg("Invalid price oracle")]
+    InvalidPriceOracle,
+    #[msg("Price is not trading, stale or too uncertain")]
+    PriceOutOfBounds,
+    #[msg("Spending limit is paused")]
+    SpendingLimitPaused,
+    #[msg("NFT is not covered by the spending limit")]
+    InvalidNft,
+    #[msg("Buffer chunk must start at a multiple of the chunk size and fill the chunk")]
+    InvalidBufferChunk,
+    #[msg("Transaction buffer has chunks that haven't been written")]
+    TransactionBufferIncomplete,
+    #[msg("Transaction message doesn't match the hash the transaction was created with")]
+    TransactionMessageHashMismatch,
+    #[msg("Settings action can't be applied without accounts other than the settings")]
+    UnsupportedSettingsAction,
+    #[msg("Instruction invokes a program the spending limit doesn't allow")]
+    ProgramNotAllowed,
+    #[msg("Transaction changed the owner or the data of the smart account")]
+    SmartAccountModified,
+    #[msg("Transaction delegates or reassigns the authority of a token account")]
+    TokenAuthorityChange,
+    #[msg("Transaction message can only be revealed for transactions created with its hash")]
+    TransactionMessageNotHashCommitted,
+    #[msg("Usage history capacity of the spending limit exceeds the maximum")]
+    InvalidUsageHistoryCapacity,
+    #[msg("Transaction writes to an account under the authority of the smart account whose assets can't be measured")]
+    UntrackedSmartAccountAsset,
 }
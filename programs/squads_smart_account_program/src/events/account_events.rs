@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::{state::SettingsAction, Settings, SmartAccountCompiledInstruction, SmartAccountSigner, SpendingLimit};
+use crate::{
+    state::SettingsAction, Batch, MintAmount, NftGroup, Proposal, Settings, SettingsTransaction,
+    SmartAccountCompiledInstruction, SmartAccountSigner, SpendingLimit, SpendingLimitTransfer,
+    SpendingLimitUpdate,
+};
 
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -17,6 +22,8 @@ pub struct SynchronousTransactionEvent {
     pub signers: Vec<Pubkey>,
     pub instructions: Vec<SmartAccountCompiledInstruction>,
     pub instruction_accounts: Vec<Pubkey>,
+    /// Ephemeral signer PDAs the transaction was signed with.
+    pub ephemeral_signers: Vec<Pubkey>,
 }
 
 
@@ -41,6 +48,16 @@ pub struct RemoveSpendingLimitEvent {
     pub spending_limit_pubkey: Pubkey,
 }
 
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UpdateSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub update: SpendingLimitUpdate,
+    pub memo: Option<String>,
+    /// The spending limit after the update.
+    pub spending_limit: SpendingLimit,
+}
+
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct UseSpendingLimitEvent {
     pub settings_pubkey: Pubkey,
@@ -50,9 +67,80 @@ pub struct UseSpendingLimitEvent {
     pub destination: Pubkey,
     pub destination_token_account: Pubkey,
     pub signer: Pubkey,
+    /// The other signers of the spending limit that co-signed the use.
+    pub cosigners: Vec<Pubkey>,
     pub mint: Pubkey,
     pub mint_decimals: u8,
     pub amount: u64,
+    /// Fee withheld from `amount` by the Token-2022 transfer fee extension of the mint.
+    pub transfer_fee: u64,
+    /// What was debited from the spending limit, in the quote currency of its price oracle
+    /// if it has one.
+    pub charged_amount: u64,
+    /// What the signer can still spend of the mint in the current period according to their
+    /// own allowance, if they have one.
+    pub signer_remaining_amount: Option<u64>,
+    pub spending_limit: SpendingLimit,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UseSpendingLimitSyncEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub smart_account: Pubkey,
+    pub signer: Pubkey,
+    /// The other signers of the spending limit that co-signed the use.
+    pub cosigners: Vec<Pubkey>,
+    /// Net outflow of every mint from the smart account, debited from the spending limit.
+    pub outflows: Vec<MintAmount>,
+    pub instructions: Vec<SmartAccountCompiledInstruction>,
+    pub instruction_accounts: Vec<Pubkey>,
+    pub spending_limit: SpendingLimit,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UseSpendingLimitBatchEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub smart_account: Pubkey,
+    pub smart_account_token_account: Pubkey,
+    pub signer: Pubkey,
+    /// The other signers of the spending limit that co-signed the use.
+    pub cosigners: Vec<Pubkey>,
+    pub mint: Pubkey,
+    pub mint_decimals: u8,
+    pub transfers: Vec<SpendingLimitTransfer>,
+    /// Accounts the transfers were made to, in the order of `transfers`.
+    pub destination_accounts: Vec<Pubkey>,
+    /// Sum of the transfer amounts.
+    pub total_amount: u64,
+    /// Sum of the fees withheld from the transfers by the Token-2022 transfer fee extension
+    /// of the mint.
+    pub total_transfer_fee: u64,
+    /// What was debited from the spending limit, in the quote currency of its price oracle
+    /// if it has one.
+    pub charged_amount: u64,
+    /// What the signer can still spend of the mint in the current period according to their
+    /// own allowance, if they have one.
+    pub signer_remaining_amount: Option<u64>,
+    pub spending_limit: SpendingLimit,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UseNftSpendingLimitEvent {
+    pub settings_pubkey: Pubkey,
+    pub spending_limit_pubkey: Pubkey,
+    pub smart_account: Pubkey,
+    pub signer: Pubkey,
+    /// The other signers of the spending limit that co-signed the use.
+    pub cosigners: Vec<Pubkey>,
+    pub group: NftGroup,
+    /// Number of NFTs of `group` transferred, debited from the spending limit.
+    pub count: u64,
+    /// Mint of the transferred NFT, `None` for compressed NFTs.
+    pub mint: Option<Pubkey>,
+    /// Destination of the transferred NFT, `None` for compressed NFTs.
+    pub destination: Option<Pubkey>,
     pub spending_limit: SpendingLimit,
 }
 
@@ -69,5 +157,121 @@ pub struct AuthorityChangeEvent {
     pub settings: Settings,
     pub settings_pubkey: Pubkey,
     pub authority: Pubkey,
-    pub new_authority: Option<Pubkey>
-}
\ No newline at end of file
+    pub new_authority: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum TransactionEventType {
+    Create,
+    Execute,
+    Close,
+}
+
+/// Logged instead of a `Transaction` or `BatchTransaction` account, the message of which can be
+/// too large to fit in the data of the `log_event` instruction.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TransactionSummary {
+    /// Length of the serialized account, without its discriminator.
+    pub size: u32,
+    /// Hash of the serialized account, without its discriminator.
+    pub hash: [u8; 32],
+}
+
+impl TransactionSummary {
+    pub fn new<T: AnchorSerialize>(transaction: &T) -> Result<Self> {
+        let data = transaction.try_to_vec()?;
+        Ok(Self {
+            size: data.len() as u32,
+            hash: hash(&data).to_bytes(),
+        })
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum TransactionContent {
+    Transaction(TransactionSummary),
+    SettingsTransaction {
+        /// State of the settings after the instruction.
+        settings: Settings,
+        transaction: SettingsTransaction,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TransactionEvent {
+    pub event_type: TransactionEventType,
+    pub settings_pubkey: Pubkey,
+    pub transaction_pubkey: Pubkey,
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    pub transaction_content: Option<TransactionContent>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum ProposalEventType {
+    Create,
+    Activate,
+    Approve,
+    Reject,
+    Cancel,
+    Execute,
+    Close,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ProposalEvent {
+    pub event_type: ProposalEventType,
+    pub settings_pubkey: Pubkey,
+    pub proposal_pubkey: Pubkey,
+    pub transaction_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    pub proposal: Option<Proposal>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum BatchEventType {
+    Create,
+    AddTransaction,
+    ExecuteTransaction,
+    CloseTransaction,
+    Close,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct BatchEvent {
+    pub event_type: BatchEventType,
+    pub settings_pubkey: Pubkey,
+    pub batch_pubkey: Pubkey,
+    pub batch_index: u64,
+    pub signer: Option<Pubkey>,
+    pub memo: Option<String>,
+    /// The `BatchTransaction` the event is about, if any.
+    pub transaction_pubkey: Option<Pubkey>,
+    pub transaction: Option<TransactionSummary>,
+    /// State of the batch after the instruction.
+    pub batch: Batch,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum TransactionBufferEventType {
+    Create,
+    Extend,
+    Close,
+    Write,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TransactionBufferEvent {
+    pub event_type: TransactionBufferEventType,
+    pub settings_pubkey: Pubkey,
+    pub transaction_buffer_pubkey: Pubkey,
+    pub buffer_index: u8,
+    pub creator: Pubkey,
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u16,
+    /// Number of bytes written to the buffer so far.
+    pub buffer_size: u16,
+}
@@ -0,0 +1,276 @@
+use anchor_lang::prelude::borsh::{BorshDeserialize, BorshSerialize};
+use std::io;
+
+use super::*;
+
+/// Version of the event payload schema emitted by this program.
+///
+/// The envelope header layout is fixed across versions, only the payload layout depends on it.
+/// Bump this whenever the serialized layout of any event (or of an account embedded in an event,
+/// e.g. `Settings`) changes, and teach the decoder in `clients/event-decoder` how to read the
+/// previous version.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+/// First byte of every enveloped event.
+/// Events emitted before the envelope was introduced start with the `SmartAccountEvent` variant
+/// index instead, which never reaches this value, so the two can always be told apart.
+pub const EVENT_ENVELOPE_MARKER: u8 = 0xff;
+
+/// Identifies the payload carried by a `SmartAccountEventEnvelope`.
+/// Discriminants are part of the wire format and must never be reused or reordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SmartAccountEventKind {
+    CreateSmartAccount = 0,
+    SynchronousTransaction = 1,
+    SynchronousSettingsTransaction = 2,
+    AddSpendingLimit = 3,
+    RemoveSpendingLimit = 4,
+    UseSpendingLimit = 5,
+    AuthoritySettings = 6,
+    AuthorityChange = 7,
+    Transaction = 8,
+    Proposal = 9,
+    Batch = 10,
+    TransactionBuffer = 11,
+    UseSpendingLimitSync = 12,
+    UpdateSpendingLimit = 13,
+    UseSpendingLimitBatch = 14,
+    UseNftSpendingLimit = 15,
+}
+
+impl TryFrom<u8> for SmartAccountEventKind {
+    type Error = io::Error;
+
+    fn try_from(kind: u8) -> io::Result<Self> {
+        Ok(match kind {
+            0 => Self::CreateSmartAccount,
+            1 => Self::SynchronousTransaction,
+            2 => Self::SynchronousSettingsTransaction,
+            3 => Self::AddSpendingLimit,
+            4 => Self::RemoveSpendingLimit,
+            5 => Self::UseSpendingLimit,
+            6 => Self::AuthoritySettings,
+            7 => Self::AuthorityChange,
+            8 => Self::Transaction,
+            9 => Self::Proposal,
+            10 => Self::Batch,
+            11 => Self::TransactionBuffer,
+            12 => Self::UseSpendingLimitSync,
+            13 => Self::UpdateSpendingLimit,
+            14 => Self::UseSpendingLimitBatch,
+            15 => Self::UseNftSpendingLimit,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown event kind {}", kind),
+                ))
+            }
+        })
+    }
+}
+
+/// Self-describing wrapper around a serialized `SmartAccountEvent`.
+///
+/// Serialized as `EVENT_ENVELOPE_MARKER` followed by the Borsh encoding of this struct,
+/// so the header can be read without knowing the payload layout, and payloads of unknown
+/// versions or kinds can be skipped using the payload length.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SmartAccountEventEnvelope {
+    /// Schema version of the `payload`, see `EVENT_SCHEMA_VERSION`.
+    pub version: u8,
+    /// `SmartAccountEventKind` of the `payload`.
+    pub kind: u8,
+    /// Position of the event among all events logged for the settings, see `EventSequence`.
+    /// Events of every kind are sequenced, as long as the caller passed the `EventSequence`
+    /// of the settings. `None` otherwise, e.g. for a client that predates it or settings that
+    /// haven't initialized it yet, so indexers can't detect gaps around such events.
+    pub sequence: Option<u64>,
+    /// Borsh-serialized event struct, prefixed with its `u32` length.
+    pub payload: Vec<u8>,
+}
+
+impl SmartAccountEventEnvelope {
+    pub fn new(event: &SmartAccountEvent, sequence: Option<u64>) -> io::Result<Self> {
+        Ok(Self {
+            version: EVENT_SCHEMA_VERSION,
+            kind: event.kind() as u8,
+            sequence,
+            payload: event.payload()?,
+        })
+    }
+
+    /// Serializes the envelope in its on-chain representation, including the marker byte.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(1 + 1 + 1 + 9 + 4 + self.payload.len());
+        bytes.push(EVENT_ENVELOPE_MARKER);
+        self.serialize(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Reads an envelope from its on-chain representation.
+    /// Fails if `data` doesn't start with `EVENT_ENVELOPE_MARKER` or has trailing bytes.
+    pub fn from_bytes(data: &[u8]) -> io::Result<Self> {
+        match data.split_first() {
+            Some((&EVENT_ENVELOPE_MARKER, mut rest)) => {
+                let envelope = Self::deserialize(&mut rest)?;
+                if !rest.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "trailing bytes after event envelope",
+                    ));
+                }
+                Ok(envelope)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "missing event envelope marker",
+            )),
+        }
+    }
+}
+
+impl SmartAccountEvent {
+    pub fn kind(&self) -> SmartAccountEventKind {
+        match self {
+            Self::CreateSmartAccountEvent(_) => SmartAccountEventKind::CreateSmartAccount,
+            Self::SynchronousTransactionEvent(_) => SmartAccountEventKind::SynchronousTransaction,
+            Self::SynchronousSettingsTransactionEvent(_) => {
+                SmartAccountEventKind::SynchronousSettingsTransaction
+            }
+            Self::AddSpendingLimitEvent(_) => SmartAccountEventKind::AddSpendingLimit,
+            Self::RemoveSpendingLimitEvent(_) => SmartAccountEventKind::RemoveSpendingLimit,
+            Self::UseSpendingLimitEvent(_) => SmartAccountEventKind::UseSpendingLimit,
+            Self::AuthoritySettingsEvent(_) => SmartAccountEventKind::AuthoritySettings,
+            Self::AuthorityChangeEvent(_) => SmartAccountEventKind::AuthorityChange,
+            Self::TransactionEvent(_) => SmartAccountEventKind::Transaction,
+            Self::ProposalEvent(_) => SmartAccountEventKind::Proposal,
+            Self::BatchEvent(_) => SmartAccountEventKind::Batch,
+            Self::TransactionBufferEvent(_) => SmartAccountEventKind::TransactionBuffer,
+            Self::UseSpendingLimitSyncEvent(_) => SmartAccountEventKind::UseSpendingLimitSync,
+            Self::UpdateSpendingLimitEvent(_) => SmartAccountEventKind::UpdateSpendingLimit,
+            Self::UseSpendingLimitBatchEvent(_) => SmartAccountEventKind::UseSpendingLimitBatch,
+            Self::UseNftSpendingLimitEvent(_) => SmartAccountEventKind::UseNftSpendingLimit,
+        }
+    }
+
+    /// Serializes the inner event struct, without the enum variant index.
+    pub fn payload(&self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::CreateSmartAccountEvent(event) => event.try_to_vec(),
+            Self::SynchronousTransactionEvent(event) => event.try_to_vec(),
+            Self::SynchronousSettingsTransactionEvent(event) => event.try_to_vec(),
+            Self::AddSpendingLimitEvent(event) => event.try_to_vec(),
+            Self::RemoveSpendingLimitEvent(event) => event.try_to_vec(),
+            Self::UseSpendingLimitEvent(event) => event.try_to_vec(),
+            Self::AuthoritySettingsEvent(event) => event.try_to_vec(),
+            Self::AuthorityChangeEvent(event) => event.try_to_vec(),
+            Self::TransactionEvent(event) => event.try_to_vec(),
+            Self::ProposalEvent(event) => event.try_to_vec(),
+            Self::BatchEvent(event) => event.try_to_vec(),
+            Self::TransactionBufferEvent(event) => event.try_to_vec(),
+            Self::UseSpendingLimitSyncEvent(event) => event.try_to_vec(),
+            Self::UpdateSpendingLimitEvent(event) => event.try_to_vec(),
+            Self::UseSpendingLimitBatchEvent(event) => event.try_to_vec(),
+            Self::UseNftSpendingLimitEvent(event) => event.try_to_vec(),
+        }
+    }
+
+    /// Inverse of `payload`, for payloads of the current `EVENT_SCHEMA_VERSION`.
+    pub fn from_payload(kind: SmartAccountEventKind, payload: &[u8]) -> io::Result<Self> {
+        Ok(match kind {
+            SmartAccountEventKind::CreateSmartAccount => {
+                Self::CreateSmartAccountEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::SynchronousTransaction => {
+                Self::SynchronousTransactionEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::SynchronousSettingsTransaction => {
+                Self::SynchronousSettingsTransactionEvent(BorshDeserialize::try_from_slice(
+                    payload,
+                )?)
+            }
+            SmartAccountEventKind::AddSpendingLimit => {
+                Self::AddSpendingLimitEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::RemoveSpendingLimit => {
+                Self::RemoveSpendingLimitEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::UseSpendingLimit => {
+                Self::UseSpendingLimitEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::AuthoritySettings => {
+                Self::AuthoritySettingsEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::AuthorityChange => {
+                Self::AuthorityChangeEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::Transaction => {
+                Self::TransactionEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::Proposal => {
+                Self::ProposalEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::Batch => {
+                Self::BatchEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::TransactionBuffer => {
+                Self::TransactionBufferEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::UseSpendingLimitSync => {
+                Self::UseSpendingLimitSyncEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::UpdateSpendingLimit => {
+                Self::UpdateSpendingLimitEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::UseSpendingLimitBatch => {
+                Self::UseSpendingLimitBatchEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+            SmartAccountEventKind::UseNftSpendingLimit => {
+                Self::UseNftSpendingLimitEvent(BorshDeserialize::try_from_slice(payload)?)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_is_kept_for_every_kind() {
+        for kind in 0..=u8::MAX {
+            if SmartAccountEventKind::try_from(kind).is_err() {
+                continue;
+            }
+            for sequence in [Some(0), Some(u64::MAX), None] {
+                let envelope = SmartAccountEventEnvelope {
+                    version: EVENT_SCHEMA_VERSION,
+                    kind,
+                    sequence,
+                    payload: vec![1, 2, 3],
+                };
+
+                let decoded =
+                    SmartAccountEventEnvelope::from_bytes(&envelope.to_bytes().unwrap()).unwrap();
+
+                assert_eq!(decoded, envelope);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_malformed_envelopes() {
+        let envelope = SmartAccountEventEnvelope {
+            version: EVENT_SCHEMA_VERSION,
+            kind: SmartAccountEventKind::Proposal as u8,
+            sequence: Some(1),
+            payload: vec![],
+        };
+        let mut data = envelope.to_bytes().unwrap();
+
+        assert!(SmartAccountEventEnvelope::from_bytes(&data[1..]).is_err());
+        data.push(0);
+        assert!(SmartAccountEventEnvelope::from_bytes(&data).is_err());
+    }
+}
@@ -2,10 +2,12 @@ use anchor_lang::{
     prelude::borsh::*, prelude::*, solana_program::program::invoke_signed, Discriminator,
 };
 
-use crate::LogEventArgs;
+use crate::{EventSequence, LogEventArgs};
 
 pub mod account_events;
+pub mod envelope;
 pub use account_events::*;
+pub use envelope::*;
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum SmartAccountEvent {
@@ -16,16 +18,107 @@ pub enum SmartAccountEvent {
     RemoveSpendingLimitEvent(RemoveSpendingLimitEvent),
     UseSpendingLimitEvent(UseSpendingLimitEvent),
     AuthoritySettingsEvent(AuthoritySettingsEvent),
-    AuthorityChangeEvent(AuthorityChangeEvent)
+    AuthorityChangeEvent(AuthorityChangeEvent),
+    TransactionEvent(TransactionEvent),
+    ProposalEvent(ProposalEvent),
+    BatchEvent(BatchEvent),
+    TransactionBufferEvent(TransactionBufferEvent),
+    UseSpendingLimitSyncEvent(UseSpendingLimitSyncEvent),
+    UpdateSpendingLimitEvent(UpdateSpendingLimitEvent),
+    UseSpendingLimitBatchEvent(UseSpendingLimitBatchEvent),
+    UseNftSpendingLimitEvent(UseNftSpendingLimitEvent),
 }
-pub struct LogAuthorityInfo<'info> {
+pub struct LogAuthorityInfo<'info, 'log> {
     pub authority: AccountInfo<'info>,
     pub authority_seeds: Vec<Vec<u8>>,
     pub bump: u8,
-    pub program: AccountInfo<'info>,
+    /// Accounts the event is logged through.
+    /// The event is not logged when the caller didn't pass them.
+    pub log_accounts: Option<LogAccounts<'log>>,
 }
+
+/// Accounts an instruction logs its events through.
+pub struct LogAccounts<'info> {
+    /// Key of the program account, which must be one of the instruction accounts.
+    pub program: Pubkey,
+    /// `EventSequence` of the settings the events are logged for.
+    /// Events are logged without a sequence number when the caller didn't pass it.
+    pub event_sequence: Option<AccountInfo<'info>>,
+}
+
+impl<'info> LogAccounts<'info> {
+    /// Log accounts of an instruction that takes the program account,
+    /// with the `EventSequence` as the optional trailing account of `remaining_accounts`.
+    pub fn new(program: Pubkey, remaining_accounts: &[AccountInfo<'info>]) -> Option<Self> {
+        Some(Self {
+            program,
+            event_sequence: remaining_accounts
+                .last()
+                .filter(|account| EventSequence::is_event_sequence(account))
+                .cloned(),
+        })
+    }
+
+    /// Log accounts of an instruction that predates event logging, passed by the caller
+    /// as the optional trailing accounts of `remaining_accounts`:
+    /// the `EventSequence`, if any, followed by the program account.
+    /// The program account can't be an optional `Program` field of the `Accounts` struct,
+    /// because Anchor resolves an optional account keyed with the program id to `None`.
+    pub fn find(remaining_accounts: &[AccountInfo<'info>]) -> Option<Self> {
+        match remaining_accounts.split_last() {
+            Some((program, accounts)) if program.key == &crate::ID => {
+                Self::new(program.key(), accounts)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the `remaining_accounts` without the optional trailing accounts of `LogAccounts`,
+/// for instructions that expect exactly `num_accounts` of them.
+pub fn strip_log_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    num_accounts: usize,
+) -> &'a [AccountInfo<'info>] {
+    let mut accounts = remaining_accounts;
+    if let Some((last, rest)) = accounts.split_last() {
+        if rest.len() >= num_accounts && last.key == &crate::ID {
+            accounts = rest;
+        }
+    }
+    // Strip the account passed as `EventSequence` even if it wasn't initialized yet.
+    if accounts.len() == num_accounts + 1 {
+        accounts = &accounts[..num_accounts];
+    }
+    accounts
+}
+
+/// Returns the `remaining_accounts` without the optional trailing `EventSequence`,
+/// for instructions that take the program account and pass the `remaining_accounts` on.
+pub fn strip_event_sequence<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> &'a [AccountInfo<'info>] {
+    match remaining_accounts.split_last() {
+        Some((last, accounts)) if EventSequence::is_event_sequence(last) => accounts,
+        _ => remaining_accounts,
+    }
+}
+
 impl SmartAccountEvent {
-    pub fn log<'info>(&self, authority_info: &LogAuthorityInfo<'info>) -> Result<()> {
+    /// Logs the event through a self-CPI, stamping it with the next sequence number
+    /// of the `EventSequence` the caller passed.
+    pub fn log(&self, authority_info: &LogAuthorityInfo<'_, '_>) -> Result<()> {
+        let Some(log_accounts) = &authority_info.log_accounts else {
+            return Ok(());
+        };
+        let sequence = log_accounts
+            .event_sequence
+            .as_ref()
+            .map(|event_sequence| {
+                EventSequence::advance_account(event_sequence, authority_info.authority.key)
+            })
+            .transpose()?;
+
         let mut signer_seeds: Vec<&[u8]> = authority_info
             .authority_seeds
             .iter()
@@ -37,7 +130,7 @@ impl SmartAccountEvent {
         let data = LogEventArgs {
             account_seeds: authority_info.authority_seeds.clone(),
             bump: authority_info.bump,
-            event: self.try_to_vec()?,
+            event: SmartAccountEventEnvelope::new(self, sequence)?.to_bytes()?,
         };
         let mut instruction_data =
             Vec::with_capacity(8 + 4 + authority_info.authority_seeds.len() + 4 + data.event.len());
@@ -45,7 +138,7 @@ impl SmartAccountEvent {
         instruction_data.extend_from_slice(&data.try_to_vec()?);
 
         let ix = solana_program::instruction::Instruction {
-            program_id: authority_info.program.key(),
+            program_id: log_accounts.program,
             accounts: vec![AccountMeta::new_readonly(
                 authority_info.authority.key(),
                 true,
@@ -59,3 +152,113 @@ impl SmartAccountEvent {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey) -> Self {
+            Self {
+                key,
+                owner: Pubkey::default(),
+                lamports: 0,
+                data: vec![],
+            }
+        }
+
+        fn event_sequence() -> Self {
+            let mut data = vec![];
+            EventSequence {
+                settings: Pubkey::new_unique(),
+                next_sequence: 0,
+                bump: 255,
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+            Self {
+                key: Pubkey::new_unique(),
+                owner: crate::ID,
+                lamports: 0,
+                data,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                false,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    #[test]
+    fn test_find_log_accounts() {
+        let mut message_account = TestAccount::new(Pubkey::new_unique());
+        let mut event_sequence = TestAccount::event_sequence();
+        let mut program = TestAccount::new(crate::ID);
+        let event_sequence_key = event_sequence.key;
+        let accounts = [
+            message_account.info(),
+            event_sequence.info(),
+            program.info(),
+        ];
+
+        // Nothing is logged without the program account.
+        assert!(LogAccounts::find(&accounts[..2]).is_none());
+
+        let log_accounts = LogAccounts::find(&[accounts[0].clone(), accounts[2].clone()]).unwrap();
+        assert_eq!(log_accounts.program, crate::ID);
+        assert!(log_accounts.event_sequence.is_none());
+
+        let log_accounts = LogAccounts::find(&accounts).unwrap();
+        assert_eq!(
+            log_accounts.event_sequence.map(|account| account.key()),
+            Some(event_sequence_key)
+        );
+    }
+
+    #[test]
+    fn test_strip_log_accounts() {
+        let mut message_account = TestAccount::new(Pubkey::new_unique());
+        let mut uninitialized_event_sequence = TestAccount::new(Pubkey::new_unique());
+        let mut program = TestAccount::new(crate::ID);
+        let accounts = [
+            message_account.info(),
+            uninitialized_event_sequence.info(),
+            program.info(),
+        ];
+
+        assert_eq!(strip_log_accounts(&accounts, 1).len(), 1);
+        assert_eq!(strip_log_accounts(&accounts[..2], 1).len(), 1);
+        assert_eq!(
+            strip_log_accounts(&[accounts[0].clone(), accounts[2].clone()], 1).len(),
+            1
+        );
+        // A program account the message itself needs is kept.
+        assert_eq!(
+            strip_log_accounts(&[accounts[0].clone(), accounts[2].clone()], 2).len(),
+            2
+        );
+        // Only initialized event sequences are stripped when the count isn't known.
+        assert_eq!(strip_event_sequence(&accounts[..2]).len(), 2);
+        let mut event_sequence = TestAccount::event_sequence();
+        assert_eq!(
+            strip_event_sequence(&[accounts[0].clone(), event_sequence.info()]).len(),
+            1
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -26,6 +27,8 @@ pub struct ActivateProposal<'info> {
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, Proposal>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl ActivateProposal<'_> {
@@ -64,10 +67,31 @@ impl ActivateProposal<'_> {
     /// Update status of a multisig proposal from `Draft` to `Active`.
     #[access_control(ctx.accounts.validate())]
     pub fn activate_proposal(ctx: Context<Self>) -> Result<()> {
-        ctx.accounts.proposal.status = ProposalStatus::Active {
+        let settings = &ctx.accounts.settings;
+        let proposal = &mut ctx.accounts.proposal;
+
+        proposal.status = ProposalStatus::Active {
             timestamp: Clock::get()?.unix_timestamp,
         };
 
+        // Log the event
+        let event = ProposalEvent {
+            event_type: ProposalEventType::Activate,
+            settings_pubkey: settings.key(),
+            proposal_pubkey: proposal.key(),
+            transaction_index: proposal.transaction_index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::*, program::SquadsSmartAccountProgram, state::*, AuthorityChangeEvent,
-    AuthoritySettingsEvent, LogAuthorityInfo, SmartAccountEvent,
+    AuthoritySettingsEvent, LogAccounts, LogAuthorityInfo, SmartAccountEvent,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -33,6 +33,13 @@ pub struct SetTimeLockArgs {
     pub memo: Option<String>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTimeLockBypassArgs {
+    pub time_lock_bypass: TimeLockBypass,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SetNewSettingsAuthorityArgs {
     pub new_settings_authority: Pubkey,
@@ -68,6 +75,8 @@ pub struct ExecuteSettingsTransactionAsAuthority<'info> {
     /// We might need it in case reallocation is needed.
     pub system_program: Option<Program<'info, System>>,
     pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl ExecuteSettingsTransactionAsAuthority<'_> {
@@ -101,8 +110,7 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
 
         // Make sure the settings account can fit the newly set rent_collector.
         Settings::realloc_if_needed(
-            settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .rent_payer
                 .as_ref()
@@ -130,7 +138,7 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -168,7 +176,7 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -201,7 +209,7 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -234,7 +242,53 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+
+    /// Set the `time_lock_bypass` config parameter for the smart account.
+    ///
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_time_lock_bypass(ctx: Context<Self>, args: SetTimeLockBypassArgs) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        settings.set_time_lock_bypass(args.time_lock_bypass);
+
+        // Make sure the settings account can fit the `SettingsExtension` holding the bypass.
+        Settings::realloc_if_needed(
+            settings,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        settings.invalidate_prior_transactions();
+
+        settings.invariant()?;
+
+        // Log the event
+        let event = AuthoritySettingsEvent {
+            settings: Settings::try_from_slice(&settings.try_to_vec()?)?,
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::SetTimeLockBypass {
+                new_time_lock_bypass: args.time_lock_bypass,
+            },
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -268,7 +322,7 @@ impl ExecuteSettingsTransactionAsAuthority<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthorityChangeEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::program::SquadsSmartAccountProgram;
-use crate::{state::*, LogAuthorityInfo, SmartAccountEvent};
+use crate::utils::AccountExtension;
+use crate::{state::*, LogAccounts, LogAuthorityInfo, SmartAccountEvent};
 use crate::{errors::*, AuthoritySettingsEvent};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -28,6 +29,8 @@ pub struct AddSpendingLimitArgs {
     /// The expiration timestamp of the spending limit.
     /// Non expiring spending limits are set to `i64::MAX`.
     pub expiration: i64,
+    /// Configuration of the spending limit beyond its original fields.
+    pub options: SpendingLimitOptions,
     /// Memo is used for indexing only.
     pub memo: Option<String>,
 }
@@ -53,7 +56,12 @@ pub struct AddSpendingLimitAsAuthority<'info> {
             args.seed.as_ref(),
         ],
         bump,
-        space = SpendingLimit::size(args.signers.len(), args.destinations.len()),
+        space = SpendingLimit::new_size(
+            args.signers.len(),
+            args.destinations.len(),
+            args.period,
+            &args.options,
+        ),
         payer = rent_payer
     )]
     pub spending_limit: Account<'info, SpendingLimit>,
@@ -64,6 +72,8 @@ pub struct AddSpendingLimitAsAuthority<'info> {
 
     pub system_program: Program<'info, System>,
     pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl AddSpendingLimitAsAuthority<'_> {
@@ -112,6 +122,8 @@ impl AddSpendingLimitAsAuthority<'_> {
         spending_limit.signers = sorted_signers;
         spending_limit.destinations = args.destinations.clone();
         spending_limit.expiration = args.expiration;
+        spending_limit.extension =
+            AccountExtension::new(SpendingLimitExtension::new(args.period, &args.options));
 
         spending_limit.invariant()?;
 
@@ -120,7 +132,7 @@ impl AddSpendingLimitAsAuthority<'_> {
             settings: Settings::try_from_slice(&settings.try_to_vec()?)?,
             settings_pubkey: ctx.accounts.settings.key(),
             authority: ctx.accounts.settings_authority.key(),
-            change: SettingsAction::AddSpendingLimit {
+            change: SettingsAction::AddSpendingLimitWithOptions {
                 seed: args.seed,
                 account_index: args.account_index,
                 mint: args.mint,
@@ -129,13 +141,14 @@ impl AddSpendingLimitAsAuthority<'_> {
                 signers: args.signers,
                 destinations: args.destinations,
                 expiration: args.expiration,
+                options: args.options,
             },
         };
         let log_authority_info = LogAuthorityInfo {
             authority: ctx.accounts.settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
 
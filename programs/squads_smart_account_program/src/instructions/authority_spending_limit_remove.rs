@@ -4,8 +4,8 @@ use crate::errors::*;
 use crate::program::SquadsSmartAccountProgram;
 use crate::state::*;
 use crate::AuthoritySettingsEvent;
-use crate::LogAuthorityInfo;
 use crate::SmartAccountEvent;
+use crate::{LogAccounts, LogAuthorityInfo};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RemoveSpendingLimitArgs {
@@ -33,6 +33,8 @@ pub struct RemoveSpendingLimitAsAuthority<'info> {
     pub rent_collector: AccountInfo<'info>,
 
     pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl RemoveSpendingLimitAsAuthority<'_> {
@@ -74,7 +76,7 @@ impl RemoveSpendingLimitAsAuthority<'_> {
             authority: ctx.accounts.settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
         Ok(())
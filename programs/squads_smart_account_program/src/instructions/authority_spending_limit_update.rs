@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::program::SquadsSmartAccountProgram;
+use crate::state::*;
+use crate::utils::realloc;
+use crate::{
+    AuthoritySettingsEvent, LogAccounts, LogAuthorityInfo, SmartAccountEvent,
+    UpdateSpendingLimitEvent,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateSpendingLimitArgs {
+    /// Changes to apply to the spending limit.
+    pub update: SpendingLimitUpdate,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSpendingLimitAsAuthority<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    /// Settings `settings_authority` that must authorize the configuration change.
+    pub settings_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// The account that will be charged in case the spending limit account needs to reallocate space,
+    /// for example when adding signers or destinations.
+    /// This is usually the same as `settings_authority`, but can be a different account if needed.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl UpdateSpendingLimitAsAuthority<'_> {
+    fn validate(&self, update: &SpendingLimitUpdate) -> Result<()> {
+        // settings_authority
+        require_keys_eq!(
+            self.settings_authority.key(),
+            self.settings.settings_authority,
+            SmartAccountError::Unauthorized
+        );
+
+        // `spending_limit`
+        require_keys_eq!(
+            self.spending_limit.settings,
+            self.settings.key(),
+            SmartAccountError::InvalidAccount
+        );
+
+        // Expiration must be greater than the current timestamp.
+        if let Some(expiration) = update.expiration {
+            if expiration != i64::MAX {
+                require!(
+                    expiration > Clock::get()?.unix_timestamp,
+                    SmartAccountError::SpendingLimitExpired
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update a spending limit of the controlled smart account in place.
+    /// NOTE: This instruction must be called only by the `settings_authority` if one is set (Controlled Smart Account).
+    ///       Uncontrolled Smart Accounts should use `create_settings_transaction` instead.
+    #[access_control(ctx.accounts.validate(&args.update))]
+    pub fn update_spending_limit(ctx: Context<Self>, args: UpdateSpendingLimitArgs) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let spending_limit = &mut ctx.accounts.spending_limit;
+
+        spending_limit.update(&args.update, Clock::get()?.unix_timestamp)?;
+
+        // Make sure the spending limit account can fit the updated state.
+        let spending_limit_info = spending_limit.to_account_info();
+        let new_size = spending_limit.current_size();
+        if spending_limit_info.data_len() < new_size {
+            realloc(
+                &spending_limit_info,
+                new_size,
+                ctx.accounts
+                    .rent_payer
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+                ctx.accounts
+                    .system_program
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+            )?;
+        }
+
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        let event = AuthoritySettingsEvent {
+            settings: Settings::try_from_slice(&settings.try_to_vec()?)?,
+            settings_pubkey: settings.key(),
+            authority: ctx.accounts.settings_authority.key(),
+            change: SettingsAction::UpdateSpendingLimit {
+                spending_limit: spending_limit.key(),
+                update: args.update.clone(),
+            },
+        };
+        SmartAccountEvent::AuthoritySettingsEvent(event).log(&log_authority_info)?;
+
+        let event = UpdateSpendingLimitEvent {
+            settings_pubkey: settings.key(),
+            spending_limit_pubkey: spending_limit.key(),
+            update: args.update,
+            memo: args.memo,
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        SmartAccountEvent::UpdateSpendingLimitEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use crate::TransactionMessage;
 
@@ -71,6 +72,8 @@ pub struct AddTransactionToBatch<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl AddTransactionToBatch<'_> {
@@ -148,6 +151,27 @@ impl AddTransactionToBatch<'_> {
         msg!("batch index: {}", batch.index);
         msg!("batch size: {}", batch.size);
 
+        // Log the event
+        let settings = &ctx.accounts.settings;
+        let event = BatchEvent {
+            event_type: BatchEventType::AddTransaction,
+            settings_pubkey: settings.key(),
+            batch_pubkey: batch_key,
+            batch_index: batch.index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            transaction_pubkey: Some(transaction.key()),
+            transaction: Some(TransactionSummary::new(&**transaction)?),
+            batch: Batch::try_from_slice(&batch.try_to_vec()?)?,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::BatchEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,130 @@
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::*;
+use crate::state::*;
+use crate::utils::realloc;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddTransactionToBatchFromBuffer<'info> {
+    // The context needed for the AddTransactionToBatch instruction
+    pub add_transaction_to_batch: AddTransactionToBatch<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        // Only the creator can turn the buffer into a batch transaction and reclaim
+        // the rent
+        constraint = transaction_buffer.creator == creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            add_transaction_to_batch.settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    // Anchor doesn't allow us to use the signer inside of
+    // add_transaction_to_batch, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = add_transaction_to_batch.signer.key(),
+    )]
+    pub creator: Signer<'info>,
+}
+
+impl<'info> AddTransactionToBatchFromBuffer<'info> {
+    pub fn validate(&self, args: &AddTransactionToBatchArgs) -> Result<()> {
+        let transaction_buffer_account = &self.transaction_buffer;
+
+        // Check that the transaction message is "empty"
+        require!(
+            args.transaction_message == vec![0, 0, 0, 0, 0, 0],
+            SmartAccountError::InvalidInstructionArgs
+        );
+
+        // Validate that every chunk of the buffer has been written
+        transaction_buffer_account.validate_written()?;
+
+        // Validate that the final hash matches the buffer
+        transaction_buffer_account.validate_hash()?;
+
+        // Validate that the final size is correct
+        transaction_buffer_account.validate_size()?;
+        Ok(())
+    }
+
+    /// Add a transaction to the batch from a completed transaction buffer account.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn add_transaction_to_batch_from_buffer(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: AddTransactionToBatchArgs,
+    ) -> Result<()> {
+        // Read-only accounts
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        // Reallocate the batch transaction account to fit the transaction message,
+        // since it was initialized with an empty one.
+        realloc(
+            &ctx.accounts
+                .add_transaction_to_batch
+                .transaction
+                .to_account_info(),
+            BatchTransaction::size(args.ephemeral_signers, &transaction_buffer.buffer)?,
+            Some(
+                ctx.accounts
+                    .add_transaction_to_batch
+                    .rent_payer
+                    .to_account_info(),
+            ),
+            Some(
+                ctx.accounts
+                    .add_transaction_to_batch
+                    .system_program
+                    .to_account_info(),
+            ),
+        )?;
+
+        // Create the args for the `add_transaction_to_batch` instruction
+        let add_args = AddTransactionToBatchArgs {
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: transaction_buffer.buffer.clone(),
+        };
+        // Create the context for the `add_transaction_to_batch` instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.add_transaction_to_batch,
+            ctx.remaining_accounts,
+            ctx.bumps.add_transaction_to_batch,
+        );
+
+        // Call the `add_transaction_to_batch` instruction
+        AddTransactionToBatch::add_transaction_to_batch(context, add_args)?;
+
+        // Log the event
+        let settings = &ctx.accounts.add_transaction_to_batch.settings;
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
+        // Anchor will close the `transaction_buffer` account for us.
+        Ok(())
+    }
+}
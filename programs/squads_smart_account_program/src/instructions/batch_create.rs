@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -41,6 +42,8 @@ pub struct CreateBatch<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CreateBatch<'_> {
@@ -103,6 +106,26 @@ impl CreateBatch<'_> {
         // Logs for indexing.
         msg!("batch index: {}", index);
 
+        // Log the event
+        let event = BatchEvent {
+            event_type: BatchEventType::Create,
+            settings_pubkey: settings_key,
+            batch_pubkey: batch.key(),
+            batch_index: index,
+            signer: Some(creator.key()),
+            memo: args.memo,
+            transaction_pubkey: None,
+            transaction: None,
+            batch: Batch::try_from_slice(&batch.try_to_vec()?)?,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::BatchEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
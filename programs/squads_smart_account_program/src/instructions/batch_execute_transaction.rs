@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use crate::utils::*;
 
@@ -61,6 +62,8 @@ pub struct ExecuteBatchTransaction<'info> {
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
     // 2. Accounts in the order they appear in `message.account_keys`.
     // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. Optionally, the `EventSequence` of the settings and the program account,
+    //    in this order, to log sequenced events.
 }
 
 impl ExecuteBatchTransaction<'_> {
@@ -117,6 +120,7 @@ impl ExecuteBatchTransaction<'_> {
 
         let settings_key = settings.key();
         let batch_key = batch.key();
+        let transaction_key = ctx.accounts.transaction.key();
 
         let smart_account_seeds = &[
             SEED_PREFIX,
@@ -128,13 +132,15 @@ impl ExecuteBatchTransaction<'_> {
 
         let transaction_message = transaction.message;
         let num_lookups = transaction_message.address_table_lookups.len();
+        let remaining_accounts = strip_log_accounts(
+            ctx.remaining_accounts,
+            num_lookups + transaction_message.num_all_account_keys(),
+        );
 
-        let message_account_infos = ctx
-            .remaining_accounts
+        let message_account_infos = remaining_accounts
             .get(num_lookups..)
             .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
-        let address_lookup_table_account_infos = ctx
-            .remaining_accounts
+        let address_lookup_table_account_infos = remaining_accounts
             .get(..num_lookups)
             .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
 
@@ -172,7 +178,8 @@ impl ExecuteBatchTransaction<'_> {
             .expect("overflow");
 
         // If this is the last transaction in the batch, set the proposal status to `Executed`.
-        if batch.executed_transaction_index == batch.size {
+        let is_last_transaction = batch.executed_transaction_index == batch.size;
+        if is_last_transaction {
             proposal.status = ProposalStatus::Executed {
                 timestamp: Clock::get()?.unix_timestamp,
             };
@@ -180,6 +187,39 @@ impl ExecuteBatchTransaction<'_> {
 
         batch.invariant()?;
 
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = BatchEvent {
+            event_type: BatchEventType::ExecuteTransaction,
+            settings_pubkey: settings_key,
+            batch_pubkey: batch_key,
+            batch_index: batch.index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            transaction_pubkey: Some(transaction_key),
+            transaction: None,
+            batch: Batch::try_from_slice(&batch.try_to_vec()?)?,
+        };
+        SmartAccountEvent::BatchEvent(event).log(&log_authority_info)?;
+
+        if is_last_transaction {
+            let event = ProposalEvent {
+                event_type: ProposalEventType::Execute,
+                settings_pubkey: settings_key,
+                proposal_pubkey: proposal.key(),
+                transaction_index: batch.index,
+                signer: Some(ctx.accounts.signer.key()),
+                memo: None,
+                proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+            };
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
         Ok(())
     }
 }
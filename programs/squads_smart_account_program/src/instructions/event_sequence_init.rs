@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitEventSequence<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = EventSequence::size(),
+        seeds = [SEED_PREFIX, settings.key().as_ref(), SEED_EVENT_SEQUENCE],
+        bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    /// The payer for the event sequence account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitEventSequence<'_> {
+    /// Creates the `EventSequence` of settings created without one, so their events get sequenced.
+    /// Permissionless, as it only costs the `rent_payer` the rent of the account.
+    pub fn init_event_sequence(ctx: Context<Self>) -> Result<()> {
+        let event_sequence = &mut ctx.accounts.event_sequence;
+
+        event_sequence.settings = ctx.accounts.settings.key();
+        event_sequence.next_sequence = 0;
+        event_sequence.bump = ctx.bumps.event_sequence;
+
+        Ok(())
+    }
+}
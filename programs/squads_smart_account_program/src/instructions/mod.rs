@@ -1,9 +1,12 @@
 pub use activate_proposal::*;
 pub use batch_add_transaction::*;
+pub use batch_add_transaction_from_buffer::*;
 pub use batch_create::*;
 pub use batch_execute_transaction::*;
+pub use event_sequence_init::*;
 pub use proposal_create::*;
 pub use settings_transaction_create::*;
+pub use settings_transaction_create_from_buffer::*;
 pub use smart_account_create::*;
 pub use transaction_create::*;
 pub use settings_transaction_execute::*;
@@ -12,24 +15,34 @@ pub use program_config_init::*;
 pub use authority_spending_limit_add::*;
 pub use authority_settings_transaction_execute::*;
 pub use authority_spending_limit_remove::*;
+pub use authority_spending_limit_update::*;
 pub use program_config_change::*;
 pub use proposal_vote::*;
 pub use use_spending_limit::*;
+pub use use_spending_limit_sync::*;
+pub use use_spending_limit_batch::*;
+pub use pause_spending_limit::*;
+pub use use_nft_spending_limit::*;
+pub use use_compressed_nft_spending_limit::*;
 pub use settings_transaction_sync::*;
 pub use transaction_close::*;
 pub use transaction_buffer_close::*;
 pub use transaction_buffer_create::*;
 pub use transaction_buffer_extend::*;
+pub use transaction_buffer_write::*;
 pub use transaction_create_from_buffer::*;
 pub use transaction_execute_sync::*;
 pub use log_event::*;
 
 mod activate_proposal;
 mod batch_add_transaction;
+mod batch_add_transaction_from_buffer;
 mod batch_create;
 mod batch_execute_transaction;
+mod event_sequence_init;
 mod proposal_create;
 mod settings_transaction_create;
+mod settings_transaction_create_from_buffer;
 mod smart_account_create;
 mod transaction_create;
 mod settings_transaction_execute;
@@ -38,14 +51,21 @@ mod program_config_init;
 mod authority_spending_limit_add;
 mod authority_settings_transaction_execute;
 mod authority_spending_limit_remove;
+mod authority_spending_limit_update;
 mod program_config_change;
 mod proposal_vote;
 mod use_spending_limit;
+mod use_spending_limit_sync;
+mod use_spending_limit_batch;
+mod pause_spending_limit;
+mod use_nft_spending_limit;
+mod use_compressed_nft_spending_limit;
 mod settings_transaction_sync;
 mod transaction_close;
 mod transaction_buffer_close;
 mod transaction_buffer_create;
 mod transaction_buffer_extend;
+mod transaction_buffer_write;
 mod transaction_create_from_buffer;
 mod transaction_execute_sync;
 mod log_event;
\ No newline at end of file
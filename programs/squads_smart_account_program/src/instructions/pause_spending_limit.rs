@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
+use crate::state::*;
+use crate::utils::realloc;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PauseSpendingLimitArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct PauseSpendingLimit<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// One of the `spending_limit.pausers`, or the `settings_authority` of a controlled smart account.
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to pause.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// The account that will be charged in case the spending limit account needs to reallocate space,
+    /// i.e. when pausing a spending limit created before it could be paused.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl PauseSpendingLimit<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings,
+            signer,
+            spending_limit,
+            ..
+        } = self;
+
+        // signer
+        // Pausers must still be signers of the settings.
+        let is_pauser = spending_limit.extension.pausers.contains(&signer.key())
+            && settings.is_signer(signer.key()).is_some();
+        let is_settings_authority = settings.settings_authority != Pubkey::default()
+            && settings.settings_authority == signer.key();
+        require!(
+            is_pauser || is_settings_authority,
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Pause a spending limit, so that it can't be used until it's resumed by a spending limit update.
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause_spending_limit(ctx: Context<Self>, args: PauseSpendingLimitArgs) -> Result<()> {
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        spending_limit.extension.paused = true;
+
+        // Make sure there's room for the pause in the spending limit account.
+        let spending_limit_info = spending_limit.to_account_info();
+        let new_size = spending_limit.current_size();
+        if spending_limit_info.data_len() < new_size {
+            realloc(
+                &spending_limit_info,
+                new_size,
+                ctx.accounts
+                    .rent_payer
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+                ctx.accounts
+                    .system_program
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+            )?;
+        }
+
+        // Log the event
+        let settings = &ctx.accounts.settings;
+        let event = UpdateSpendingLimitEvent {
+            settings_pubkey: settings.key(),
+            spending_limit_pubkey: spending_limit.key(),
+            update: SpendingLimitUpdate {
+                paused: Some(true),
+                ..SpendingLimitUpdate::default()
+            },
+            memo: args.memo,
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::UpdateSpendingLimitEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+}
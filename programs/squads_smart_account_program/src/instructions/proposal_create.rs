@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -43,6 +44,8 @@ pub struct CreateProposal<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CreateProposal<'_> {
@@ -109,6 +112,24 @@ impl CreateProposal<'_> {
         proposal.rejected = vec![];
         proposal.cancelled = vec![];
 
+        // Log the event
+        let event = ProposalEvent {
+            event_type: ProposalEventType::Create,
+            settings_pubkey: settings.key(),
+            proposal_pubkey: proposal.key(),
+            transaction_index: args.transaction_index,
+            signer: Some(ctx.accounts.creator.key()),
+            memo: None,
+            proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -34,6 +35,8 @@ pub struct VoteOnProposal<'info> {
 
     // Only required for cancelling a proposal.
     pub system_program: Option<Program<'info, System>>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl VoteOnProposal<'_> {
@@ -80,23 +83,61 @@ impl VoteOnProposal<'_> {
         Ok(())
     }
 
+    /// Log the vote along with the resulting state of the `proposal`.
+    fn log_vote(
+        &self,
+        event_type: ProposalEventType,
+        memo: Option<String>,
+        log_accounts: Option<LogAccounts<'_>>,
+    ) -> Result<()> {
+        let Self {
+            settings,
+            proposal,
+            signer,
+            ..
+        } = self;
+
+        let event = ProposalEvent {
+            event_type,
+            settings_pubkey: settings.key(),
+            proposal_pubkey: proposal.key(),
+            transaction_index: proposal.transaction_index,
+            signer: Some(signer.key()),
+            memo,
+            proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts,
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)
+    }
+
     /// Approve a smart account proposal on behalf of the `signer`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Approve))]
-    pub fn approve_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
+    pub fn approve_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
         let proposal = &mut ctx.accounts.proposal;
         let signer = &mut ctx.accounts.signer;
 
         proposal.approve(signer.key(), usize::from(settings.threshold))?;
 
+        ctx.accounts.log_vote(
+            ProposalEventType::Approve,
+            args.memo,
+            LogAccounts::find(ctx.remaining_accounts),
+        )?;
+
         Ok(())
     }
 
     /// Reject a smart account proposal on behalf of the `signer`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Reject))]
-    pub fn reject_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
+    pub fn reject_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
         let proposal = &mut ctx.accounts.proposal;
         let signer = &mut ctx.accounts.signer;
@@ -105,13 +146,19 @@ impl VoteOnProposal<'_> {
 
         proposal.reject(signer.key(), cutoff)?;
 
+        ctx.accounts.log_vote(
+            ProposalEventType::Reject,
+            args.memo,
+            LogAccounts::find(ctx.remaining_accounts),
+        )?;
+
         Ok(())
     }
 
     /// Cancel a smart account proposal on behalf of the `signer`.
     /// The proposal must be `Approved`.
     #[access_control(ctx.accounts.validate(Vote::Cancel))]
-    pub fn cancel_proposal(ctx: Context<Self>, _args: VoteOnProposalArgs) -> Result<()> {
+    pub fn cancel_proposal(ctx: Context<Self>, args: VoteOnProposalArgs) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
         let proposal = &mut ctx.accounts.proposal;
         let signer = &mut ctx.accounts.signer;
@@ -134,6 +181,12 @@ impl VoteOnProposal<'_> {
             Some(system_program.to_account_info().clone()),
         )?;
 
+        ctx.accounts.log_vote(
+            ProposalEventType::Cancel,
+            args.memo,
+            LogAccounts::find(ctx.remaining_accounts),
+        )?;
+
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use crate::utils::validate_settings_actions;
 
@@ -42,6 +43,8 @@ pub struct CreateSettingsTransaction<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CreateSettingsTransaction<'_> {
@@ -101,6 +104,27 @@ impl CreateSettingsTransaction<'_> {
         // Logs for indexing.
         msg!("transaction index: {}", transaction_index);
 
+        // Log the event
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Create,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction.key(),
+            transaction_index,
+            signer: Some(creator.key()),
+            memo: args.memo,
+            transaction_content: Some(TransactionContent::SettingsTransaction {
+                settings: Settings::try_from_slice(&settings.try_to_vec()?)?,
+                transaction: SettingsTransaction::try_from_slice(&transaction.try_to_vec()?)?,
+            }),
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
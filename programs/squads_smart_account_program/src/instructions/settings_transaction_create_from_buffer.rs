@@ -0,0 +1,134 @@
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::*;
+use crate::state::*;
+use crate::utils::realloc;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateSettingsTransactionFromBuffer<'info> {
+    // The context needed for the CreateSettingsTransaction instruction
+    pub settings_transaction_create: CreateSettingsTransaction<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        // Only the creator can turn the buffer into a settings transaction and reclaim
+        // the rent
+        constraint = transaction_buffer.creator == creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            settings_transaction_create.settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    // Anchor doesn't allow us to use the creator inside of
+    // settings_transaction_create, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = settings_transaction_create.creator.key(),
+    )]
+    pub creator: Signer<'info>,
+}
+
+impl<'info> CreateSettingsTransactionFromBuffer<'info> {
+    pub fn validate(&self, args: &CreateSettingsTransactionArgs) -> Result<()> {
+        let transaction_buffer_account = &self.transaction_buffer;
+
+        // Check that the actions are "empty"
+        require!(
+            args.actions.is_empty(),
+            SmartAccountError::InvalidInstructionArgs
+        );
+
+        // Validate that every chunk of the buffer has been written
+        transaction_buffer_account.validate_written()?;
+
+        // Validate that the final hash matches the buffer
+        transaction_buffer_account.validate_hash()?;
+
+        // Validate that the final size is correct
+        transaction_buffer_account.validate_size()?;
+        Ok(())
+    }
+
+    /// Create a new settings transaction from a completed transaction buffer account
+    /// holding its serialized actions.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_settings_transaction_from_buffer(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: CreateSettingsTransactionArgs,
+    ) -> Result<()> {
+        // Read-only accounts
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        let actions = Vec::<SettingsAction>::deserialize(&mut transaction_buffer.buffer.as_slice())
+            .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
+
+        // Reallocate the transaction account to fit the actions,
+        // since it was initialized with none.
+        realloc(
+            &ctx.accounts
+                .settings_transaction_create
+                .transaction
+                .to_account_info(),
+            SettingsTransaction::size(&actions),
+            Some(
+                ctx.accounts
+                    .settings_transaction_create
+                    .rent_payer
+                    .to_account_info(),
+            ),
+            Some(
+                ctx.accounts
+                    .settings_transaction_create
+                    .system_program
+                    .to_account_info(),
+            ),
+        )?;
+
+        // Create the args for the `create_settings_transaction` instruction
+        let create_args = CreateSettingsTransactionArgs {
+            actions,
+            memo: args.memo,
+        };
+        // Create the context for the `create_settings_transaction` instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.settings_transaction_create,
+            ctx.remaining_accounts,
+            ctx.bumps.settings_transaction_create,
+        );
+
+        // Call the `create_settings_transaction` instruction
+        CreateSettingsTransaction::create_settings_transaction(context, create_args)?;
+
+        // Log the event
+        let settings = &mut ctx.accounts.settings_transaction_create.settings;
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
+        // Anchor will close the `transaction_buffer` account for us.
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -50,9 +51,12 @@ pub struct ExecuteSettingsTransaction<'info> {
 
     /// We might need it in case reallocation is needed.
     pub system_program: Option<Program<'info, System>>,
-    // In case the transaction contains Add(Remove)SpendingLimit actions,
-    // `remaining_accounts` must contain the SpendingLimit accounts to be initialized/closed.
+    // In case the transaction contains Add(Remove/Update)SpendingLimit actions,
+    // `remaining_accounts` must contain the SpendingLimit accounts
+    // to be initialized/closed/updated.
     // remaining_accounts
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl<'info> ExecuteSettingsTransaction<'info> {
@@ -96,7 +100,23 @@ impl<'info> ExecuteSettingsTransaction<'info> {
         let current_timestamp = Clock::get()?.unix_timestamp;
 
         for action in self.transaction.actions.iter() {
-            if let SettingsAction::AddSpendingLimit { expiration, .. } = action {
+            if let SettingsAction::AddSpendingLimit { expiration, .. }
+            | SettingsAction::AddSpendingLimitWithOptions { expiration, .. } = action
+            {
+                require!(
+                    *expiration > current_timestamp,
+                    SmartAccountError::SpendingLimitExpired
+                );
+            }
+            if let SettingsAction::UpdateSpendingLimit {
+                update:
+                    SpendingLimitUpdate {
+                        expiration: Some(expiration),
+                        ..
+                    },
+                ..
+            } = action
+            {
                 require!(
                     *expiration > current_timestamp,
                     SmartAccountError::SpendingLimitExpired
@@ -132,8 +152,7 @@ impl<'info> ExecuteSettingsTransaction<'info> {
 
         // Make sure the smart account can fit the updated state: added signers or newly set rent_collector.
         Settings::realloc_if_needed(
-            settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .rent_payer
                 .as_ref()
@@ -152,6 +171,62 @@ impl<'info> ExecuteSettingsTransaction<'info> {
             timestamp: Clock::get()?.unix_timestamp,
         };
 
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Execute,
+            settings_pubkey: settings_key,
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            transaction_content: Some(TransactionContent::SettingsTransaction {
+                settings: Settings::try_from_slice(&settings.try_to_vec()?)?,
+                transaction: SettingsTransaction::try_from_slice(&transaction.try_to_vec()?)?,
+            }),
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
+        let event = ProposalEvent {
+            event_type: ProposalEventType::Execute,
+            settings_pubkey: settings_key,
+            proposal_pubkey: proposal.key(),
+            transaction_index: transaction.index,
+            signer: Some(ctx.accounts.signer.key()),
+            memo: None,
+            proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+        };
+        SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
+        for action in transaction.actions.iter() {
+            if let SettingsAction::UpdateSpendingLimit {
+                spending_limit,
+                update,
+            } = action
+            {
+                let spending_limit_data = ctx
+                    .remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == spending_limit)
+                    .ok_or(SmartAccountError::MissingAccount)?
+                    .try_borrow_data()?;
+
+                let event = UpdateSpendingLimitEvent {
+                    settings_pubkey: settings_key,
+                    spending_limit_pubkey: *spending_limit,
+                    update: update.clone(),
+                    memo: None,
+                    spending_limit: SpendingLimit::deserialize(&mut &spending_limit_data[8..])?,
+                };
+                SmartAccountEvent::UpdateSpendingLimitEvent(event).log(&log_authority_info)?;
+            }
+        }
+
         Ok(())
     }
 }
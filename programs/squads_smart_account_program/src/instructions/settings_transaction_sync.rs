@@ -1,4 +1,4 @@
-use account_events::{AddSpendingLimitEvent, RemoveSpendingLimitEvent};
+use account_events::{AddSpendingLimitEvent, RemoveSpendingLimitEvent, UpdateSpendingLimitEvent};
 use anchor_lang::prelude::*;
 
 use crate::{errors::*, events::*, program::SquadsSmartAccountProgram, state::*, utils::*};
@@ -31,8 +31,10 @@ pub struct SyncSettingsTransaction<'info> {
     pub system_program: Option<Program<'info, System>>,
     // `remaining_accounts` must include the following accounts in the exact order:
     // 1. The amount of signers specified in `num_signers`
-    // 2. Any SpendingLimit accounts that need to be initialized/closed based on actions
+    // 2. Any SpendingLimit accounts that need to be initialized/closed/updated based on actions
     pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl<'info> SyncSettingsTransaction<'info> {
@@ -83,8 +85,7 @@ impl<'info> SyncSettingsTransaction<'info> {
 
         // Make sure the smart account can fit the updated state: added signers or newly set archival_authority.
         Settings::realloc_if_needed(
-            settings.to_account_info(),
-            settings.signers.len(),
+            settings,
             ctx.accounts
                 .rent_payer
                 .as_ref()
@@ -112,13 +113,14 @@ impl<'info> SyncSettingsTransaction<'info> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::SynchronousSettingsTransactionEvent(event).log(&log_authority_info)?;
 
         for action in args.actions.iter() {
             match action {
-                SettingsAction::AddSpendingLimit { seed, .. } => {
+                SettingsAction::AddSpendingLimit { seed, .. }
+                | SettingsAction::AddSpendingLimitWithOptions { seed, .. } => {
                     let spending_limit_pubkey = Pubkey::find_program_address(
                         &[
                             SEED_PREFIX,
@@ -141,7 +143,7 @@ impl<'info> SyncSettingsTransaction<'info> {
                     let event = AddSpendingLimitEvent {
                         settings_pubkey: settings_key,
                         spending_limit_pubkey: spending_limit_pubkey,
-                        spending_limit: SpendingLimit::try_from_slice(&spending_limit_data[8..])?,
+                        spending_limit: SpendingLimit::deserialize(&mut &spending_limit_data[8..])?,
                     };
                     SmartAccountEvent::AddSpendingLimitEvent(event).log(&log_authority_info)?;
                 }
@@ -152,6 +154,26 @@ impl<'info> SyncSettingsTransaction<'info> {
                     };
                     SmartAccountEvent::RemoveSpendingLimitEvent(event).log(&log_authority_info)?;
                 }
+                SettingsAction::UpdateSpendingLimit {
+                    spending_limit,
+                    update,
+                } => {
+                    let spending_limit_data = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == spending_limit)
+                        .ok_or(SmartAccountError::MissingAccount)?
+                        .try_borrow_data()?;
+
+                    let event = UpdateSpendingLimitEvent {
+                        settings_pubkey: settings_key,
+                        spending_limit_pubkey: *spending_limit,
+                        update: update.clone(),
+                        memo: args.memo.clone(),
+                        spending_limit: SpendingLimit::deserialize(&mut &spending_limit_data[8..])?,
+                    };
+                    SmartAccountEvent::UpdateSpendingLimitEvent(event).log(&log_authority_info)?;
+                }
                 _ => {
                     continue;
                 }
@@ -47,6 +47,8 @@ pub struct CreateSmartAccount<'info> {
 
     pub system_program: Program<'info, System>,
     pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl<'info> CreateSmartAccount<'info> {
@@ -98,7 +100,9 @@ impl<'info> CreateSmartAccount<'info> {
             signers,
             account_utilization: 0,
             _reserved1: 0,
-            _reserved2: 0,
+            extension: SettingsExtension::V1 {
+                time_lock_bypass: TimeLockBypass::Disabled,
+            },
         };
 
         // Initialize the settings account with the configuration.
@@ -112,6 +116,14 @@ impl<'info> CreateSmartAccount<'info> {
         settings_configuration
             .try_serialize(&mut &mut settings_account_info.data.borrow_mut()[..])?;
 
+        // Initialize the event sequence of the settings, if passed, so their events are sequenced.
+        let event_sequence_info = EventSequence::find_and_initialize(
+            &settings_pubkey,
+            &ctx.accounts.creator.to_account_info(),
+            ctx.remaining_accounts,
+            &ctx.accounts.system_program,
+        )?;
+
         settings_configuration.invariant()?;
 
         // Check if the creation fee is set and transfer the fee to the treasury if necessary.
@@ -143,7 +155,10 @@ impl<'info> CreateSmartAccount<'info> {
             authority: settings_account_info.clone(),
             authority_seeds: get_settings_signer_seeds(settings_seed),
             bump: settings_bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: Some(LogAccounts {
+                program: ctx.accounts.program.key(),
+                event_sequence: event_sequence_info.cloned(),
+            }),
         };
         SmartAccountEvent::CreateSmartAccountEvent(event).log(&log_authority_info)?;
 
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -32,6 +33,8 @@ pub struct CloseTransactionBuffer<'info> {
 
     /// The signer on the smart account that created the TransactionBuffer.
     pub creator: Signer<'info>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CloseTransactionBuffer<'_> {
@@ -42,6 +45,29 @@ impl CloseTransactionBuffer<'_> {
     /// Close a transaction buffer account.
     #[access_control(ctx.accounts.validate())]
     pub fn close_transaction_buffer(ctx: Context<Self>) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        // Log the event
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
+        // Anchor will close the `transaction_buffer` account for us.
         Ok(())
     }
 }
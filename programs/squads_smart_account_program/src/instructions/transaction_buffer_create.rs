@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
-use crate::state::MAX_BUFFER_SIZE;
+use crate::events::*;
+use crate::state::MAX_FINAL_BUFFER_SIZE;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateTransactionBufferArgs {
@@ -30,7 +32,7 @@ pub struct CreateTransactionBuffer<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = TransactionBuffer::size(args.final_buffer_size)?,
+        space = TransactionBuffer::initial_size(args.final_buffer_size)?,
         seeds = [
             SEED_PREFIX,
             settings.key().as_ref(),
@@ -50,6 +52,8 @@ pub struct CreateTransactionBuffer<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CreateTransactionBuffer<'_> {
@@ -69,9 +73,9 @@ impl CreateTransactionBuffer<'_> {
             SmartAccountError::Unauthorized
         );
 
-        // Final Buffer Size must not exceed 4000 bytes
+        // Final Buffer Size must not exceed `MAX_FINAL_BUFFER_SIZE` bytes
         require!(
-            args.final_buffer_size as usize <= MAX_BUFFER_SIZE,
+            args.final_buffer_size as usize <= MAX_FINAL_BUFFER_SIZE,
             SmartAccountError::FinalBufferSizeExceeded
         );
         Ok(())
@@ -100,10 +104,37 @@ impl CreateTransactionBuffer<'_> {
         transaction_buffer.final_buffer_hash = args.final_buffer_hash;
         transaction_buffer.final_buffer_size = args.final_buffer_size;
         transaction_buffer.buffer = args.buffer;
+        transaction_buffer.written_chunks = AccountExtension::new(vec![
+            0;
+            TransactionBuffer::written_chunks_length(
+                args.final_buffer_size
+            )
+        ]);
+        let buffer_size = transaction_buffer.buffer.len();
+        transaction_buffer.mark_written(0, buffer_size);
 
         // Invariant function on the transaction buffer
         transaction_buffer.invariant()?;
 
+        // Log the event
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Create,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
+use crate::utils::realloc;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ExtendTransactionBufferArgs {
@@ -35,6 +37,16 @@ pub struct ExtendTransactionBuffer<'info> {
 
     /// The signer on the smart account that created the TransactionBuffer.
     pub creator: Signer<'info>,
+
+    /// The account that will be charged in case the transaction buffer account needs to
+    /// reallocate space, when extending buffers larger than `MAX_BUFFER_SIZE`.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl ExtendTransactionBuffer<'_> {
@@ -89,13 +101,54 @@ impl ExtendTransactionBuffer<'_> {
         let buffer_slice_extension = args.buffer;
 
         // Extend the buffer inside the transaction buffer
+        let current_buffer_size = transaction_buffer.buffer.len();
         transaction_buffer
             .buffer
             .extend_from_slice(&buffer_slice_extension);
+        let new_buffer_size = transaction_buffer.buffer.len();
+        transaction_buffer.mark_written(current_buffer_size, new_buffer_size);
 
         // Invariant function on the transaction buffer
         transaction_buffer.invariant()?;
 
+        // Make sure the transaction buffer account can fit the extended buffer.
+        let transaction_buffer_info = transaction_buffer.to_account_info();
+        let new_size = transaction_buffer.current_size()?;
+        if transaction_buffer_info.data_len() < new_size {
+            realloc(
+                &transaction_buffer_info,
+                new_size,
+                ctx.accounts
+                    .rent_payer
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+                ctx.accounts
+                    .system_program
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+            )?;
+        }
+
+        // Log the event
+        let settings = &ctx.accounts.settings;
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Extend,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
         Ok(())
     }
 }
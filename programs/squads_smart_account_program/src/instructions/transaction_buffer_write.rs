@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
+use crate::state::*;
+use crate::utils::realloc;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WriteTransactionBufferArgs {
+    /// Offset to write `buffer` at, a multiple of `BUFFER_CHUNK_SIZE`.
+    pub offset: u16,
+    /// Chunk of the buffer to write, `BUFFER_CHUNK_SIZE` bytes
+    /// unless it's the last chunk of the buffer.
+    pub buffer: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: WriteTransactionBufferArgs)]
+pub struct WriteTransactionBuffer<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        mut,
+        // Only the creator can write to the buffer
+        constraint = transaction_buffer.creator == creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+
+    /// The signer on the smart account that created the TransactionBuffer.
+    pub creator: Signer<'info>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+
+    /// The account that will be charged in case the transaction buffer account needs to
+    /// reallocate space to hold the whole buffer.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl WriteTransactionBuffer<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            settings, creator, ..
+        } = self;
+
+        // creator is still a signer on the smart account
+        require!(
+            settings.is_signer(creator.key()).is_some(),
+            SmartAccountError::NotASigner
+        );
+
+        // creator still has initiate permissions
+        require!(
+            settings.signer_has_permission(creator.key(), Permission::Initiate),
+            SmartAccountError::Unauthorized
+        );
+
+        Ok(())
+    }
+
+    /// Write a chunk of the transaction buffer at the given offset.
+    /// Chunks can be written in any order and written again, so that uploads can be resumed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn write_transaction_buffer(
+        ctx: Context<Self>,
+        args: WriteTransactionBufferArgs,
+    ) -> Result<()> {
+        // Mutable Accounts
+        let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+
+        // Write the chunk inside the transaction buffer
+        transaction_buffer.write_chunk(args.offset as usize, &args.buffer)?;
+
+        // Invariant function on the transaction buffer
+        transaction_buffer.invariant()?;
+
+        // Make sure the transaction buffer account can fit the whole buffer.
+        let transaction_buffer_info = transaction_buffer.to_account_info();
+        let new_size = transaction_buffer.current_size()?;
+        if transaction_buffer_info.data_len() < new_size {
+            realloc(
+                &transaction_buffer_info,
+                new_size,
+                ctx.accounts
+                    .rent_payer
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+                ctx.accounts
+                    .system_program
+                    .as_ref()
+                    .map(ToAccountInfo::to_account_info),
+            )?;
+        }
+
+        // Log the event
+        let settings = &ctx.accounts.settings;
+        let event = TransactionBufferEvent {
+            event_type: TransactionBufferEventType::Write,
+            settings_pubkey: settings.key(),
+            transaction_buffer_pubkey: transaction_buffer.key(),
+            buffer_index: transaction_buffer.buffer_index,
+            creator: transaction_buffer.creator,
+            final_buffer_hash: transaction_buffer.final_buffer_hash,
+            final_buffer_size: transaction_buffer.final_buffer_size,
+            buffer_size: transaction_buffer.buffer.len() as u16,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)?;
+
+        Ok(())
+    }
+}
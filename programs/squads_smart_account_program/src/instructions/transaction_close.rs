@@ -12,9 +12,16 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
 use crate::state::*;
 use crate::utils;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseTransactionArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
 #[derive(Accounts)]
 pub struct CloseSettingsTransaction<'info> {
     #[account(
@@ -60,6 +67,8 @@ pub struct CloseSettingsTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CloseSettingsTransaction<'_> {
@@ -68,6 +77,15 @@ impl CloseSettingsTransaction<'_> {
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale.
     pub fn close_settings_transaction(ctx: Context<Self>) -> Result<()> {
+        Self::close_settings_transaction_v2(ctx, CloseTransactionArgs { memo: None })
+    }
+
+    /// Closes a `SettingsTransaction` and the corresponding `Proposal`,
+    /// logging the `memo` of the `args` in the events.
+    pub fn close_settings_transaction_v2(
+        ctx: Context<Self>,
+        args: CloseTransactionArgs,
+    ) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let transaction = &ctx.accounts.transaction;
         let proposal = &mut ctx.accounts.proposal;
@@ -111,6 +129,37 @@ impl CloseSettingsTransaction<'_> {
 
         require!(can_close, SmartAccountError::InvalidProposalStatus);
 
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: None,
+            memo: args.memo.clone(),
+            transaction_content: None,
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
+        if let Some(proposal_account) = &proposal_account {
+            let event = ProposalEvent {
+                event_type: ProposalEventType::Close,
+                settings_pubkey: settings.key(),
+                proposal_pubkey: proposal.key(),
+                transaction_index: transaction.index,
+                signer: None,
+                memo: args.memo.clone(),
+                proposal: Some(Proposal::try_from_slice(&proposal_account.try_to_vec()?)?),
+            };
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
         // Close the `proposal` account if exists.
         Proposal::close_if_exists(
             proposal_account,
@@ -168,6 +217,8 @@ pub struct CloseTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CloseTransaction<'_> {
@@ -176,6 +227,12 @@ impl CloseTransaction<'_> {
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale and not `Approved`.
     pub fn close_transaction(ctx: Context<Self>) -> Result<()> {
+        Self::close_transaction_v2(ctx, CloseTransactionArgs { memo: None })
+    }
+
+    /// Closes a `Transaction` and the corresponding `Proposal`,
+    /// logging the `memo` of the `args` in the events.
+    pub fn close_transaction_v2(ctx: Context<Self>, args: CloseTransactionArgs) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let transaction = &ctx.accounts.transaction;
         let proposal = &mut ctx.accounts.proposal;
@@ -219,6 +276,37 @@ impl CloseTransaction<'_> {
 
         require!(can_close, SmartAccountError::InvalidProposalStatus);
 
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = TransactionEvent {
+            event_type: TransactionEventType::Close,
+            settings_pubkey: settings.key(),
+            transaction_pubkey: transaction.key(),
+            transaction_index: transaction.index,
+            signer: None,
+            memo: args.memo.clone(),
+            transaction_content: None,
+        };
+        SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
+        if let Some(proposal_account) = &proposal_account {
+            let event = ProposalEvent {
+                event_type: ProposalEventType::Close,
+                settings_pubkey: settings.key(),
+                proposal_pubkey: proposal.key(),
+                transaction_index: transaction.index,
+                signer: None,
+                memo: args.memo.clone(),
+                proposal: Some(Proposal::try_from_slice(&proposal_account.try_to_vec()?)?),
+            };
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
         // Close the `proposal` account if exists.
         Proposal::close_if_exists(
             proposal_account,
@@ -270,6 +358,8 @@ pub struct CloseBatchTransaction<'info> {
     pub transaction_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CloseBatchTransaction<'_> {
@@ -343,12 +433,43 @@ impl CloseBatchTransaction<'_> {
     /// and the operation is only allowed if any of the following conditions is met:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale and not `Approved`.
-    #[access_control(ctx.accounts.validate())]
     pub fn close_batch_transaction(ctx: Context<Self>) -> Result<()> {
+        Self::close_batch_transaction_v2(ctx, CloseTransactionArgs { memo: None })
+    }
+
+    /// Closes a `BatchTransaction` belonging to the `batch` and `proposal`,
+    /// logging the `memo` of the `args` in the event.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_batch_transaction_v2(
+        ctx: Context<Self>,
+        args: CloseTransactionArgs,
+    ) -> Result<()> {
         let batch = &mut ctx.accounts.batch;
 
         batch.size = batch.size.checked_sub(1).expect("overflow");
 
+        // Log the event
+        let settings = &ctx.accounts.settings;
+        let transaction = &ctx.accounts.transaction;
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = BatchEvent {
+            event_type: BatchEventType::CloseTransaction,
+            settings_pubkey: settings.key(),
+            batch_pubkey: batch.key(),
+            batch_index: batch.index,
+            signer: None,
+            memo: args.memo.clone(),
+            transaction_pubkey: Some(transaction.key()),
+            transaction: Some(TransactionSummary::new(&**transaction)?),
+            batch: Batch::try_from_slice(&batch.try_to_vec()?)?,
+        };
+        SmartAccountEvent::BatchEvent(event).log(&log_authority_info)?;
+
         // Anchor macro will close the `transaction` account for us.
 
         Ok(())
@@ -403,6 +524,8 @@ pub struct CloseBatch<'info> {
     pub batch_rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
 }
 
 impl CloseBatch<'_> {
@@ -412,6 +535,12 @@ impl CloseBatch<'_> {
     /// This instruction is only allowed to be executed when all `BatchTransaction` accounts
     /// in the `batch` are already closed: `batch.size == 0`.
     pub fn close_batch(ctx: Context<Self>) -> Result<()> {
+        Self::close_batch_v2(ctx, CloseTransactionArgs { memo: None })
+    }
+
+    /// Closes Batch and the corresponding Proposal accounts,
+    /// logging the `memo` of the `args` in the events.
+    pub fn close_batch_v2(ctx: Context<Self>, args: CloseTransactionArgs) -> Result<()> {
         let settings = &ctx.accounts.settings;
         let batch = &ctx.accounts.batch;
         let proposal = &mut ctx.accounts.proposal;
@@ -458,6 +587,39 @@ impl CloseBatch<'_> {
         // Batch must be empty.
         require_eq!(batch.size, 0, SmartAccountError::BatchNotEmpty);
 
+        // Log the events
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::find(ctx.remaining_accounts),
+        };
+        let event = BatchEvent {
+            event_type: BatchEventType::Close,
+            settings_pubkey: settings.key(),
+            batch_pubkey: batch.key(),
+            batch_index: batch.index,
+            signer: None,
+            memo: args.memo.clone(),
+            transaction_pubkey: None,
+            transaction: None,
+            batch: Batch::try_from_slice(&batch.try_to_vec()?)?,
+        };
+        SmartAccountEvent::BatchEvent(event).log(&log_authority_info)?;
+
+        if let Some(proposal_account) = &proposal_account {
+            let event = ProposalEvent {
+                event_type: ProposalEventType::Close,
+                settings_pubkey: settings.key(),
+                proposal_pubkey: proposal.key(),
+                transaction_index: batch.index,
+                signer: None,
+                memo: args.memo.clone(),
+                proposal: Some(Proposal::try_from_slice(&proposal_account.try_to_vec()?)?),
+            };
+            SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+        }
+
         // Close the `proposal` account if exists.
         Proposal::close_if_exists(
             proposal_account,
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
 use crate::state::*;
 use crate::utils::*;
 
@@ -14,6 +16,35 @@ pub struct CreateTransactionArgs {
     pub memo: Option<String>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTransactionV2Args {
+    /// Index of the smart account this transaction belongs to.
+    pub account_index: u8,
+    /// Number of ephemeral signing PDAs required by the transaction.
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+    pub memo: Option<String>,
+    /// Store the message as a `CompressedTransactionMessage`, which takes less rent
+    /// but costs more compute units to execute.
+    pub compress_message: bool,
+    /// Store only the hash of the serialized `TransactionMessage`, instead of the message,
+    /// which is then revealed at execution. `transaction_message` must be empty.
+    pub message_hash: Option<[u8; 32]>,
+}
+
+impl From<CreateTransactionArgs> for CreateTransactionV2Args {
+    fn from(args: CreateTransactionArgs) -> Self {
+        Self {
+            account_index: args.account_index,
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: args.transaction_message,
+            memo: args.memo,
+            compress_message: false,
+            message_hash: None,
+        }
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(args: CreateTransactionArgs)]
 pub struct CreateTransaction<'info> {
@@ -27,7 +58,7 @@ pub struct CreateTransaction<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = Transaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        space = Transaction::size(args.ephemeral_signers, &args.transaction_message, false, None)?,
         seeds = [
             SEED_PREFIX,
             settings.key().as_ref(),
@@ -46,89 +77,215 @@ pub struct CreateTransaction<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    // The `EventSequence` of the settings and the program account can be passed
+    // as optional trailing accounts, in this order, to log sequenced events.
+}
+
+/// Same accounts as `CreateTransaction`, with the transaction sized for the message
+/// encoding selected in `CreateTransactionV2Args` and the logging program required.
+#[derive(Accounts)]
+#[instruction(args: CreateTransactionV2Args)]
+pub struct CreateTransactionV2<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Account<'info, Settings>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Transaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            args.compress_message,
+            args.message_hash,
+        )?,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &settings.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, Transaction>,
+
+    /// The member of the multisig that is creating the transaction.
+    pub creator: Signer<'info>,
+
+    /// The payer for the transaction account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl<'info> CreateTransaction<'info> {
     pub fn validate(&self) -> Result<()> {
-        let Self {
-            settings, creator, ..
-        } = self;
-
-        // creator
-        require!(
-            settings.is_signer(creator.key()).is_some(),
-            SmartAccountError::NotASigner
-        );
-        require!(
-            settings.signer_has_permission(creator.key(), Permission::Initiate),
-            SmartAccountError::Unauthorized
-        );
-
-        Ok(())
+        validate_creator(&self.settings, self.creator.key())
     }
 
     /// Create a new vault transaction.
     #[access_control(ctx.accounts.validate())]
     pub fn create_transaction(ctx: Context<Self>, args: CreateTransactionArgs) -> Result<()> {
-        let settings = &mut ctx.accounts.settings;
-        let transaction = &mut ctx.accounts.transaction;
-        let creator = &mut ctx.accounts.creator;
-        let rent_payer = &mut ctx.accounts.rent_payer;
+        let accounts = ctx.accounts;
+        accounts.transaction.bump = ctx.bumps.transaction;
 
-        let transaction_message =
-            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+        initialize_transaction(
+            ctx.program_id,
+            &mut accounts.settings,
+            &mut accounts.transaction,
+            accounts.creator.key(),
+            accounts.rent_payer.key(),
+            LogAccounts::find(ctx.remaining_accounts),
+            args.into(),
+        )
+    }
+}
 
-        let settings_key = settings.key();
-        let transaction_key = transaction.key();
+impl<'info> CreateTransactionV2<'info> {
+    pub fn validate(&self, args: &CreateTransactionV2Args) -> Result<()> {
+        validate_creator(&self.settings, self.creator.key())?;
 
-        let smart_account_seeds = &[
-            SEED_PREFIX,
-            settings_key.as_ref(),
-            SEED_SMART_ACCOUNT,
-            &args.account_index.to_le_bytes(),
-        ];
-        let (_, smart_account_bump) =
-            Pubkey::find_program_address(smart_account_seeds, ctx.program_id);
-
-        let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
-            .map(|ephemeral_signer_index| {
-                let ephemeral_signer_seeds = &[
-                    SEED_PREFIX,
-                    transaction_key.as_ref(),
-                    SEED_EPHEMERAL_SIGNER,
-                    &ephemeral_signer_index.to_le_bytes(),
-                ];
-
-                let (_, bump) =
-                    Pubkey::find_program_address(ephemeral_signer_seeds, ctx.program_id);
-                bump
-            })
-            .collect();
-
-        // Increment the transaction index.
-        let transaction_index = settings.transaction_index.checked_add(1).unwrap();
-
-        // Initialize the transaction fields.
-        transaction.settings = settings_key;
-        transaction.creator = creator.key();
-        transaction.rent_collector = rent_payer.key();
-        transaction.index = transaction_index;
-        transaction.bump = ctx.bumps.transaction;
-        transaction.account_index = args.account_index;
-        transaction.account_bump = smart_account_bump;
-        transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
-        transaction.message = transaction_message.try_into()?;
-
-        // Updated last transaction index in the settings account.
-        settings.transaction_index = transaction_index;
-
-        settings.invariant()?;
-
-        // Logs for indexing.
-        msg!("transaction index: {}", transaction_index);
+        // message_hash
+        // Only the hash of the message is stored, so it can't be stored too.
+        if args.message_hash.is_some() {
+            require!(
+                args.transaction_message.is_empty() && !args.compress_message,
+                SmartAccountError::InvalidInstructionArgs
+            );
+        }
 
         Ok(())
     }
+
+    /// Create a new vault transaction, storing its message compressed or only its hash.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_transaction_v2(ctx: Context<Self>, args: CreateTransactionV2Args) -> Result<()> {
+        let accounts = ctx.accounts;
+        accounts.transaction.bump = ctx.bumps.transaction;
+
+        initialize_transaction(
+            ctx.program_id,
+            &mut accounts.settings,
+            &mut accounts.transaction,
+            accounts.creator.key(),
+            accounts.rent_payer.key(),
+            LogAccounts::new(accounts.program.key(), ctx.remaining_accounts),
+            args,
+        )
+    }
+}
+
+fn validate_creator(settings: &Settings, creator: Pubkey) -> Result<()> {
+    require!(
+        settings.is_signer(creator).is_some(),
+        SmartAccountError::NotASigner
+    );
+    require!(
+        settings.signer_has_permission(creator, Permission::Initiate),
+        SmartAccountError::Unauthorized
+    );
+
+    Ok(())
+}
+
+/// Initializes the fields of a new `transaction` (except its `bump`) and increments the
+/// transaction index of the `settings`.
+fn initialize_transaction<'info>(
+    program_id: &Pubkey,
+    settings: &mut Account<'info, Settings>,
+    transaction: &mut Account<'info, Transaction>,
+    creator: Pubkey,
+    rent_payer: Pubkey,
+    log_accounts: Option<LogAccounts<'_>>,
+    args: CreateTransactionV2Args,
+) -> Result<()> {
+    let settings_key = settings.key();
+    let transaction_key = transaction.key();
+
+    let smart_account_seeds = &[
+        SEED_PREFIX,
+        settings_key.as_ref(),
+        SEED_SMART_ACCOUNT,
+        &args.account_index.to_le_bytes(),
+    ];
+    let (_, smart_account_bump) = Pubkey::find_program_address(smart_account_seeds, program_id);
+
+    let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let ephemeral_signer_seeds = &[
+                SEED_PREFIX,
+                transaction_key.as_ref(),
+                SEED_EPHEMERAL_SIGNER,
+                &ephemeral_signer_index.to_le_bytes(),
+            ];
+
+            let (_, bump) = Pubkey::find_program_address(ephemeral_signer_seeds, program_id);
+            bump
+        })
+        .collect();
+
+    // Increment the transaction index.
+    let transaction_index = settings.transaction_index.checked_add(1).unwrap();
+
+    // Initialize the transaction fields.
+    transaction.settings = settings_key;
+    transaction.creator = creator;
+    transaction.rent_collector = rent_payer;
+    transaction.index = transaction_index;
+    transaction.account_index = args.account_index;
+    transaction.account_bump = smart_account_bump;
+    transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
+    transaction.extension = AccountExtension::new(TransactionExtension::default());
+    if args.message_hash.is_some() {
+        transaction.extension.message_hash = args.message_hash;
+    } else {
+        let transaction_message: SmartAccountTransactionMessage =
+            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?
+                .try_into()?;
+        if args.compress_message {
+            transaction.extension.compressed_message =
+                CompressedTransactionMessage::compress(&transaction_message);
+        } else {
+            transaction.message = transaction_message;
+        }
+    }
+
+    // Updated last transaction index in the settings account.
+    settings.transaction_index = transaction_index;
+
+    settings.invariant()?;
+
+    // Logs for indexing.
+    msg!("transaction index: {}", transaction_index);
+
+    // Log the event
+    let event = TransactionEvent {
+        event_type: TransactionEventType::Create,
+        settings_pubkey: settings_key,
+        transaction_pubkey: transaction_key,
+        transaction_index,
+        signer: Some(creator),
+        memo: args.memo,
+        transaction_content: Some(TransactionContent::Transaction(TransactionSummary::new(
+            &**transaction,
+        )?)),
+    };
+    let log_authority_info = LogAuthorityInfo {
+        authority: settings.to_account_info(),
+        authority_seeds: get_settings_signer_seeds(settings.seed),
+        bump: settings.bump,
+        log_accounts,
+    };
+    SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
+    Ok(())
 }
 
 /// Unvalidated instruction data, must be treated as untrusted.
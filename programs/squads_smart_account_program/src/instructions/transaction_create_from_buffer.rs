@@ -1,4 +1,5 @@
 use crate::errors::*;
+use crate::events::*;
 use crate::instructions::*;
 use crate::state::*;
 use anchor_lang::{prelude::*, system_program};
@@ -34,75 +35,66 @@ pub struct CreateTransactionFromBuffer<'info> {
     pub creator: Signer<'info>,
 }
 
-impl<'info> CreateTransactionFromBuffer<'info> {
-    pub fn validate(&self, args: &CreateTransactionArgs) -> Result<()> {
-        let transaction_buffer_account = &self.transaction_buffer;
+/// Same accounts as `CreateTransactionFromBuffer`, for `CreateTransactionV2Args`.
+#[derive(Accounts)]
+pub struct CreateTransactionFromBufferV2<'info> {
+    // The context needed for the CreateTransactionV2 instruction
+    pub transaction_create: CreateTransactionV2<'info>,
 
-        // Check that the transaction message is "empty"
-        require!(
-            args.transaction_message == vec![0, 0, 0, 0, 0, 0],
-            SmartAccountError::InvalidInstructionArgs
-        );
+    #[account(
+        mut,
+        close = creator,
+        // Only the creator can turn the buffer into a transaction and reclaim
+        // the rent
+        constraint = transaction_buffer.creator == creator.key() @ SmartAccountError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            transaction_create.settings.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
 
-        // Validate that the final hash matches the buffer
-        transaction_buffer_account.validate_hash()?;
+    // Anchor doesn't allow us to use the creator inside of
+    // transaction_create, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = transaction_create.creator.key(),
+    )]
+    pub creator: Signer<'info>,
+}
 
-        // Validate that the final size is correct
-        transaction_buffer_account.validate_size()?;
-        Ok(())
+impl<'info> CreateTransactionFromBuffer<'info> {
+    pub fn validate(&self, args: &CreateTransactionArgs) -> Result<()> {
+        validate_buffer(&self.transaction_buffer, &args.transaction_message)
     }
+
     /// Create a new Transaction from a completed transaction buffer account.
     #[access_control(ctx.accounts.validate(&args))]
     pub fn create_transaction_from_buffer(
         ctx: Context<'_, '_, 'info, 'info, Self>,
         args: CreateTransactionArgs,
     ) -> Result<()> {
-        // Account infos necessary for reallocation
-        let transaction_account_info = &ctx
-            .accounts
-            .transaction_create
-            .transaction
-            .to_account_info();
-        let rent_payer_account_info = &ctx
-            .accounts
-            .transaction_create
-            .rent_payer
-            .to_account_info();
-
-        let system_program = &ctx
-            .accounts
-            .transaction_create
-            .system_program
-            .to_account_info();
-
-        // Read-only accounts
+        let transaction_create = &ctx.accounts.transaction_create;
         let transaction_buffer = &ctx.accounts.transaction_buffer;
 
         // Calculate the new required length of the transaction account,
         // since it was initialized with an empty transaction message
-        let new_len =
-            Transaction::size(args.ephemeral_signers, transaction_buffer.buffer.as_slice())?;
-
-        // Calculate the rent exemption for new length
-        let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
-
-        // Check the difference between the rent exemption and the current lamports
-        let top_up_lamports =
-            rent_exempt_lamports.saturating_sub(transaction_account_info.lamports());
-
-        // System Transfer the remaining difference to the transaction account
-        let transfer_context = CpiContext::new(
-            system_program.to_account_info(),
-            system_program::Transfer {
-                from: rent_payer_account_info.clone(),
-                to: transaction_account_info.clone(),
-            },
-        );
-        system_program::transfer(transfer_context, top_up_lamports)?;
-
-        // Reallocate the transaction account to the new length of the
-        // actual transaction message
-        AccountInfo::realloc(&transaction_account_info, new_len, true)?;
+        let new_len = Transaction::size(
+            args.ephemeral_signers,
+            transaction_buffer.buffer.as_slice(),
+            false,
+            None,
+        )?;
+        realloc_transaction(
+            &transaction_create.transaction.to_account_info(),
+            &transaction_create.rent_payer.to_account_info(),
+            &transaction_create.system_program.to_account_info(),
+            new_len,
+        )?;
 
         // Create the args for the `create_transaction` instruction
         let create_args = CreateTransactionArgs {
@@ -122,6 +114,157 @@ impl<'info> CreateTransactionFromBuffer<'info> {
         // Call the `create_transaction` instruction
         CreateTransaction::create_transaction(context, create_args)?;
 
+        let transaction_create = &mut ctx.accounts.transaction_create;
+        log_buffer_close(
+            &mut transaction_create.settings,
+            &ctx.accounts.transaction_buffer,
+            LogAccounts::find(ctx.remaining_accounts),
+        )?;
+
+        // Anchor will close the `transaction_buffer` account for us.
         Ok(())
     }
 }
+
+impl<'info> CreateTransactionFromBufferV2<'info> {
+    pub fn validate(&self, args: &CreateTransactionV2Args) -> Result<()> {
+        // The message is stored, not its hash
+        require!(
+            args.message_hash.is_none(),
+            SmartAccountError::InvalidInstructionArgs
+        );
+
+        validate_buffer(&self.transaction_buffer, &args.transaction_message)
+    }
+
+    /// Create a new Transaction from a completed transaction buffer account,
+    /// optionally storing its message compressed.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn create_transaction_from_buffer_v2(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: CreateTransactionV2Args,
+    ) -> Result<()> {
+        let transaction_create = &ctx.accounts.transaction_create;
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        // Calculate the new required length of the transaction account,
+        // since it was initialized with an empty transaction message
+        let new_len = Transaction::size(
+            args.ephemeral_signers,
+            transaction_buffer.buffer.as_slice(),
+            args.compress_message,
+            None,
+        )?;
+        realloc_transaction(
+            &transaction_create.transaction.to_account_info(),
+            &transaction_create.rent_payer.to_account_info(),
+            &transaction_create.system_program.to_account_info(),
+            new_len,
+        )?;
+
+        // Create the args for the `create_transaction_v2` instruction
+        let create_args = CreateTransactionV2Args {
+            account_index: args.account_index,
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: transaction_buffer.buffer.clone(),
+            memo: args.memo,
+            compress_message: args.compress_message,
+            message_hash: None,
+        };
+        // Create the context for the `create_transaction_v2` instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.transaction_create,
+            ctx.remaining_accounts,
+            ctx.bumps.transaction_create,
+        );
+
+        // Call the `create_transaction_v2` instruction
+        CreateTransactionV2::create_transaction_v2(context, create_args)?;
+
+        let transaction_create = &mut ctx.accounts.transaction_create;
+        log_buffer_close(
+            &mut transaction_create.settings,
+            &ctx.accounts.transaction_buffer,
+            LogAccounts::new(transaction_create.program.key(), ctx.remaining_accounts),
+        )?;
+
+        // Anchor will close the `transaction_buffer` account for us.
+        Ok(())
+    }
+}
+
+fn validate_buffer(
+    transaction_buffer: &TransactionBuffer,
+    transaction_message: &[u8],
+) -> Result<()> {
+    // Check that the transaction message is "empty"
+    require!(
+        transaction_message == [0, 0, 0, 0, 0, 0],
+        SmartAccountError::InvalidInstructionArgs
+    );
+
+    // Validate that every chunk of the buffer has been written
+    transaction_buffer.validate_written()?;
+
+    // Validate that the final hash matches the buffer
+    transaction_buffer.validate_hash()?;
+
+    // Validate that the final size is correct
+    transaction_buffer.validate_size()?;
+    Ok(())
+}
+
+/// Grows the transaction account, initialized with an empty transaction message,
+/// to `new_len` and tops up its rent exemption from the `rent_payer`.
+fn realloc_transaction<'info>(
+    transaction_account_info: &AccountInfo<'info>,
+    rent_payer_account_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    // Calculate the rent exemption for new length
+    let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
+
+    // Check the difference between the rent exemption and the current lamports
+    let top_up_lamports = rent_exempt_lamports.saturating_sub(transaction_account_info.lamports());
+
+    // System Transfer the remaining difference to the transaction account
+    let transfer_context = CpiContext::new(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: rent_payer_account_info.clone(),
+            to: transaction_account_info.clone(),
+        },
+    );
+    system_program::transfer(transfer_context, top_up_lamports)?;
+
+    // Reallocate the transaction account to the new length of the
+    // actual transaction message
+    AccountInfo::realloc(transaction_account_info, new_len, true)?;
+    Ok(())
+}
+
+fn log_buffer_close<'info>(
+    settings: &mut Account<'info, Settings>,
+    transaction_buffer: &Account<'info, TransactionBuffer>,
+    log_accounts: Option<LogAccounts<'_>>,
+) -> Result<()> {
+    let event = TransactionBufferEvent {
+        event_type: TransactionBufferEventType::Close,
+        settings_pubkey: settings.key(),
+        transaction_buffer_pubkey: transaction_buffer.key(),
+        buffer_index: transaction_buffer.buffer_index,
+        creator: transaction_buffer.creator,
+        final_buffer_hash: transaction_buffer.final_buffer_hash,
+        final_buffer_size: transaction_buffer.final_buffer_size,
+        buffer_size: transaction_buffer.buffer.len() as u16,
+    };
+    let log_authority_info = LogAuthorityInfo {
+        authority: settings.to_account_info(),
+        authority_seeds: get_settings_signer_seeds(settings.seed),
+        bump: settings.bump,
+        log_accounts,
+    };
+    SmartAccountEvent::TransactionBufferEvent(event).log(&log_authority_info)
+}
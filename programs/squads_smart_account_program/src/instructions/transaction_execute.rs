@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
 use crate::state::*;
 use crate::utils::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteTransactionV2Args {
+    /// The serialized `TransactionMessage` of a transaction created with only the hash
+    /// of its message. Must be `None` for other transactions.
+    pub transaction_message: Option<Vec<u8>>,
+}
+
 #[derive(Accounts)]
 pub struct ExecuteTransaction<'info> {
     #[account(
@@ -43,114 +52,242 @@ pub struct ExecuteTransaction<'info> {
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
     // 2. Accounts in the order they appear in `message.account_keys`.
     // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. Optionally, the `EventSequence` of the settings and the program account,
+    //    in this order, to log sequenced events.
 }
 
-impl ExecuteTransaction<'_> {
-    fn validate(&self) -> Result<()> {
-        let Self {
-            settings,
-            proposal,
-            signer,
-            ..
-        } = self;
-
-        // signer
-        require!(
-            settings.is_signer(signer.key()).is_some(),
-            SmartAccountError::NotASigner
-        );
-        require!(
-            settings.signer_has_permission(signer.key(), Permission::Execute),
-            SmartAccountError::Unauthorized
-        );
-
-        // proposal
-        match proposal.status {
-            ProposalStatus::Approved { timestamp } => {
-                require!(
-                    Clock::get()?.unix_timestamp - timestamp >= i64::from(settings.time_lock),
-                    SmartAccountError::TimeLockNotReleased
-                );
-            }
-            _ => return err!(SmartAccountError::InvalidProposalStatus),
-        }
-        // Stale transaction proposals CAN be executed if they were approved
-        // before becoming stale, hence no check for staleness here.
+/// Same accounts as `ExecuteTransaction`, with the logging program required.
+#[derive(Accounts)]
+pub struct ExecuteTransactionV2<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// The proposal account associated with the transaction.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// The transaction to execute.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+    )]
+    pub transaction: Account<'info, Transaction>,
+
+    pub signer: Signer<'info>,
 
-        // `transaction` is validated by its seeds.
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // `remaining_accounts` must include the following accounts in the exact order:
+    // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
+    // 2. Accounts in the order they appear in `message.account_keys`.
+    // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. Optionally, the `EventSequence` of the settings, to log sequenced events.
+}
 
-        Ok(())
+impl ExecuteTransaction<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_execute(&self.settings, &self.proposal, self.signer.key())
     }
 
     /// Execute the smart account transaction.
     /// The transaction must be `Approved`.
     #[access_control(ctx.accounts.validate())]
     pub fn execute_transaction(ctx: Context<Self>) -> Result<()> {
-        let settings = &mut ctx.accounts.settings;
-        let proposal = &mut ctx.accounts.proposal;
+        let accounts = ctx.accounts;
 
-        // NOTE: After `take()` is called, the Transaction is reduced to
-        // its default empty value, which means it should no longer be referenced or
-        // used after this point to avoid faulty behavior.
-        // Instead only make use of the returned `transaction` value.
-        let transaction = ctx.accounts.transaction.take();
+        execute(
+            ctx.program_id,
+            &accounts.settings,
+            &mut accounts.proposal,
+            &mut accounts.transaction,
+            accounts.signer.key(),
+            ctx.remaining_accounts,
+            LogAccounts::find(ctx.remaining_accounts),
+            None,
+        )
+    }
+}
 
-        let settings_key = settings.key();
-        let transaction_key = ctx.accounts.transaction.key();
+impl ExecuteTransactionV2<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_execute(&self.settings, &self.proposal, self.signer.key())
+    }
 
-        let smart_account_seeds = &[
-            SEED_PREFIX,
-            settings_key.as_ref(),
-            SEED_SMART_ACCOUNT,
-            &transaction.account_index.to_le_bytes(),
-            &[transaction.account_bump],
-        ];
-
-        let transaction_message = transaction.message;
-        let num_lookups = transaction_message.address_table_lookups.len();
-
-        let message_account_infos = ctx
-            .remaining_accounts
-            .get(num_lookups..)
-            .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
-        let address_lookup_table_account_infos = ctx
-            .remaining_accounts
-            .get(..num_lookups)
-            .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
-
-        let smart_account_pubkey =
-            Pubkey::create_program_address(smart_account_seeds, ctx.program_id).unwrap();
-
-        let (ephemeral_signer_keys, ephemeral_signer_seeds) =
-            derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
-
-        let executable_message = ExecutableTransactionMessage::new_validated(
-            transaction_message,
-            message_account_infos,
-            address_lookup_table_account_infos,
-            &smart_account_pubkey,
-            &ephemeral_signer_keys,
-        )?;
-
-        let protected_accounts = &[proposal.key()];
-
-        // Execute the transaction message instructions one-by-one.
-        // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-        // which in turn calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        executable_message.execute_message(
-            smart_account_seeds,
-            &ephemeral_signer_seeds,
-            protected_accounts,
-        )?;
-
-        // Mark the proposal as executed.
-        proposal.status = ProposalStatus::Executed {
-            timestamp: Clock::get()?.unix_timestamp,
-        };
-
-        Ok(())
+    /// Execute the smart account transaction, revealing its message if only its hash is stored.
+    /// The transaction must be `Approved`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_transaction_v2(
+        ctx: Context<Self>,
+        args: ExecuteTransactionV2Args,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+
+        execute(
+            ctx.program_id,
+            &accounts.settings,
+            &mut accounts.proposal,
+            &mut accounts.transaction,
+            accounts.signer.key(),
+            ctx.remaining_accounts,
+            LogAccounts::new(accounts.program.key(), ctx.remaining_accounts),
+            args.transaction_message.as_deref(),
+        )
+    }
+}
+
+fn validate_execute(settings: &Settings, proposal: &Proposal, signer: Pubkey) -> Result<()> {
+    // signer
+    require!(
+        settings.is_signer(signer).is_some(),
+        SmartAccountError::NotASigner
+    );
+    require!(
+        settings.signer_has_permission(signer, Permission::Execute),
+        SmartAccountError::Unauthorized
+    );
+
+    // proposal
+    match proposal.status {
+        ProposalStatus::Approved { timestamp } => {
+            require!(
+                Clock::get()?.unix_timestamp - timestamp >= i64::from(settings.time_lock),
+                SmartAccountError::TimeLockNotReleased
+            );
+        }
+        _ => return err!(SmartAccountError::InvalidProposalStatus),
     }
+    // Stale transaction proposals CAN be executed if they were approved
+    // before becoming stale, hence no check for staleness here.
+
+    // `transaction` is validated by its seeds.
+
+    Ok(())
+}
+
+/// Executes the message of the `transaction`, or the `revealed_message` of a hash-committed one,
+/// and marks the `proposal` as executed.
+#[allow(clippy::too_many_arguments)]
+fn execute<'info>(
+    program_id: &Pubkey,
+    settings: &Account<'info, Settings>,
+    proposal: &mut Account<'info, Proposal>,
+    transaction_account: &mut Account<'info, Transaction>,
+    signer: Pubkey,
+    remaining_accounts: &[AccountInfo<'_>],
+    log_accounts: Option<LogAccounts<'_>>,
+    revealed_message: Option<&[u8]>,
+) -> Result<()> {
+    // NOTE: After `take()` is called, the Transaction is reduced to
+    // its default empty value, which means it should no longer be referenced or
+    // used after this point to avoid faulty behavior.
+    // Instead only make use of the returned `transaction` value.
+    let mut transaction = transaction_account.take();
+
+    let settings_key = settings.key();
+    let transaction_key = transaction_account.key();
+    let transaction_index = transaction.index;
+
+    let smart_account_seeds = &[
+        SEED_PREFIX,
+        settings_key.as_ref(),
+        SEED_SMART_ACCOUNT,
+        &transaction.account_index.to_le_bytes(),
+        &[transaction.account_bump],
+    ];
+
+    // The revealed message of a hash-committed transaction is checked against its hash.
+    let transaction_message = transaction.take_message(revealed_message)?;
+    let num_lookups = transaction_message.address_table_lookups.len();
+    let remaining_accounts = strip_log_accounts(
+        remaining_accounts,
+        num_lookups + transaction_message.num_all_account_keys(),
+    );
+
+    let message_account_infos = remaining_accounts
+        .get(num_lookups..)
+        .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
+    let address_lookup_table_account_infos = remaining_accounts
+        .get(..num_lookups)
+        .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
+
+    let smart_account_pubkey =
+        Pubkey::create_program_address(smart_account_seeds, program_id).unwrap();
+
+    let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+        derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
+
+    let executable_message = ExecutableTransactionMessage::new_validated(
+        transaction_message,
+        message_account_infos,
+        address_lookup_table_account_infos,
+        &smart_account_pubkey,
+        &ephemeral_signer_keys,
+    )?;
+
+    let protected_accounts = &[proposal.key()];
+
+    // Execute the transaction message instructions one-by-one.
+    // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
+    // which in turn calls `take()` on
+    // `self.message.instructions`, therefore after this point no more
+    // references or usages of `self.message` should be made to avoid
+    // faulty behavior.
+    executable_message.execute_message(
+        smart_account_seeds,
+        &ephemeral_signer_seeds,
+        protected_accounts,
+    )?;
+
+    // Mark the proposal as executed.
+    proposal.status = ProposalStatus::Executed {
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    // Log the events
+    let log_authority_info = LogAuthorityInfo {
+        authority: settings.to_account_info(),
+        authority_seeds: get_settings_signer_seeds(settings.seed),
+        bump: settings.bump,
+        log_accounts,
+    };
+    let event = TransactionEvent {
+        event_type: TransactionEventType::Execute,
+        settings_pubkey: settings_key,
+        transaction_pubkey: transaction_key,
+        transaction_index,
+        signer: Some(signer),
+        memo: None,
+        transaction_content: None,
+    };
+    SmartAccountEvent::TransactionEvent(event).log(&log_authority_info)?;
+
+    let event = ProposalEvent {
+        event_type: ProposalEventType::Execute,
+        settings_pubkey: settings_key,
+        proposal_pubkey: proposal.key(),
+        transaction_index,
+        signer: Some(signer),
+        memo: None,
+        proposal: Some(Proposal::try_from_slice(&proposal.try_to_vec()?)?),
+    };
+    SmartAccountEvent::ProposalEvent(event).log(&log_authority_info)?;
+
+    Ok(())
 }
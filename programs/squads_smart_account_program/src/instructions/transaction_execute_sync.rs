@@ -6,11 +6,13 @@ use crate::{
     events::*,
     program::SquadsSmartAccountProgram,
     state::*,
-    utils::{validate_synchronous_consensus, SynchronousTransactionMessage},
+    utils::{
+        derive_ephemeral_signers, validate_synchronous_consensus, SynchronousTransactionMessage,
+    },
     SmallVec,
 };
 
-use super::CompiledInstruction;
+use super::{CompiledInstruction, MessageAddressTableLookup};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SyncTransactionArgs {
@@ -22,6 +24,22 @@ pub struct SyncTransactionArgs {
     pub instructions: Vec<u8>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SyncTransactionV2Args {
+    /// The index of the smart account this transaction is for
+    pub account_index: u8,
+    /// The number of signers to reach threshold and adequate permissions
+    pub num_signers: u8,
+    /// Expected to be serialized as a SmallVec<u8, CompiledInstruction>
+    pub instructions: Vec<u8>,
+    /// Address lookup tables used to load additional accounts.
+    /// Expected to be serialized as a SmallVec<u8, MessageAddressTableLookup>
+    pub address_table_lookups: Vec<u8>,
+    /// Number of ephemeral signing PDAs required by the transaction.
+    /// Using ephemeral signers consumes a transaction index of the smart account.
+    pub ephemeral_signers: u8,
+}
+
 #[derive(Accounts)]
 pub struct SyncTransaction<'info> {
     #[account(
@@ -33,83 +51,196 @@ pub struct SyncTransaction<'info> {
     // `remaining_accounts` must include the following accounts in the exact order:
     // 1. The exact amount of signers required to reach the threshold
     // 2. Any remaining accounts associated with the instructions
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
 impl SyncTransaction<'_> {
     #[access_control(validate_synchronous_consensus( &ctx.accounts.settings, args.num_signers, &ctx.remaining_accounts))]
     pub fn sync_transaction(ctx: Context<Self>, args: SyncTransactionArgs) -> Result<()> {
-        // Readonly Accounts
-        let settings = &ctx.accounts.settings;
+        execute_sync_transaction(
+            ctx.program_id,
+            &mut ctx.accounts.settings,
+            ctx.accounts.program.key(),
+            ctx.remaining_accounts,
+            args,
+            vec![],
+            0,
+        )
+    }
+}
 
-        let settings_key = settings.key();
-        // Deserialize the instructions
-        let compiled_instructions =
-            SmallVec::<u8, CompiledInstruction>::try_from_slice(&args.instructions)
+#[derive(Accounts)]
+pub struct SyncTransactionV2<'info> {
+    /// Mutable, as ephemeral signers consume a transaction index of the settings.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // `remaining_accounts` must include the following accounts in the exact order:
+    // 1. The exact amount of signers required to reach the threshold
+    // 2. Any remaining accounts associated with the instructions
+    // 3. Accounts loaded from the address lookup tables, in the order of `address_table_lookups`
+    // 4. The address lookup table accounts, in the order of `address_table_lookups`
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl SyncTransactionV2<'_> {
+    /// Same as `sync_transaction`, but allows loading accounts through address lookup tables
+    /// and signing with ephemeral signers.
+    #[access_control(validate_synchronous_consensus( &ctx.accounts.settings, args.num_signers, &ctx.remaining_accounts))]
+    pub fn sync_transaction_v2(ctx: Context<Self>, args: SyncTransactionV2Args) -> Result<()> {
+        let address_table_lookups =
+            SmallVec::<u8, MessageAddressTableLookup>::try_from_slice(&args.address_table_lookups)
                 .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
-        // Convert to SmartAccountCompiledInstruction
-        let settings_compiled_instructions: Vec<SmartAccountCompiledInstruction> =
-            Vec::from(compiled_instructions)
+
+        execute_sync_transaction(
+            ctx.program_id,
+            &mut ctx.accounts.settings,
+            ctx.accounts.program.key(),
+            ctx.remaining_accounts,
+            SyncTransactionArgs {
+                account_index: args.account_index,
+                num_signers: args.num_signers,
+                instructions: args.instructions,
+            },
+            Vec::from(address_table_lookups)
                 .into_iter()
-                .map(SmartAccountCompiledInstruction::from)
-                .collect();
-
-        let smart_account_seeds = &[
-            SEED_PREFIX,
-            settings_key.as_ref(),
-            SEED_SMART_ACCOUNT,
-            &args.account_index.to_le_bytes(),
-        ];
-
-        let (smart_account_pubkey, smart_account_bump) =
-            Pubkey::find_program_address(smart_account_seeds, ctx.program_id);
-
-        // Get the signer seeds for the smart account
-        let smart_account_signer_seeds = &[
-            smart_account_seeds[0],
-            smart_account_seeds[1],
-            smart_account_seeds[2],
-            smart_account_seeds[3],
-            &[smart_account_bump],
-        ];
-
-        let executable_message = SynchronousTransactionMessage::new_validated(
-            &settings.key(),
-            &settings,
-            &smart_account_pubkey,
-            settings_compiled_instructions,
-            &ctx.remaining_accounts,
-        )?;
-
-        // Execute the transaction message instructions one-by-one.
-        // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-        // which in turn calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        executable_message.execute(smart_account_signer_seeds)?;
-
-        // Log the event
-        let event = SynchronousTransactionEvent {
-            settings_pubkey: settings.key(),
-            signers: ctx.remaining_accounts[..args.num_signers as usize]
-                .iter()
-                .map(|acc| acc.key.clone())
+                .map(SmartAccountMessageAddressTableLookup::from)
                 .collect(),
-            account_index: args.account_index,
-            instructions: executable_message.instructions,
-            instruction_accounts: executable_message
-                .accounts
-                .iter()
-                .map(|a| a.key.clone())
-                .collect(),
-        };
-        let log_authority_info = LogAuthorityInfo {
-            authority: settings.to_account_info(),
-            authority_seeds: get_settings_signer_seeds(settings.seed),
-            bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
-        };
-        SmartAccountEvent::SynchronousTransactionEvent(event).log(&log_authority_info)?;
-        Ok(())
+            args.ephemeral_signers,
+        )
     }
 }
+
+/// Executes the instructions of a sync transaction, loading accounts through
+/// `address_table_lookups` and signing with `ephemeral_signers` ephemeral signers.
+/// `settings` are only modified when ephemeral signers are used.
+fn execute_sync_transaction(
+    program_id: &Pubkey,
+    settings: &mut Account<'_, Settings>,
+    program: Pubkey,
+    remaining_accounts: &[AccountInfo<'_>],
+    args: SyncTransactionArgs,
+    address_table_lookups: Vec<SmartAccountMessageAddressTableLookup>,
+    ephemeral_signers: u8,
+) -> Result<()> {
+    let SyncTransactionArgs {
+        account_index,
+        num_signers,
+        instructions,
+    } = args;
+
+    let settings_key = settings.key();
+    // Deserialize the instructions
+    let compiled_instructions = SmallVec::<u8, CompiledInstruction>::try_from_slice(&instructions)
+        .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
+    // Convert to SmartAccountCompiledInstruction
+    let settings_compiled_instructions: Vec<SmartAccountCompiledInstruction> =
+        Vec::from(compiled_instructions)
+            .into_iter()
+            .map(SmartAccountCompiledInstruction::from)
+            .collect();
+
+    let smart_account_seeds = &[
+        SEED_PREFIX,
+        settings_key.as_ref(),
+        SEED_SMART_ACCOUNT,
+        &account_index.to_le_bytes(),
+    ];
+
+    let (smart_account_pubkey, smart_account_bump) =
+        Pubkey::find_program_address(smart_account_seeds, program_id);
+
+    // Get the signer seeds for the smart account
+    let smart_account_signer_seeds = &[
+        smart_account_seeds[0],
+        smart_account_seeds[1],
+        smart_account_seeds[2],
+        smart_account_seeds[3],
+        &[smart_account_bump],
+    ];
+
+    // Sync transactions have no `Transaction` account to derive the ephemeral signers from,
+    // so we consume the next transaction index and derive them from the address the
+    // `Transaction` would have had. This makes them unique per invocation, and they can
+    // never be derived again by either a sync or an async transaction.
+    let (ephemeral_signer_keys, ephemeral_signer_seeds) = if ephemeral_signers > 0 {
+        let transaction_index = settings.transaction_index.checked_add(1).unwrap();
+        settings.transaction_index = transaction_index;
+
+        let (transaction_key, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                settings_key.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+            ],
+            program_id,
+        );
+        let ephemeral_signer_bumps: Vec<u8> = (0..ephemeral_signers)
+            .map(|ephemeral_signer_index| {
+                let ephemeral_signer_seeds = &[
+                    SEED_PREFIX,
+                    transaction_key.as_ref(),
+                    SEED_EPHEMERAL_SIGNER,
+                    &ephemeral_signer_index.to_le_bytes(),
+                ];
+
+                let (_, bump) = Pubkey::find_program_address(ephemeral_signer_seeds, program_id);
+                bump
+            })
+            .collect();
+
+        derive_ephemeral_signers(transaction_key, &ephemeral_signer_bumps)
+    } else {
+        (vec![], vec![])
+    };
+
+    let executable_message = SynchronousTransactionMessage::new_validated_with_lookups(
+        &settings.key(),
+        settings,
+        &smart_account_pubkey,
+        settings_compiled_instructions,
+        &address_table_lookups,
+        strip_event_sequence(remaining_accounts),
+        &ephemeral_signer_keys,
+    )?;
+
+    // Execute the transaction message instructions one-by-one.
+    // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
+    // which in turn calls `take()` on
+    // `self.message.instructions`, therefore after this point no more
+    // references or usages of `self.message` should be made to avoid
+    // faulty behavior.
+    executable_message.execute(smart_account_signer_seeds, &ephemeral_signer_seeds)?;
+
+    // Log the event
+    let event = SynchronousTransactionEvent {
+        settings_pubkey: settings.key(),
+        signers: remaining_accounts[..num_signers as usize]
+            .iter()
+            .map(|acc| acc.key.clone())
+            .collect(),
+        account_index,
+        instructions: executable_message.instructions,
+        instruction_accounts: executable_message
+            .accounts
+            .iter()
+            .map(|a| a.key.clone())
+            .collect(),
+        ephemeral_signers: ephemeral_signer_keys,
+    };
+    let log_authority_info = LogAuthorityInfo {
+        authority: settings.to_account_info(),
+        authority_seeds: get_settings_signer_seeds(settings.seed),
+        bump: settings.bump,
+        log_accounts: LogAccounts::new(program, remaining_accounts),
+    };
+    SmartAccountEvent::SynchronousTransactionEvent(event).log(&log_authority_info)?;
+    Ok(())
+}
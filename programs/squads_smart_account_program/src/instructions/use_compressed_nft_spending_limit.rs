@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+
+use crate::{
+    errors::*,
+    events::*,
+    program::SquadsSmartAccountProgram,
+    state::*,
+    utils::{
+        check_spending_limit_instructions, SmartAccountAssetBalances, SynchronousTransactionMessage,
+    },
+    SmallVec,
+};
+
+use super::CompiledInstruction;
+
+/// Name of the instruction compressed NFT verifiers must implement, Anchor-style:
+/// the instruction data is the first 8 bytes of `sha256("global:<name>")` followed by
+/// a serialized `CompressedNftTransferRequest`.
+pub const VERIFY_COMPRESSED_NFT_TRANSFER_IX_NAME: &str = "verify_compressed_nft_transfer";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UseCompressedNftSpendingLimitArgs {
+    /// The group of the NFT allowance to use.
+    pub group: NftGroup,
+    /// Number of compressed NFTs of `group` the instructions transfer,
+    /// which the verifier must confirm.
+    pub count: u64,
+    /// Expected to be serialized as a SmallVec<u8, CompiledInstruction>
+    pub instructions: Vec<u8>,
+    /// Opaque data passed on to the verifier, e.g. the proofs of the transferred leaves.
+    pub verifier_data: Vec<u8>,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+/// The request a compressed NFT verifier is asked to approve.
+/// The verifier must fail unless every compressed NFT the instructions transfer out of
+/// `smart_account` is a verified member of `group` and goes to one of `destinations`
+/// (any destination if empty), and must set the number of such NFTs as its return data,
+/// serialized as a little-endian `u64`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CompressedNftTransferRequest {
+    pub group: NftGroup,
+    pub smart_account: Pubkey,
+    pub destinations: Vec<Pubkey>,
+    /// Keys of the accounts referenced by `instructions`, in the same order they're
+    /// passed to the verifier.
+    pub account_keys: Vec<Pubkey>,
+    pub instructions: Vec<SmartAccountCompiledInstruction>,
+    pub verifier_data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct UseCompressedNftSpendingLimit<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// Smart account the transfers are executed on behalf of.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    /// The `compressed_verifier` of the NFT allowance.
+    /// CHECK: We do the checks in `UseCompressedNftSpendingLimit::validate`.
+    #[account(executable)]
+    pub verifier: AccountInfo<'info>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // `remaining_accounts` must start with the `spending_limit.threshold - 1` co-signers,
+    // followed by the accounts referenced by the instructions.
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl<'info> UseCompressedNftSpendingLimit<'info> {
+    fn validate(&self, group: &NftGroup, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Self {
+            signer,
+            spending_limit,
+            verifier,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            spending_limit.signers.contains(&signer.key()),
+            SmartAccountError::Unauthorized
+        );
+
+        // cosigners
+        // `spending_limit.threshold - 1` other signers must co-sign the use.
+        let (cosigners, _) = spending_limit.split_cosigners(remaining_accounts)?;
+        spending_limit.check_cosigners(&signer.key(), cosigners)?;
+
+        // verifier
+        // Only groups with a compressed NFT verifier can be used with compressed NFTs.
+        let allowance = spending_limit
+            .nft_allowance(group)
+            .ok_or(SmartAccountError::InvalidNft)?;
+        require!(
+            allowance.compressed_verifier == Some(verifier.key()),
+            SmartAccountError::InvalidNft
+        );
+
+        // Spending limit must not be paused.
+        require!(
+            !spending_limit.extension.paused,
+            SmartAccountError::SpendingLimitPaused
+        );
+
+        // Spending limit must not be expired.
+        if spending_limit.expiration != i64::MAX {
+            require!(
+                spending_limit.expiration > Clock::get()?.unix_timestamp,
+                SmartAccountError::SpendingLimitExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Use an NFT allowance of a spending limit to execute transfers of compressed NFTs on
+    /// behalf of the smart account.
+    ///
+    /// The instructions can only invoke the `allowed_programs` of the spending limit. They are
+    /// first submitted to the `compressed_verifier` of the allowance, which approves them and
+    /// returns the number of compressed NFTs they transfer. That number must be the declared
+    /// `count`, which is debited from the allowance. Outflow of any fungible asset, or any change
+    /// of the authorities of the smart account's token accounts or of the smart account itself,
+    /// fails the transaction.
+    #[access_control(ctx.accounts.validate(&args.group, ctx.remaining_accounts))]
+    pub fn use_compressed_nft_spending_limit(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: UseCompressedNftSpendingLimitArgs,
+    ) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        let smart_account = &ctx.accounts.smart_account;
+        let verifier = &ctx.accounts.verifier;
+
+        let signer = ctx.accounts.signer.key();
+        let (cosigners, remaining_accounts) =
+            spending_limit.split_cosigners(strip_event_sequence(ctx.remaining_accounts))?;
+        let settings_key = settings.key();
+        let smart_account_bump = ctx.bumps.smart_account;
+        let now: i64 = Clock::get()?.unix_timestamp;
+
+        // Reset the remaining counts if the `spending_limit.period` has passed.
+        spending_limit.reset_if_period_passed(now);
+
+        // Deserialize the instructions
+        let compiled_instructions =
+            SmallVec::<u8, CompiledInstruction>::try_from_slice(&args.instructions)
+                .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
+        let instructions: Vec<SmartAccountCompiledInstruction> = Vec::from(compiled_instructions)
+            .into_iter()
+            .map(SmartAccountCompiledInstruction::from)
+            .collect();
+
+        let executable_message = SynchronousTransactionMessage::new_validated(
+            &settings_key,
+            settings,
+            smart_account.key,
+            instructions,
+            remaining_accounts,
+            &[],
+        )?;
+
+        // Only the programs allowed by the spending limit can be invoked.
+        check_spending_limit_instructions(
+            &spending_limit.extension.allowed_programs,
+            &executable_message.instructions,
+            &executable_message.accounts,
+        )?;
+
+        // Ask the verifier how many compressed NFTs of the group the instructions transfer.
+        let count = verify_compressed_nft_transfer(
+            verifier,
+            CompressedNftTransferRequest {
+                group: args.group,
+                smart_account: smart_account.key(),
+                destinations: spending_limit.destinations.clone(),
+                account_keys: executable_message
+                    .accounts
+                    .iter()
+                    .map(|account| account.key())
+                    .collect(),
+                instructions: executable_message.instructions.clone(),
+                verifier_data: args.verifier_data,
+            },
+            &executable_message.accounts,
+        )?;
+        // The verifier must confirm the transfers the signer declared.
+        require!(
+            count > 0 && count == args.count,
+            SmartAccountError::InvalidNft
+        );
+
+        // This will also check that the NFT allowance isn't exhausted.
+        spending_limit.debit_nfts(&args.group, count, now)?;
+        // Which compressed NFTs go where is only known to the verifier.
+        spending_limit.record_use(SpendingLimitUse {
+            signer,
+            mint: Pubkey::default(),
+            destination: Pubkey::default(),
+            amount: count,
+            timestamp: now,
+            memo_hash: SpendingLimitUse::memo_hash(&args.memo),
+        });
+
+        let balances =
+            SmartAccountAssetBalances::snapshot(smart_account, &executable_message.accounts)?;
+
+        executable_message.execute(
+            &[
+                SEED_PREFIX,
+                settings_key.as_ref(),
+                SEED_SMART_ACCOUNT,
+                &spending_limit.account_index.to_le_bytes(),
+                &[smart_account_bump],
+            ],
+            &[],
+        )?;
+
+        // Only compressed NFTs can leave the smart account.
+        let outflows = balances.outflows(smart_account, &executable_message.accounts)?;
+        require!(
+            outflows.is_empty(),
+            SmartAccountError::SpendingLimitInvalidOutflow
+        );
+
+        // Log the event
+        let event = UseNftSpendingLimitEvent {
+            settings_pubkey: settings_key,
+            spending_limit_pubkey: spending_limit.key(),
+            smart_account: smart_account.key(),
+            signer,
+            cosigners: cosigners.iter().map(|cosigner| cosigner.key()).collect(),
+            group: args.group,
+            count,
+            mint: None,
+            destination: None,
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::UseNftSpendingLimitEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+}
+
+/// Submits `request` to `verifier`, passing it `accounts` as read-only non-signers,
+/// and returns the number of compressed NFTs the verifier approved.
+fn verify_compressed_nft_transfer<'info>(
+    verifier: &AccountInfo<'info>,
+    request: CompressedNftTransferRequest,
+    accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let mut data = hash(format!("global:{VERIFY_COMPRESSED_NFT_TRANSFER_IX_NAME}").as_bytes())
+        .to_bytes()[..8]
+        .to_vec();
+    request.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: verifier.key(),
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta::new_readonly(account.key(), false))
+            .collect(),
+        data,
+    };
+    let mut account_infos = accounts.to_vec();
+    account_infos.push(verifier.clone());
+    invoke(&ix, &account_infos)?;
+
+    let (program_id, return_data) = get_return_data().ok_or(SmartAccountError::InvalidNft)?;
+    require_keys_eq!(program_id, verifier.key(), SmartAccountError::InvalidNft);
+    let count: [u8; 8] = return_data
+        .try_into()
+        .map_err(|_| SmartAccountError::InvalidNft)?;
+
+    Ok(u64::from_le_bytes(count))
+}
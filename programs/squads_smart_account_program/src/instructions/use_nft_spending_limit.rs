@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::TransferChecked;
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
+use crate::state::*;
+use crate::utils::NftMetadata;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UseNftSpendingLimitArgs {
+    /// The group of the NFT allowance to use.
+    pub group: NftGroup,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UseNftSpendingLimit<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// Smart account to transfer the NFT from.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    /// Destination account to transfer the NFT to.
+    /// CHECK: We do the checks in `UseNftSpendingLimit::validate`.
+    pub destination: AccountInfo<'info>,
+
+    /// The mint of the NFT to transfer.
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The Metaplex metadata account of `mint`.
+    /// CHECK: We do the checks in `UseNftSpendingLimit::validate`.
+    pub metadata: AccountInfo<'info>,
+
+    /// Smart account token account to transfer the NFT from.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = smart_account,
+    )]
+    pub smart_account_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination token account to transfer the NFT to.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = destination,
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // `remaining_accounts` must include the `spending_limit.threshold - 1` co-signers.
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl UseNftSpendingLimit<'_> {
+    fn validate(&self, group: &NftGroup, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Self {
+            signer,
+            spending_limit,
+            mint,
+            metadata,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            spending_limit.signers.contains(&signer.key()),
+            SmartAccountError::Unauthorized
+        );
+
+        // cosigners
+        // `spending_limit.threshold - 1` other signers must co-sign the use.
+        let (cosigners, _) = spending_limit.split_cosigners(remaining_accounts)?;
+        spending_limit.check_cosigners(&signer.key(), cosigners)?;
+
+        // mint
+        // Must be a non-fungible token covered by the NFT allowance of `group`.
+        require!(
+            mint.decimals == 0 && mint.supply == 1,
+            SmartAccountError::InvalidNft
+        );
+        require!(
+            spending_limit.nft_allowance(group).is_some(),
+            SmartAccountError::InvalidNft
+        );
+
+        // metadata
+        require!(
+            NftMetadata::read(metadata, &mint.key())?.belongs_to(group),
+            SmartAccountError::InvalidNft
+        );
+
+        // destination
+        if !spending_limit.destinations.is_empty() {
+            require!(
+                spending_limit
+                    .destinations
+                    .contains(&self.destination.key()),
+                SmartAccountError::InvalidDestination
+            );
+        }
+
+        // Spending limit must not be paused.
+        require!(
+            !spending_limit.extension.paused,
+            SmartAccountError::SpendingLimitPaused
+        );
+
+        // Spending limit must not be expired.
+        if spending_limit.expiration != i64::MAX {
+            require!(
+                spending_limit.expiration > Clock::get()?.unix_timestamp,
+                SmartAccountError::SpendingLimitExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Use an NFT allowance of a spending limit to transfer an NFT from a smart account
+    /// to a destination account.
+    #[access_control(ctx.accounts.validate(&args.group, ctx.remaining_accounts))]
+    pub fn use_nft_spending_limit(ctx: Context<Self>, args: UseNftSpendingLimitArgs) -> Result<()> {
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        let (cosigners, _) = spending_limit.split_cosigners(ctx.remaining_accounts)?;
+        let smart_account = &ctx.accounts.smart_account;
+
+        let settings_key = ctx.accounts.settings.key();
+        let smart_account_bump = ctx.bumps.smart_account;
+        let now: i64 = Clock::get()?.unix_timestamp;
+
+        // Reset the remaining counts if the `spending_limit.period` has passed.
+        spending_limit.reset_if_period_passed(now);
+
+        // This will also check that the NFT allowance isn't exhausted.
+        spending_limit.debit_nfts(&args.group, 1, now)?;
+        spending_limit.record_use(SpendingLimitUse {
+            signer: ctx.accounts.signer.key(),
+            mint: ctx.accounts.mint.key(),
+            destination: ctx.accounts.destination.key(),
+            amount: 1,
+            timestamp: now,
+            memo_hash: SpendingLimitUse::memo_hash(&args.memo),
+        });
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.smart_account_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.destination_token_account.to_account_info(),
+                    authority: smart_account.clone(),
+                },
+                &[&[
+                    SEED_PREFIX,
+                    settings_key.as_ref(),
+                    SEED_SMART_ACCOUNT,
+                    &spending_limit.account_index.to_le_bytes(),
+                    &[smart_account_bump],
+                ]],
+            ),
+            1,
+            0,
+        )?;
+
+        // Log the event
+        let event = UseNftSpendingLimitEvent {
+            settings_pubkey: settings_key,
+            spending_limit_pubkey: spending_limit.key(),
+            smart_account: smart_account.key(),
+            signer: ctx.accounts.signer.key(),
+            cosigners: cosigners.iter().map(|cosigner| cosigner.key()).collect(),
+            group: args.group,
+            count: 1,
+            mint: Some(ctx.accounts.mint.key()),
+            destination: Some(ctx.accounts.destination.key()),
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        let settings = &ctx.accounts.settings;
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::UseNftSpendingLimitEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+}
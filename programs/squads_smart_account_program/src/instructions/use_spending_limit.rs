@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::TransferChecked;
-use anchor_spl::token_interface;
+use anchor_lang::Ids;
+use anchor_spl::associated_token::{self, AssociatedToken};
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::*;
 use crate::events::*;
 use crate::program::SquadsSmartAccountProgram;
 use crate::state::*;
+use crate::utils::{transfer_checked_with_extra_accounts, transfer_fee, TransferCheckedAccounts};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UseSpendingLimitArgs {
@@ -77,25 +78,39 @@ pub struct UseSpendingLimit<'info> {
     pub smart_account_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Destination token account in case `spending_limit.mint` is an SPL token.
-    #[account(
-        mut,
-        token::mint = mint,
-        token::authority = destination,
-    )]
-    pub destination_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// If it doesn't exist yet and `associated_token_program` is provided, it's created
+    /// as the associated token account of `destination`, paid by `rent_payer`.
+    /// CHECK: We do the checks in `UseSpendingLimit::validate`.
+    #[account(mut)]
+    pub destination_token_account: Option<AccountInfo<'info>>,
 
     /// In case `spending_limit.mint` is an SPL token.
     pub token_program: Option<Interface<'info, TokenInterface>>,
 
     pub program: Program<'info, SquadsSmartAccountProgram>,
+
+    /// Pays for the creation of `destination_token_account` in case it doesn't exist yet.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// In case `destination_token_account` needs to be created.
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// Price account of `spending_limit.mint` in case the spending limit has a `price_oracle`.
+    /// CHECK: We do the checks in `SpendingLimit::value_of`.
+    pub price_account: Option<AccountInfo<'info>>,
+    // `remaining_accounts` must start with the `spending_limit.threshold - 1` co-signers,
+    // the accounts that follow are passed on to the token program,
+    // e.g. the extra accounts required by the transfer hook of a Token-2022 mint.
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
 }
 
-impl UseSpendingLimit<'_> {
-    fn validate(&self) -> Result<()> {
+impl<'info> UseSpendingLimit<'info> {
+    fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
         let Self {
             signer,
             spending_limit,
-            mint,
             ..
         } = self;
 
@@ -105,19 +120,20 @@ impl UseSpendingLimit<'_> {
             SmartAccountError::Unauthorized
         );
 
+        // cosigners
+        // `spending_limit.threshold - 1` other signers must co-sign the use.
+        let (cosigners, _) = spending_limit.split_cosigners(remaining_accounts)?;
+        spending_limit.check_cosigners(&signer.key(), cosigners)?;
+
         // spending_limit - needs no checking.
 
         // mint
-        if spending_limit.mint == Pubkey::default() {
-            // SpendingLimit is for SOL, there should be no mint account in this case.
-            require!(mint.is_none(), SmartAccountError::InvalidMint);
-        } else {
-            // SpendingLimit is for an SPL token, `mint` must match `spending_limit.mint`.
-            require!(
-                spending_limit.mint == mint.as_ref().unwrap().key(),
-                SmartAccountError::InvalidMint
-            );
-        }
+        // Selects which of the mints covered by the spending limit is transferred,
+        // no mint account meaning SOL.
+        require!(
+            spending_limit.covers_mint(&self.mint_key()),
+            SmartAccountError::InvalidMint
+        );
 
         // smart_account - checked in the #[account] attribute.
 
@@ -133,7 +149,16 @@ impl UseSpendingLimit<'_> {
             );
         }
 
-        // destination_token_account - checked in the #[account] attribute.
+        // destination_token_account
+        if let Some(destination_token_account) = &self.destination_token_account {
+            self.validate_destination_token_account(destination_token_account)?;
+        }
+
+        // Spending limit must not be paused.
+        require!(
+            !spending_limit.extension.paused,
+            SmartAccountError::SpendingLimitPaused
+        );
 
         // Spending limit must not be expired.
         if spending_limit.expiration != i64::MAX {
@@ -146,9 +171,87 @@ impl UseSpendingLimit<'_> {
         Ok(())
     }
 
+    /// Checks that `destination_token_account` is a token account of `mint` owned by
+    /// `destination`, or the not yet created associated token account of `destination`
+    /// in case it's going to be created.
+    fn validate_destination_token_account(
+        &self,
+        destination_token_account: &AccountInfo,
+    ) -> Result<()> {
+        let mint = self
+            .mint
+            .as_ref()
+            .ok_or(SmartAccountError::MissingAccount)?;
+        let token_program = self
+            .token_program
+            .as_ref()
+            .ok_or(SmartAccountError::MissingAccount)?;
+
+        if destination_token_account.data_is_empty() {
+            require!(
+                self.associated_token_program.is_some()
+                    && self.rent_payer.is_some()
+                    && self.system_program.is_some(),
+                SmartAccountError::MissingAccount
+            );
+            require_keys_eq!(
+                destination_token_account.key(),
+                associated_token::get_associated_token_address_with_program_id(
+                    &self.destination.key(),
+                    &mint.key(),
+                    &token_program.key(),
+                ),
+                SmartAccountError::InvalidAccount
+            );
+            return Ok(());
+        }
+
+        require!(
+            TokenInterface::ids().contains(destination_token_account.owner),
+            SmartAccountError::IllegalAccountOwner
+        );
+        let token_account =
+            TokenAccount::try_deserialize(&mut &destination_token_account.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            token_account.mint,
+            mint.key(),
+            SmartAccountError::InvalidMint
+        );
+        require_keys_eq!(
+            token_account.owner,
+            self.destination.key(),
+            SmartAccountError::InvalidDestination
+        );
+
+        Ok(())
+    }
+
+    /// The mint being transferred, `Pubkey::default()` for SOL.
+    fn mint_key(&self) -> Pubkey {
+        self.mint
+            .as_ref()
+            .map_or(Pubkey::default(), |mint| mint.key())
+    }
+
     /// Use a spending limit to transfer tokens from a smart account to a destination account.
-    #[access_control(ctx.accounts.validate())]
-    pub fn use_spending_limit(ctx: Context<Self>, args: UseSpendingLimitArgs) -> Result<()> {
+    ///
+    /// For mints with a Token-2022 transfer fee, the spending limit is charged either `amount`
+    /// or `amount` minus the fee, depending on `spending_limit.transfer_fee_charging`.
+    #[access_control(ctx.accounts.validate(ctx.remaining_accounts))]
+    pub fn use_spending_limit(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: UseSpendingLimitArgs,
+    ) -> Result<()> {
+        let mint_key = ctx.accounts.mint_key();
+        let (cosigners, remaining_accounts) = ctx
+            .accounts
+            .spending_limit
+            .split_cosigners(strip_event_sequence(ctx.remaining_accounts))?;
+        // Fee withheld from the transfer by the Token-2022 transfer fee extension of the mint.
+        let fee = match &ctx.accounts.mint {
+            Some(mint) => transfer_fee(&mint.to_account_info(), args.amount, Clock::get()?.epoch)?,
+            None => 0,
+        };
         let spending_limit = &mut ctx.accounts.spending_limit;
         let smart_account = &mut ctx.accounts.smart_account;
         let destination = &mut ctx.accounts.destination;
@@ -158,31 +261,31 @@ impl UseSpendingLimit<'_> {
         let now: i64 = Clock::get()?.unix_timestamp;
 
         // Reset `spending_limit.remaining_amount` if the `spending_limit.period` has passed.
-        if let Some(reset_period) = spending_limit.period.to_seconds() {
-            let passed_since_last_reset = now.checked_sub(spending_limit.last_reset).unwrap();
-
-            if passed_since_last_reset > reset_period {
-                spending_limit.remaining_amount = spending_limit.amount;
-
-                let periods_passed = passed_since_last_reset.checked_div(reset_period).unwrap();
-
-                // last_reset = last_reset + periods_passed * reset_period,
-                spending_limit.last_reset = spending_limit
-                    .last_reset
-                    .checked_add(periods_passed.checked_mul(reset_period).unwrap())
-                    .unwrap();
-            }
-        }
-
-        // Update `spending_limit.remaining_amount`.
-        // This will also check if `amount` doesn't exceed `spending_limit.remaining_amount`.
-        spending_limit.remaining_amount = spending_limit
-            .remaining_amount
-            .checked_sub(args.amount)
-            .ok_or(SmartAccountError::SpendingLimitExceeded)?;
+        spending_limit.reset_if_period_passed(now);
+
+        // Update the remaining amount of the mint.
+        // This will also check if the charged amount doesn't exceed the remaining amount.
+        let charged_amount = spending_limit.value_of(
+            spending_limit
+                .extension
+                .transfer_fee_charging
+                .charged_amount(args.amount, fee),
+            args.decimals,
+            ctx.accounts.price_account.as_ref(),
+            now,
+        )?;
+        spending_limit.debit(&ctx.accounts.signer.key(), &mint_key, charged_amount, now)?;
+        spending_limit.record_use(SpendingLimitUse {
+            signer: ctx.accounts.signer.key(),
+            mint: mint_key,
+            destination: destination.key(),
+            amount: args.amount,
+            timestamp: now,
+            memo_hash: SpendingLimitUse::memo_hash(&args.memo),
+        });
 
         // Transfer tokens.
-        if spending_limit.mint == Pubkey::default() {
+        if mint_key == Pubkey::default() {
             // Transfer using the system_program::transfer.
             let system_program = &ctx
                 .accounts
@@ -233,6 +336,37 @@ impl UseSpendingLimit<'_> {
                 .as_ref()
                 .ok_or(SmartAccountError::MissingAccount)?;
 
+            // Create the destination associated token account if it doesn't exist yet.
+            if destination_token_account.data_is_empty() {
+                let associated_token_program = &ctx
+                    .accounts
+                    .associated_token_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let rent_payer = &ctx
+                    .accounts
+                    .rent_payer
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let system_program = &ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                associated_token::create(CpiContext::new(
+                    associated_token_program.to_account_info(),
+                    associated_token::Create {
+                        payer: rent_payer.to_account_info(),
+                        associated_token: destination_token_account.to_account_info(),
+                        authority: destination.clone(),
+                        mint: mint.to_account_info(),
+                        system_program: system_program.to_account_info(),
+                        token_program: token_program.to_account_info(),
+                    },
+                ))?;
+            }
+
             msg!(
                 "token_program {} mint {} smart account {} destination {} amount {} decimals {}",
                 &token_program.key,
@@ -243,25 +377,24 @@ impl UseSpendingLimit<'_> {
                 &args.decimals
             );
 
-            token_interface::transfer_checked(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    TransferChecked {
-                        from: smart_account_token_account.to_account_info(),
-                        mint: mint.to_account_info(),
-                        to: destination_token_account.to_account_info(),
-                        authority: smart_account.clone(),
-                    },
-                    &[&[
-                        SEED_PREFIX,
-                        settings_key.as_ref(),
-                        SEED_SMART_ACCOUNT,
-                        &spending_limit.account_index.to_le_bytes(),
-                        &[smart_account_bump],
-                    ]],
-                ),
+            transfer_checked_with_extra_accounts(
+                TransferCheckedAccounts {
+                    token_program: &token_program.to_account_info(),
+                    from: &smart_account_token_account.to_account_info(),
+                    mint: &mint.to_account_info(),
+                    to: destination_token_account,
+                    authority: smart_account,
+                    extra_accounts: remaining_accounts,
+                },
                 args.amount,
                 args.decimals,
+                &[&[
+                    SEED_PREFIX,
+                    settings_key.as_ref(),
+                    SEED_SMART_ACCOUNT,
+                    &spending_limit.account_index.to_le_bytes(),
+                    &[smart_account_bump],
+                ]],
             )?;
         }
         // Log the event
@@ -276,9 +409,14 @@ impl UseSpendingLimit<'_> {
             destination: destination.key(),
             destination_token_account: destination_token_account.map_or(Pubkey::default(),|account| account.key()),
             signer: ctx.accounts.signer.key(),
+            cosigners: cosigners.iter().map(|cosigner| cosigner.key()).collect(),
             mint: mint.map_or(Pubkey::default(),|account| account.key()),
             mint_decimals: args.decimals,
             amount: args.amount,
+            transfer_fee: fee,
+            charged_amount,
+            signer_remaining_amount: spending_limit
+                .signer_remaining_amount_of(&ctx.accounts.signer.key(), &mint_key),
             spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
         };
         let settings = &ctx.accounts.settings;
@@ -286,7 +424,7 @@ impl UseSpendingLimit<'_> {
             authority: settings.to_account_info(),
             authority_seeds: get_settings_signer_seeds(settings.seed),
             bump: settings.bump,
-            program: ctx.accounts.program.to_account_info(),
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
         };
         SmartAccountEvent::UseSpendingLimitEvent(event).log(&log_authority_info)?;
         Ok(())
@@ -0,0 +1,378 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::*;
+use crate::events::*;
+use crate::program::SquadsSmartAccountProgram;
+use crate::state::*;
+use crate::utils::{transfer_checked_with_extra_accounts, transfer_fee, TransferCheckedAccounts};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SpendingLimitTransfer {
+    /// Destination to transfer tokens to.
+    pub destination: Pubkey,
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UseSpendingLimitBatchArgs {
+    /// Transfers to make, in the order of their accounts in `remaining_accounts`
+    /// following the co-signers.
+    pub transfers: Vec<SpendingLimitTransfer>,
+    /// Decimals of the token mint. Used for double-checking against incorrect order of magnitude of the amounts.
+    pub decimals: u8,
+    /// Number of accounts following the account of each transfer in `remaining_accounts`,
+    /// passed on to the token program for that transfer,
+    /// e.g. the extra accounts required by the transfer hook of a Token-2022 mint.
+    pub num_extra_accounts: u8,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UseSpendingLimitBatch<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// Smart account to transfer tokens from.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    /// In case `spending_limit.mint` is SOL.
+    pub system_program: Option<Program<'info, System>>,
+
+    /// The mint of the tokens to transfer in case `spending_limit.mint` is an SPL token.
+    /// CHECK: We do the checks in `UseSpendingLimitBatch::validate`.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Smart account token account to transfer tokens from in case `spending_limit.mint` is an SPL token.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = smart_account,
+    )]
+    pub smart_account_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// In case `spending_limit.mint` is an SPL token.
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+
+    /// Price account of `spending_limit.mint` in case the spending limit has a `price_oracle`.
+    /// CHECK: We do the checks in `SpendingLimit::value_of`.
+    pub price_account: Option<AccountInfo<'info>>,
+    // `remaining_accounts` must start with the `spending_limit.threshold - 1` co-signers,
+    // followed by one writable account per transfer, in order:
+    // the destination itself when transferring SOL, or the destination token account otherwise.
+    // Each of them is followed by the `args.num_extra_accounts` extra accounts of its transfer.
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl<'info> UseSpendingLimitBatch<'info> {
+    fn validate(
+        &self,
+        args: &UseSpendingLimitBatchArgs,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let Self {
+            signer,
+            spending_limit,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            spending_limit.signers.contains(&signer.key()),
+            SmartAccountError::Unauthorized
+        );
+
+        // cosigners
+        // `spending_limit.threshold - 1` other signers must co-sign the use.
+        let (cosigners, remaining_accounts) =
+            spending_limit.split_cosigners(strip_event_sequence(remaining_accounts))?;
+        spending_limit.check_cosigners(&signer.key(), cosigners)?;
+
+        // mint
+        require!(
+            spending_limit.covers_mint(&self.mint_key()),
+            SmartAccountError::InvalidMint
+        );
+
+        // transfers
+        require!(
+            !args.transfers.is_empty(),
+            SmartAccountError::InvalidInstructionArgs
+        );
+        // Extra accounts are only passed on to the token program.
+        require!(
+            self.mint.is_some() || args.num_extra_accounts == 0,
+            SmartAccountError::InvalidNumberOfAccounts
+        );
+        require!(
+            args.transfers.len() * transfer_accounts_len(args) == remaining_accounts.len(),
+            SmartAccountError::InvalidNumberOfAccounts
+        );
+        let transfer_accounts = remaining_accounts.chunks(transfer_accounts_len(args));
+        for (transfer, accounts) in args.transfers.iter().zip(transfer_accounts) {
+            let account = &accounts[0];
+            if !spending_limit.destinations.is_empty() {
+                require!(
+                    spending_limit.destinations.contains(&transfer.destination),
+                    SmartAccountError::InvalidDestination
+                );
+            }
+
+            require!(account.is_writable, SmartAccountError::InvalidAccount);
+            match &self.mint {
+                // The destination itself receives SOL.
+                None => require_keys_eq!(
+                    *account.key,
+                    transfer.destination,
+                    SmartAccountError::InvalidDestination
+                ),
+                // Otherwise a token account of the mint owned by the destination.
+                Some(mint) => {
+                    let token_account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+                    require_keys_eq!(
+                        token_account.mint,
+                        mint.key(),
+                        SmartAccountError::InvalidMint
+                    );
+                    require_keys_eq!(
+                        token_account.owner,
+                        transfer.destination,
+                        SmartAccountError::InvalidDestination
+                    );
+                }
+            }
+        }
+
+        // Spending limit must not be paused.
+        require!(
+            !spending_limit.extension.paused,
+            SmartAccountError::SpendingLimitPaused
+        );
+
+        // Spending limit must not be expired.
+        if spending_limit.expiration != i64::MAX {
+            require!(
+                spending_limit.expiration > Clock::get()?.unix_timestamp,
+                SmartAccountError::SpendingLimitExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The mint being transferred, `Pubkey::default()` for SOL.
+    fn mint_key(&self) -> Pubkey {
+        self.mint
+            .as_ref()
+            .map_or(Pubkey::default(), |mint| mint.key())
+    }
+
+    /// Use a spending limit to transfer tokens from a smart account to multiple destinations.
+    /// The total amount is debited from the spending limit at once.
+    #[access_control(ctx.accounts.validate(&args, ctx.remaining_accounts))]
+    pub fn use_spending_limit_batch(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: UseSpendingLimitBatchArgs,
+    ) -> Result<()> {
+        let mint_key = ctx.accounts.mint_key();
+        let signer = ctx.accounts.signer.key();
+        let (cosigners, transfer_accounts) = ctx
+            .accounts
+            .spending_limit
+            .split_cosigners(strip_event_sequence(ctx.remaining_accounts))?;
+        let transfer_accounts: Vec<_> = transfer_accounts
+            .chunks(transfer_accounts_len(&args))
+            .collect();
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        let smart_account = &ctx.accounts.smart_account;
+
+        let settings_key = ctx.accounts.settings.key();
+        let smart_account_bump = ctx.bumps.smart_account;
+        let now: i64 = Clock::get()?.unix_timestamp;
+
+        let total_amount = args
+            .transfers
+            .iter()
+            .try_fold(0_u64, |total, transfer| total.checked_add(transfer.amount))
+            .ok_or(SmartAccountError::SpendingLimitExceeded)?;
+        // Fees withheld from the transfers by the Token-2022 transfer fee extension of the mint.
+        let epoch = Clock::get()?.epoch;
+        let total_transfer_fee = match &ctx.accounts.mint {
+            Some(mint) => args.transfers.iter().try_fold(0_u64, |total, transfer| {
+                let fee = transfer_fee(&mint.to_account_info(), transfer.amount, epoch)?;
+                Ok::<u64, Error>(total.saturating_add(fee))
+            })?,
+            None => 0,
+        };
+
+        // Reset `spending_limit.remaining_amount` if the `spending_limit.period` has passed.
+        spending_limit.reset_if_period_passed(now);
+
+        // Update the remaining amount of the mint.
+        // This will also check if the charged amount doesn't exceed the remaining amount.
+        let charged_amount = spending_limit.value_of(
+            spending_limit
+                .extension
+                .transfer_fee_charging
+                .charged_amount(total_amount, total_transfer_fee),
+            args.decimals,
+            ctx.accounts.price_account.as_ref(),
+            now,
+        )?;
+        spending_limit.debit(&signer, &mint_key, charged_amount, now)?;
+        let memo_hash = SpendingLimitUse::memo_hash(&args.memo);
+        for transfer in &args.transfers {
+            spending_limit.record_use(SpendingLimitUse {
+                signer,
+                mint: mint_key,
+                destination: transfer.destination,
+                amount: transfer.amount,
+                timestamp: now,
+                memo_hash,
+            });
+        }
+
+        let account_index = spending_limit.account_index.to_le_bytes();
+        let smart_account_seeds: &[&[u8]] = &[
+            SEED_PREFIX,
+            settings_key.as_ref(),
+            SEED_SMART_ACCOUNT,
+            &account_index,
+            &[smart_account_bump],
+        ];
+
+        // Transfer tokens.
+        for (transfer, accounts) in args.transfers.iter().zip(&transfer_accounts) {
+            let (destination, extra_accounts) = (&accounts[0], &accounts[1..]);
+            if mint_key == Pubkey::default() {
+                // Transfer using the system_program::transfer.
+                let system_program = &ctx
+                    .accounts
+                    .system_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                // Sanity check for the decimals. Similar to the one in token_interface::transfer_checked.
+                require!(args.decimals == 9, SmartAccountError::DecimalsMismatch);
+
+                anchor_lang::system_program::transfer(
+                    CpiContext::new_with_signer(
+                        system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: smart_account.clone(),
+                            to: destination.clone(),
+                        },
+                        &[smart_account_seeds],
+                    ),
+                    transfer.amount,
+                )?
+            } else {
+                // Transfer using the token_program::transfer_checked.
+                let mint = &ctx
+                    .accounts
+                    .mint
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let smart_account_token_account = &ctx
+                    .accounts
+                    .smart_account_token_account
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+                let token_program = &ctx
+                    .accounts
+                    .token_program
+                    .as_ref()
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                transfer_checked_with_extra_accounts(
+                    TransferCheckedAccounts {
+                        token_program: &token_program.to_account_info(),
+                        from: &smart_account_token_account.to_account_info(),
+                        mint: &mint.to_account_info(),
+                        to: destination,
+                        authority: smart_account,
+                        extra_accounts,
+                    },
+                    transfer.amount,
+                    args.decimals,
+                    &[smart_account_seeds],
+                )?;
+            }
+        }
+
+        // Log the event
+        let event = UseSpendingLimitBatchEvent {
+            settings_pubkey: settings_key,
+            spending_limit_pubkey: spending_limit.key(),
+            smart_account: smart_account.key(),
+            smart_account_token_account: ctx
+                .accounts
+                .smart_account_token_account
+                .as_ref()
+                .map_or(Pubkey::default(), |account| account.key()),
+            signer,
+            cosigners: cosigners.iter().map(|cosigner| cosigner.key()).collect(),
+            mint: mint_key,
+            mint_decimals: args.decimals,
+            transfers: args.transfers,
+            destination_accounts: transfer_accounts
+                .iter()
+                .map(|accounts| accounts[0].key())
+                .collect(),
+            total_amount,
+            total_transfer_fee,
+            charged_amount,
+            signer_remaining_amount: spending_limit.signer_remaining_amount_of(&signer, &mint_key),
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        let settings = &ctx.accounts.settings;
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::UseSpendingLimitBatchEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+}
+
+/// Number of `remaining_accounts` of every transfer: its destination account and extra accounts.
+fn transfer_accounts_len(args: &UseSpendingLimitBatchArgs) -> usize {
+    1 + args.num_extra_accounts as usize
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::*,
+    events::*,
+    program::SquadsSmartAccountProgram,
+    state::*,
+    utils::{
+        check_spending_limit_instructions, SmartAccountAssetBalances, SynchronousTransactionMessage,
+    },
+    SmallVec,
+};
+
+use super::CompiledInstruction;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UseSpendingLimitSyncArgs {
+    /// Expected to be serialized as a SmallVec<u8, CompiledInstruction>
+    pub instructions: Vec<u8>,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UseSpendingLimitSync<'info> {
+    /// The settings the `spending_limit` belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_SETTINGS, settings.seed.to_le_bytes().as_ref()],
+        bump = settings.bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    pub signer: Signer<'info>,
+
+    /// The SpendingLimit account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SPENDING_LIMIT,
+            spending_limit.seed.as_ref(),
+        ],
+        bump = spending_limit.bump,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// Smart account the transaction is executed on behalf of.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            settings.key().as_ref(),
+            SEED_SMART_ACCOUNT,
+            &spending_limit.account_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub smart_account: AccountInfo<'info>,
+
+    pub program: Program<'info, SquadsSmartAccountProgram>,
+    // `remaining_accounts` must start with the `spending_limit.threshold - 1` co-signers,
+    // followed by the accounts referenced by the instructions.
+    // The `EventSequence` of the settings can be passed as an optional trailing account
+    // to log sequenced events.
+}
+
+impl UseSpendingLimitSync<'_> {
+    fn validate(&self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Self {
+            signer,
+            spending_limit,
+            ..
+        } = self;
+
+        // signer
+        require!(
+            spending_limit.signers.contains(&signer.key()),
+            SmartAccountError::Unauthorized
+        );
+
+        // cosigners
+        // `spending_limit.threshold - 1` other signers must co-sign the use.
+        let (cosigners, _) = spending_limit.split_cosigners(remaining_accounts)?;
+        spending_limit.check_cosigners(&signer.key(), cosigners)?;
+
+        // destinations
+        // The recipients of an arbitrary transaction can't be determined,
+        // so only spending limits without a destination allowlist can be used this way.
+        require!(
+            spending_limit.destinations.is_empty(),
+            SmartAccountError::InvalidDestination
+        );
+
+        // price_oracle
+        // Outflows can only be measured in raw amounts of their mints.
+        require!(
+            spending_limit.extension.price_oracle.is_none(),
+            SmartAccountError::InvalidPriceOracle
+        );
+
+        // Spending limit must not be paused.
+        require!(
+            !spending_limit.extension.paused,
+            SmartAccountError::SpendingLimitPaused
+        );
+
+        // Spending limit must not be expired.
+        if spending_limit.expiration != i64::MAX {
+            require!(
+                spending_limit.expiration > Clock::get()?.unix_timestamp,
+                SmartAccountError::SpendingLimitExpired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Use a spending limit to execute an arbitrary transaction on behalf of the smart account.
+    ///
+    /// The instructions can only invoke the `allowed_programs` of the spending limit, and can't
+    /// approve delegates or set authorities of token accounts.
+    /// The net outflow of every mint covered by the spending limit is debited from its remaining
+    /// amount. Outflow of any other asset, any change of the owner, delegate or close
+    /// authority of the smart account's token accounts, or of the owner or data of the smart
+    /// account itself, fails the transaction.
+    /// Only SOL held by the smart account and the balances of its token accounts passed as
+    /// writable can be measured, so the transaction can't write to any other account owned by
+    /// the smart account or referencing it, such as stake accounts it is the authority of.
+    #[access_control(ctx.accounts.validate(ctx.remaining_accounts))]
+    pub fn use_spending_limit_sync(
+        ctx: Context<Self>,
+        args: UseSpendingLimitSyncArgs,
+    ) -> Result<()> {
+        let settings = &ctx.accounts.settings;
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        let smart_account = &ctx.accounts.smart_account;
+
+        let signer = ctx.accounts.signer.key();
+        let (cosigners, remaining_accounts) =
+            spending_limit.split_cosigners(strip_event_sequence(ctx.remaining_accounts))?;
+        let settings_key = settings.key();
+        let smart_account_bump = ctx.bumps.smart_account;
+        let now: i64 = Clock::get()?.unix_timestamp;
+
+        // Reset `spending_limit.remaining_amount` if the `spending_limit.period` has passed.
+        spending_limit.reset_if_period_passed(now);
+
+        // Deserialize the instructions
+        let compiled_instructions =
+            SmallVec::<u8, CompiledInstruction>::try_from_slice(&args.instructions)
+                .map_err(|_| SmartAccountError::InvalidInstructionArgs)?;
+        let instructions: Vec<SmartAccountCompiledInstruction> = Vec::from(compiled_instructions)
+            .into_iter()
+            .map(SmartAccountCompiledInstruction::from)
+            .collect();
+
+        let executable_message = SynchronousTransactionMessage::new_validated(
+            &settings_key,
+            settings,
+            smart_account.key,
+            instructions,
+            remaining_accounts,
+            &[],
+        )?;
+
+        // Only the programs allowed by the spending limit can be invoked.
+        check_spending_limit_instructions(
+            &spending_limit.extension.allowed_programs,
+            &executable_message.instructions,
+            &executable_message.accounts,
+        )?;
+
+        let balances =
+            SmartAccountAssetBalances::snapshot(smart_account, &executable_message.accounts)?;
+
+        executable_message.execute(
+            &[
+                SEED_PREFIX,
+                settings_key.as_ref(),
+                SEED_SMART_ACCOUNT,
+                &spending_limit.account_index.to_le_bytes(),
+                &[smart_account_bump],
+            ],
+            &[],
+        )?;
+
+        let outflows = balances.outflows(smart_account, &executable_message.accounts)?;
+
+        // Update the remaining amounts of the mints that left the smart account.
+        // This will also check that no outflow exceeds the remaining amount of its mint.
+        let memo_hash = SpendingLimitUse::memo_hash(&args.memo);
+        for outflow in &outflows {
+            require!(
+                spending_limit.covers_mint(&outflow.mint),
+                SmartAccountError::SpendingLimitInvalidOutflow
+            );
+            spending_limit.debit(&signer, &outflow.mint, outflow.amount, now)?;
+            // The recipients of an arbitrary transaction can't be determined.
+            spending_limit.record_use(SpendingLimitUse {
+                signer,
+                mint: outflow.mint,
+                destination: Pubkey::default(),
+                amount: outflow.amount,
+                timestamp: now,
+                memo_hash,
+            });
+        }
+
+        // Log the event
+        let event = UseSpendingLimitSyncEvent {
+            settings_pubkey: settings_key,
+            spending_limit_pubkey: spending_limit.key(),
+            smart_account: smart_account.key(),
+            signer,
+            cosigners: cosigners.iter().map(|cosigner| cosigner.key()).collect(),
+            outflows,
+            instruction_accounts: executable_message
+                .accounts
+                .iter()
+                .map(|account| account.key())
+                .collect(),
+            instructions: executable_message.instructions,
+            spending_limit: SpendingLimit::try_from_slice(&spending_limit.try_to_vec()?)?,
+        };
+        let log_authority_info = LogAuthorityInfo {
+            authority: settings.to_account_info(),
+            authority_seeds: get_settings_signer_seeds(settings.seed),
+            bump: settings.bump,
+            log_accounts: LogAccounts::new(ctx.accounts.program.key(), ctx.remaining_accounts),
+        };
+        SmartAccountEvent::UseSpendingLimitSyncEvent(event).log(&log_authority_info)?;
+        Ok(())
+    }
+}
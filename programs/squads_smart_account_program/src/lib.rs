@@ -13,7 +13,7 @@ use solana_security_txt::security_txt;
 pub use instructions::ProgramConfig;
 pub use instructions::*;
 pub use state::*;
-pub use utils::SmallVec;
+pub use utils::{AccountExtension, SmallVec};
 pub use events::*;
 
 pub mod allocator;
@@ -84,6 +84,12 @@ pub mod squads_smart_account_program {
         CreateSmartAccount::create_smart_account(ctx, args)
     }
 
+    /// Create the event sequence of a smart account created before event sequences,
+    /// so its events are stamped with sequence numbers.
+    pub fn initialize_event_sequence(ctx: Context<InitEventSequence>) -> Result<()> {
+        InitEventSequence::init_event_sequence(ctx)
+    }
+
     /// Add a new signer to the controlled smart account.
     pub fn add_signer_as_authority(
         ctx: Context<ExecuteSettingsTransactionAsAuthority>,
@@ -108,6 +114,14 @@ pub mod squads_smart_account_program {
         ExecuteSettingsTransactionAsAuthority::set_time_lock(ctx, args)
     }
 
+    /// Set the `time_lock_bypass` config parameter for the controlled smart account.
+    pub fn set_time_lock_bypass_as_authority(
+        ctx: Context<ExecuteSettingsTransactionAsAuthority>,
+        args: SetTimeLockBypassArgs,
+    ) -> Result<()> {
+        ExecuteSettingsTransactionAsAuthority::set_time_lock_bypass(ctx, args)
+    }
+
     /// Set the `threshold` config parameter for the controlled smart account.
     pub fn change_threshold_as_authority(
         ctx: Context<ExecuteSettingsTransactionAsAuthority>,
@@ -148,6 +162,14 @@ pub mod squads_smart_account_program {
         RemoveSpendingLimitAsAuthority::remove_spending_limit(ctx, args)
     }
 
+    /// Update a spending limit of the controlled smart account in place.
+    pub fn update_spending_limit_as_authority(
+        ctx: Context<UpdateSpendingLimitAsAuthority>,
+        args: UpdateSpendingLimitArgs,
+    ) -> Result<()> {
+        UpdateSpendingLimitAsAuthority::update_spending_limit(ctx, args)
+    }
+
     /// Create a new settings transaction.
     pub fn create_settings_transaction(
         ctx: Context<CreateSettingsTransaction>,
@@ -156,6 +178,16 @@ pub mod squads_smart_account_program {
         CreateSettingsTransaction::create_settings_transaction(ctx, args)
     }
 
+    /// Create a new settings transaction from a completed transaction buffer
+    /// holding its serialized actions.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    pub fn create_settings_transaction_from_buffer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSettingsTransactionFromBuffer<'info>>,
+        args: CreateSettingsTransactionArgs,
+    ) -> Result<()> {
+        CreateSettingsTransactionFromBuffer::create_settings_transaction_from_buffer(ctx, args)
+    }
+
     /// Execute a settings transaction.
     /// The transaction must be `Approved`.
     pub fn execute_settings_transaction<'info>(
@@ -172,6 +204,14 @@ pub mod squads_smart_account_program {
         CreateTransaction::create_transaction(ctx, args)
     }
 
+    /// Create a new vault transaction, storing its message compressed or only its hash.
+    pub fn create_transaction_v2(
+        ctx: Context<CreateTransactionV2>,
+        args: CreateTransactionV2Args,
+    ) -> Result<()> {
+        CreateTransactionV2::create_transaction_v2(ctx, args)
+    }
+
     /// Create a transaction buffer account.
     pub fn create_transaction_buffer(
         ctx: Context<CreateTransactionBuffer>,
@@ -186,6 +226,7 @@ pub mod squads_smart_account_program {
     }
 
     /// Extend a transaction buffer account.
+    /// Buffers larger than `MAX_BUFFER_SIZE` are reallocated as they grow.
     pub fn extend_transaction_buffer(
         ctx: Context<ExtendTransactionBuffer>,
         args: ExtendTransactionBufferArgs,
@@ -193,6 +234,15 @@ pub mod squads_smart_account_program {
         ExtendTransactionBuffer::extend_transaction_buffer(ctx, args)
     }
 
+    /// Write a chunk of a transaction buffer account at the given offset.
+    /// Chunks can be written in any order and written again, so that uploads can be resumed.
+    pub fn write_transaction_buffer(
+        ctx: Context<WriteTransactionBuffer>,
+        args: WriteTransactionBufferArgs,
+    ) -> Result<()> {
+        WriteTransactionBuffer::write_transaction_buffer(ctx, args)
+    }
+
     /// Create a new vault transaction from a completed transaction buffer.
     /// Finalized buffer hash must match `final_buffer_hash`
     pub fn create_transaction_from_buffer<'info>(
@@ -202,12 +252,32 @@ pub mod squads_smart_account_program {
         CreateTransactionFromBuffer::create_transaction_from_buffer(ctx, args)
     }
 
+    /// Create a new vault transaction from a completed transaction buffer,
+    /// optionally storing its message compressed.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    pub fn create_transaction_from_buffer_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateTransactionFromBufferV2<'info>>,
+        args: CreateTransactionV2Args,
+    ) -> Result<()> {
+        CreateTransactionFromBufferV2::create_transaction_from_buffer_v2(ctx, args)
+    }
+
     /// Execute a smart account transaction.
     /// The transaction must be `Approved`.
     pub fn execute_transaction(ctx: Context<ExecuteTransaction>) -> Result<()> {
         ExecuteTransaction::execute_transaction(ctx)
     }
 
+    /// Execute a smart account transaction.
+    /// The transaction must be `Approved`.
+    /// Transactions created with only the hash of their message must reveal it.
+    pub fn execute_transaction_v2(
+        ctx: Context<ExecuteTransactionV2>,
+        args: ExecuteTransactionV2Args,
+    ) -> Result<()> {
+        ExecuteTransactionV2::execute_transaction_v2(ctx, args)
+    }
+
     /// Create a new batch.
     pub fn create_batch(ctx: Context<CreateBatch>, args: CreateBatchArgs) -> Result<()> {
         CreateBatch::create_batch(ctx, args)
@@ -221,6 +291,15 @@ pub mod squads_smart_account_program {
         AddTransactionToBatch::add_transaction_to_batch(ctx, args)
     }
 
+    /// Add a transaction to the batch from a completed transaction buffer.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    pub fn add_transaction_to_batch_from_buffer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddTransactionToBatchFromBuffer<'info>>,
+        args: AddTransactionToBatchArgs,
+    ) -> Result<()> {
+        AddTransactionToBatchFromBuffer::add_transaction_to_batch_from_buffer(ctx, args)
+    }
+
     /// Execute a transaction from the batch.
     pub fn execute_batch_transaction(ctx: Context<ExecuteBatchTransaction>) -> Result<()> {
         ExecuteBatchTransaction::execute_batch_transaction(ctx)
@@ -255,13 +334,57 @@ pub mod squads_smart_account_program {
     }
 
     /// Use a spending limit to transfer tokens from a smart account vault to a destination account.
-    pub fn use_spending_limit(
-        ctx: Context<UseSpendingLimit>,
+    pub fn use_spending_limit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UseSpendingLimit<'info>>,
         args: UseSpendingLimitArgs,
     ) -> Result<()> {
         UseSpendingLimit::use_spending_limit(ctx, args)
     }
 
+    /// Use a spending limit to synchronously execute a transaction on behalf of a smart account.
+    /// The net outflow of the spending limit mint is debited from the spending limit.
+    pub fn use_spending_limit_sync(
+        ctx: Context<UseSpendingLimitSync>,
+        args: UseSpendingLimitSyncArgs,
+    ) -> Result<()> {
+        UseSpendingLimitSync::use_spending_limit_sync(ctx, args)
+    }
+
+    /// Use a spending limit to transfer tokens from a smart account vault to multiple destinations.
+    pub fn use_spending_limit_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UseSpendingLimitBatch<'info>>,
+        args: UseSpendingLimitBatchArgs,
+    ) -> Result<()> {
+        UseSpendingLimitBatch::use_spending_limit_batch(ctx, args)
+    }
+
+    /// Pause a spending limit without reaching threshold.
+    /// Can be called by one of the spending limit pausers, or the `settings_authority` of a controlled smart account.
+    /// Resuming the spending limit requires a spending limit update.
+    pub fn pause_spending_limit(
+        ctx: Context<PauseSpendingLimit>,
+        args: PauseSpendingLimitArgs,
+    ) -> Result<()> {
+        PauseSpendingLimit::pause_spending_limit(ctx, args)
+    }
+
+    /// Use an NFT allowance of a spending limit to transfer an NFT from a smart account vault to a destination account.
+    pub fn use_nft_spending_limit(
+        ctx: Context<UseNftSpendingLimit>,
+        args: UseNftSpendingLimitArgs,
+    ) -> Result<()> {
+        UseNftSpendingLimit::use_nft_spending_limit(ctx, args)
+    }
+
+    /// Use an NFT allowance of a spending limit to synchronously execute transfers of compressed NFTs
+    /// on behalf of a smart account, as approved by the compressed NFT verifier of the allowance.
+    pub fn use_compressed_nft_spending_limit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UseCompressedNftSpendingLimit<'info>>,
+        args: UseCompressedNftSpendingLimitArgs,
+    ) -> Result<()> {
+        UseCompressedNftSpendingLimit::use_compressed_nft_spending_limit(ctx, args)
+    }
+
     /// Closes a `SettingsTransaction` and the corresponding `Proposal`.
     /// `transaction` can be closed if either:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
@@ -296,6 +419,35 @@ pub mod squads_smart_account_program {
         CloseBatch::close_batch(ctx)
     }
 
+    /// Same as `close_settings_transaction`, logging a memo in the events.
+    pub fn close_settings_transaction_v2(
+        ctx: Context<CloseSettingsTransaction>,
+        args: CloseTransactionArgs,
+    ) -> Result<()> {
+        CloseSettingsTransaction::close_settings_transaction_v2(ctx, args)
+    }
+
+    /// Same as `close_transaction`, logging a memo in the events.
+    pub fn close_transaction_v2(
+        ctx: Context<CloseTransaction>,
+        args: CloseTransactionArgs,
+    ) -> Result<()> {
+        CloseTransaction::close_transaction_v2(ctx, args)
+    }
+
+    /// Same as `close_batch_transaction`, logging a memo in the event.
+    pub fn close_batch_transaction_v2(
+        ctx: Context<CloseBatchTransaction>,
+        args: CloseTransactionArgs,
+    ) -> Result<()> {
+        CloseBatchTransaction::close_batch_transaction_v2(ctx, args)
+    }
+
+    /// Same as `close_batch`, logging a memo in the events.
+    pub fn close_batch_v2(ctx: Context<CloseBatch>, args: CloseTransactionArgs) -> Result<()> {
+        CloseBatch::close_batch_v2(ctx, args)
+    }
+
     /// Synchronously execute a transaction
     pub fn execute_transaction_sync(
         ctx: Context<SyncTransaction>,
@@ -304,6 +456,14 @@ pub mod squads_smart_account_program {
         SyncTransaction::sync_transaction(ctx, args)
     }
 
+    /// Synchronously execute a transaction that loads accounts through address lookup tables
+    pub fn execute_transaction_sync_v2(
+        ctx: Context<SyncTransactionV2>,
+        args: SyncTransactionV2Args,
+    ) -> Result<()> {
+        SyncTransactionV2::sync_transaction_v2(ctx, args)
+    }
+
     /// Synchronously execute a config transaction
     pub fn execute_settings_transaction_sync<'info>(
         ctx: Context<'_, '_, 'info, 'info, SyncSettingsTransaction<'info>>,
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::errors::SmartAccountError;
+use crate::utils::create_account;
+use crate::{SEED_EVENT_SEQUENCE, SEED_PREFIX};
+
+/// Counter stamping the events logged for a `Settings` account with consecutive sequence numbers,
+/// so indexers can detect missed events and backfill them.
+/// Kept out of the settings, so instructions that only read the settings don't have to lock them
+/// to log their events.
+#[account]
+#[derive(InitSpace)]
+pub struct EventSequence {
+    /// The settings whose events are sequenced.
+    pub settings: Pubkey,
+    /// Sequence number of the next logged event. Wraps around after `u64::MAX`.
+    pub next_sequence: u64,
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl EventSequence {
+    pub fn size() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Returns the sequence number for the next logged event and advances the counter.
+    pub fn advance(&mut self) -> u64 {
+        let sequence = self.next_sequence;
+        self.next_sequence = sequence.wrapping_add(1);
+        sequence
+    }
+
+    /// Whether the account is an initialized `EventSequence`, of any settings.
+    pub fn is_event_sequence(account: &AccountInfo) -> bool {
+        account.owner == &crate::ID
+            && account
+                .try_borrow_data()
+                .is_ok_and(|data| data.starts_with(&Self::DISCRIMINATOR))
+    }
+
+    /// Advances the `EventSequence` stored in `account`, which must belong to `settings`,
+    /// and returns the sequence number for the next logged event.
+    pub fn advance_account(account: &AccountInfo, settings: &Pubkey) -> Result<u64> {
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        require!(account.is_writable, ErrorCode::AccountNotMutable);
+
+        let mut data = account.try_borrow_mut_data()?;
+        let mut event_sequence = EventSequence::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(
+            event_sequence.settings,
+            *settings,
+            SmartAccountError::InvalidAccount
+        );
+
+        let sequence = event_sequence.advance();
+        event_sequence.try_serialize(&mut &mut data[..])?;
+        Ok(sequence)
+    }
+
+    /// Creates the `EventSequence` of `settings` if the caller passed it in `remaining_accounts`.
+    /// Used when the settings are created, so their first event is already sequenced.
+    pub fn find_and_initialize<'info>(
+        settings: &Pubkey,
+        rent_payer: &AccountInfo<'info>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        system_program: &Program<'info, System>,
+    ) -> Result<Option<&'info AccountInfo<'info>>> {
+        let (event_sequence_key, bump) = Pubkey::find_program_address(
+            &[SEED_PREFIX, settings.as_ref(), SEED_EVENT_SEQUENCE],
+            &crate::ID,
+        );
+        let Some(event_sequence_info) = remaining_accounts
+            .iter()
+            .find(|acc| acc.key == &event_sequence_key)
+        else {
+            return Ok(None);
+        };
+
+        require!(
+            event_sequence_info.owner == &system_program::ID,
+            ErrorCode::AccountNotSystemOwned
+        );
+        require!(
+            event_sequence_info.is_writable,
+            ErrorCode::AccountNotMutable
+        );
+
+        create_account(
+            rent_payer,
+            event_sequence_info,
+            system_program,
+            &crate::ID,
+            &Rent::get()?,
+            Self::size(),
+            vec![
+                SEED_PREFIX.to_vec(),
+                settings.to_bytes().to_vec(),
+                SEED_EVENT_SEQUENCE.to_vec(),
+                vec![bump],
+            ],
+        )?;
+
+        let event_sequence = EventSequence {
+            settings: *settings,
+            next_sequence: 0,
+            bump,
+        };
+        event_sequence.try_serialize(&mut &mut event_sequence_info.try_borrow_mut_data()?[..])?;
+
+        Ok(Some(event_sequence_info))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_sequence_advances_and_wraps() {
+        let mut event_sequence = EventSequence {
+            settings: Pubkey::new_unique(),
+            next_sequence: 0,
+            bump: 255,
+        };
+
+        assert_eq!(event_sequence.advance(), 0);
+        assert_eq!(event_sequence.advance(), 1);
+
+        event_sequence.next_sequence = u64::MAX - 1;
+
+        assert_eq!(event_sequence.advance(), u64::MAX - 1);
+        assert_eq!(event_sequence.advance(), u64::MAX);
+        assert_eq!(event_sequence.advance(), 0);
+        assert_eq!(event_sequence.next_sequence, 1);
+    }
+
+    #[test]
+    fn test_advance_account_only_for_its_settings() {
+        let settings = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![];
+        EventSequence {
+            settings,
+            next_sequence: 7,
+            bump: 255,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        let account = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        assert!(EventSequence::is_event_sequence(&account));
+        assert_eq!(
+            EventSequence::advance_account(&account, &Pubkey::new_unique()),
+            Err(SmartAccountError::InvalidAccount.into())
+        );
+        assert_eq!(EventSequence::advance_account(&account, &settings), Ok(7));
+        assert_eq!(EventSequence::advance_account(&account, &settings), Ok(8));
+    }
+}
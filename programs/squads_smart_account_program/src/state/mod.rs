@@ -1,5 +1,6 @@
 pub use self::settings::*;
 pub use batch::*;
+pub use event_sequence::*;
 pub use settings_transaction::*;
 pub use program_config::*;
 pub use proposal::*;
@@ -9,6 +10,7 @@ pub use transaction_buffer::*;
 pub use transaction::*;
 
 mod batch;
+mod event_sequence;
 mod settings_transaction;
 mod settings;
 mod program_config;
@@ -5,6 +5,7 @@ use crate::{errors::*, id, state::*, utils::*, SettingsAction};
 pub const MAX_TIME_LOCK: u32 = 3 * 30 * 24 * 60 * 60; // 3 months
 
 #[account]
+#[derive(Debug)]
 pub struct Settings {
     /// An integer that is used seed the settings PDA. Its incremented by 1
     /// inside the program conifg by 1 for each smart account created. This is
@@ -47,9 +48,11 @@ pub struct Settings {
     pub signers: Vec<SmartAccountSigner>,
     /// Counter for how many sub accounts are in use (improves off-chain indexing)
     pub account_utilization: u8,
-    // Reserved for future use
+    /// Reserved for future use
     pub _reserved1: u8,
-    pub _reserved2: u8,
+    /// State added after the account layout was frozen, stored in place of the former `_reserved2`.
+    /// Settings created before then read it as `SettingsExtension::None`.
+    pub extension: SettingsExtension,
 }
 
 impl Settings {
@@ -114,8 +117,13 @@ impl Settings {
         4  + // signers vector length
         signers_length * SmartAccountSigner::INIT_SPACE + // signers
         1  + // sub_account_utilization
-        1  + // _reserved_1
-        1 // _reserved_2
+        1  + // _reserved1
+        SettingsExtension::INIT_SPACE // extension
+    }
+
+    /// Size of the account as laid out before `SettingsExtension`, with `SettingsExtension::None`.
+    pub fn size_without_extension(signers_length: usize) -> usize {
+        Settings::size(signers_length) - SettingsExtension::INIT_SPACE + 1
     }
 
     pub fn num_voters(signers: &[SmartAccountSigner]) -> usize {
@@ -139,33 +147,47 @@ impl Settings {
             .count()
     }
 
-    /// Check if the settings account space needs to be reallocated to accommodate `signers_length`.
-    /// Returns `true` if the account was reallocated.
+    /// Check if the settings account space needs to be reallocated to accommodate its signers
+    /// and `SettingsExtension`. Returns `true` if the account was reallocated.
+    ///
+    /// Settings created before `SettingsExtension` are switched to its latest version
+    /// when the account can fit it or a `rent_payer` pays for it, and keep their layout otherwise.
     pub fn realloc_if_needed<'a>(
-        settings: AccountInfo<'a>,
-        signers_length: usize,
+        settings: &mut Account<'a, Settings>,
         rent_payer: Option<AccountInfo<'a>>,
         system_program: Option<AccountInfo<'a>>,
     ) -> Result<bool> {
+        let settings_info = settings.to_account_info();
+
         // Sanity checks
         require_keys_eq!(
-            *settings.owner,
+            *settings_info.owner,
             id(),
             SmartAccountError::IllegalAccountOwner
         );
 
-        let current_account_size = settings.data.borrow().len();
+        let current_account_size = settings_info.data.borrow().len();
+        let signers_length = settings.signers.len();
         let account_size_to_fit_signers = Settings::size(signers_length);
 
         // Check if we need to reallocate space.
         if current_account_size >= account_size_to_fit_signers {
+            settings.upgrade_extension();
+            return Ok(false);
+        }
+
+        if settings.extension == SettingsExtension::None
+            && rent_payer.is_none()
+            && current_account_size >= Settings::size_without_extension(signers_length)
+        {
             return Ok(false);
         }
 
         let new_size = account_size_to_fit_signers;
 
         // Reallocate more space.
-        realloc(&settings, new_size, rent_payer, system_program)?;
+        realloc(&settings_info, new_size, rent_payer, system_program)?;
+        settings.upgrade_extension();
 
         Ok(true)
     }
@@ -229,6 +251,14 @@ impl Settings {
             SmartAccountError::TimeLockExceedsMaxAllowed
         );
 
+        // Time lock bypass threshold must be stricter than the threshold, but still reachable.
+        if let TimeLockBypass::Threshold(bypass_threshold) = self.time_lock_bypass() {
+            require!(
+                bypass_threshold > *threshold && usize::from(bypass_threshold) <= num_voters,
+                SmartAccountError::InvalidTimeLockBypassThreshold
+            );
+        }
+
         Ok(())
     }
 
@@ -238,6 +268,52 @@ impl Settings {
         self.stale_transaction_index = self.transaction_index;
     }
 
+    /// Switches settings created before `SettingsExtension` to its latest version.
+    /// The account must be reallocated to fit it, see `realloc_if_needed`.
+    fn upgrade_extension(&mut self) {
+        if self.extension == SettingsExtension::None {
+            self.extension = SettingsExtension::V1 {
+                time_lock_bypass: TimeLockBypass::Disabled,
+            };
+        }
+    }
+
+    /// Consensus under which synchronous transactions may be executed despite the `time_lock`.
+    /// Always `TimeLockBypass::Disabled` for settings that predate `SettingsExtension::V1`.
+    pub fn time_lock_bypass(&self) -> TimeLockBypass {
+        match self.extension {
+            SettingsExtension::None => TimeLockBypass::Disabled,
+            SettingsExtension::V1 {
+                time_lock_bypass, ..
+            } => time_lock_bypass,
+        }
+    }
+
+    /// Settings that predate `SettingsExtension::V1` are switched to it
+    /// and must be reallocated afterwards, see `realloc_if_needed`.
+    pub fn set_time_lock_bypass(&mut self, new_time_lock_bypass: TimeLockBypass) {
+        if new_time_lock_bypass == self.time_lock_bypass() {
+            return;
+        }
+        self.upgrade_extension();
+        if let SettingsExtension::V1 {
+            time_lock_bypass, ..
+        } = &mut self.extension
+        {
+            *time_lock_bypass = new_time_lock_bypass;
+        }
+    }
+
+    /// How many voters must sign a synchronous transaction for it to bypass the `time_lock`.
+    /// `None` if synchronous transactions can't bypass the `time_lock`.
+    pub fn time_lock_bypass_threshold(&self) -> Option<usize> {
+        match self.time_lock_bypass() {
+            TimeLockBypass::Disabled => None,
+            TimeLockBypass::Threshold(bypass_threshold) => Some(usize::from(bypass_threshold)),
+            TimeLockBypass::Unanimous => Some(Self::num_voters(&self.signers)),
+        }
+    }
+
     /// Returns `Some(index)` if `signer_pubkey` is a signer, with `index` into the `signers` vec.
     /// `None` otherwise.
     pub fn is_signer(&self, signer_pubkey: Pubkey) -> Option<usize> {
@@ -296,36 +372,39 @@ impl Settings {
         program_id: &Pubkey,
     ) -> Result<()> {
         match action {
-            SettingsAction::AddSigner { new_signer } => {
-                self.add_signer(new_signer.to_owned());
-                self.invalidate_prior_transactions();
-            }
-
-            SettingsAction::RemoveSigner { old_signer } => {
-                self.remove_signer(old_signer.to_owned())?;
-                self.invalidate_prior_transactions();
-            }
-
-            SettingsAction::ChangeThreshold { new_threshold } => {
-                self.threshold = *new_threshold;
-                self.invalidate_prior_transactions();
-            }
-
-            SettingsAction::SetTimeLock { new_time_lock } => {
-                self.time_lock = *new_time_lock;
-                self.invalidate_prior_transactions();
+            SettingsAction::AddSigner { .. }
+            | SettingsAction::RemoveSigner { .. }
+            | SettingsAction::ChangeThreshold { .. }
+            | SettingsAction::SetTimeLock { .. }
+            | SettingsAction::SetTimeLockBypass { .. } => {
+                self.modify_consensus_with_action(action)?;
             }
 
             SettingsAction::AddSpendingLimit {
                 seed,
                 account_index,
+                mint,
+                amount,
+                period,
                 signers,
+                destinations,
+                expiration,
+            }
+            | SettingsAction::AddSpendingLimitWithOptions {
+                seed,
+                account_index,
                 mint,
                 amount,
                 period,
+                signers,
                 destinations,
                 expiration,
+                ..
             } => {
+                let options = match action {
+                    SettingsAction::AddSpendingLimitWithOptions { options, .. } => options.clone(),
+                    _ => SpendingLimitOptions::default(),
+                };
                 let (spending_limit_key, spending_limit_bump) = Pubkey::find_program_address(
                     &[
                         SEED_PREFIX,
@@ -354,7 +433,7 @@ impl Settings {
                     &system_program.to_account_info(),
                     &id(),
                     rent,
-                    SpendingLimit::size(signers.len(), destinations.len()),
+                    SpendingLimit::new_size(signers.len(), destinations.len(), *period, &options),
                     vec![
                         SEED_PREFIX.to_vec(),
                         self_key.as_ref().to_vec(),
@@ -380,6 +459,9 @@ impl Settings {
                     bump: spending_limit_bump,
                     destinations: destinations.to_vec(),
                     expiration: *expiration,
+                    extension: AccountExtension::new(SpendingLimitExtension::new(
+                        *period, &options,
+                    )),
                 };
 
                 spending_limit.invariant()?;
@@ -410,6 +492,40 @@ impl Settings {
                 spending_limit.close(rent_payer.to_account_info())?;
             }
 
+            SettingsAction::UpdateSpendingLimit {
+                spending_limit: spending_limit_key,
+                update,
+            } => {
+                let spending_limit_info = remaining_accounts
+                    .iter()
+                    .find(|acc| acc.key == spending_limit_key)
+                    .ok_or(SmartAccountError::MissingAccount)?;
+
+                let mut spending_limit = Account::<SpendingLimit>::try_from(spending_limit_info)?;
+
+                require_keys_eq!(
+                    spending_limit.settings,
+                    *self_key,
+                    SmartAccountError::InvalidAccount
+                );
+
+                spending_limit.update(update, Clock::get()?.unix_timestamp)?;
+
+                // Make sure the spending limit account can fit the updated state.
+                let new_size = spending_limit.current_size();
+                if spending_limit_info.data_len() < new_size {
+                    realloc(
+                        spending_limit_info,
+                        new_size,
+                        rent_payer.as_ref().map(ToAccountInfo::to_account_info),
+                        system_program.as_ref().map(ToAccountInfo::to_account_info),
+                    )?;
+                }
+
+                spending_limit
+                    .try_serialize(&mut &mut spending_limit_info.data.borrow_mut()[..])?;
+            }
+
             SettingsAction::SetArchivalAuthority {
                 new_archival_authority,
             } => {
@@ -421,12 +537,76 @@ impl Settings {
         Ok(())
     }
 
+    /// Modify the consensus parameters (signers, threshold, time lock and its bypass) with a given action.
+    /// Unlike `modify_with_action`, this doesn't touch any accounts other than the settings state itself.
+    ///
+    /// # Errors
+    /// - `SmartAccountError::UnsupportedSettingsAction` if `action` requires accounts other than the settings.
+    pub fn modify_consensus_with_action(&mut self, action: &SettingsAction) -> Result<()> {
+        match action {
+            SettingsAction::AddSigner { new_signer } => {
+                self.add_signer(new_signer.to_owned());
+            }
+
+            SettingsAction::RemoveSigner { old_signer } => {
+                self.remove_signer(old_signer.to_owned())?;
+            }
+
+            SettingsAction::ChangeThreshold { new_threshold } => {
+                self.threshold = *new_threshold;
+            }
+
+            SettingsAction::SetTimeLock { new_time_lock } => {
+                self.time_lock = *new_time_lock;
+            }
+
+            SettingsAction::SetTimeLockBypass {
+                new_time_lock_bypass,
+            } => {
+                self.set_time_lock_bypass(*new_time_lock_bypass);
+            }
+
+            _ => return err!(SmartAccountError::UnsupportedSettingsAction),
+        }
+
+        self.invalidate_prior_transactions();
+
+        Ok(())
+    }
+
     pub fn increment_account_utilization(&mut self) {
         self.account_utilization = self.account_utilization.checked_add(1).unwrap();
     }
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone)]
+/// Settings state that didn't fit the original account layout.
+/// New fields go into a new variant, so settings of any version keep deserializing.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Default, Debug)]
+pub enum SettingsExtension {
+    /// Settings created before the extension was introduced.
+    #[default]
+    None,
+    V1 {
+        /// Consensus under which synchronous transactions may be executed despite the `time_lock`.
+        time_lock_bypass: TimeLockBypass,
+    },
+}
+
+/// Consensus under which synchronous transactions may be executed despite the `time_lock`.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Default, Debug,
+)]
+pub enum TimeLockBypass {
+    /// Synchronous transactions can't be executed while the `time_lock` is set.
+    #[default]
+    Disabled,
+    /// Synchronous transactions bypass the `time_lock` if signed by at least this many voters.
+    Threshold(u16),
+    /// Synchronous transactions bypass the `time_lock` if signed by all voters.
+    Unanimous,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Debug)]
 pub struct SmartAccountSigner {
     pub key: Pubkey,
     pub permissions: Permissions,
@@ -461,3 +641,446 @@ impl Permissions {
         self.mask & (permission as u8) != 0
     }
 }
+
+/// Builds `Settings` for tests. Starts from an autonomous smart account without signers,
+/// with a threshold of 1 and no time lock.
+#[cfg(any(test, feature = "test-utils"))]
+pub struct SettingsBuilder {
+    settings: Settings,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl SettingsBuilder {
+    pub fn new() -> Self {
+        Self {
+            settings: Settings {
+                seed: 1,
+                settings_authority: Pubkey::default(),
+                threshold: 1,
+                time_lock: 0,
+                transaction_index: 0,
+                stale_transaction_index: 0,
+                archival_authority: Some(Pubkey::default()),
+                archivable_after: 0,
+                bump: 255,
+                signers: vec![],
+                account_utilization: 0,
+                _reserved1: 0,
+                extension: SettingsExtension::None,
+            },
+        }
+    }
+
+    pub fn seed(mut self, seed: u128) -> Self {
+        self.settings.seed = seed;
+        self
+    }
+
+    pub fn bump(mut self, bump: u8) -> Self {
+        self.settings.bump = bump;
+        self
+    }
+
+    pub fn threshold(mut self, threshold: u16) -> Self {
+        self.settings.threshold = threshold;
+        self
+    }
+
+    pub fn time_lock(mut self, time_lock: u32) -> Self {
+        self.settings.time_lock = time_lock;
+        self
+    }
+
+    pub fn transaction_index(mut self, transaction_index: u64) -> Self {
+        self.settings.transaction_index = transaction_index;
+        self
+    }
+
+    /// Adds a signer with the given `permissions`.
+    pub fn signer(mut self, key: Pubkey, permissions: Permissions) -> Self {
+        self.settings
+            .signers
+            .push(SmartAccountSigner { key, permissions });
+        self
+    }
+
+    pub fn signers(mut self, signers: Vec<SmartAccountSigner>) -> Self {
+        self.settings.signers = signers;
+        self
+    }
+
+    pub fn time_lock_bypass(mut self, time_lock_bypass: TimeLockBypass) -> Self {
+        self.settings.extension = SettingsExtension::V1 { time_lock_bypass };
+        self
+    }
+
+    pub fn build(self) -> Settings {
+        self.settings
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl Default for SettingsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anchor_lang::Discriminator;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Small pool of keys, so that generated actions often hit existing signers.
+    const NUM_KEYS: u8 = 6;
+
+    fn key(index: u8) -> Pubkey {
+        Pubkey::new_from_array([index + 1; 32])
+    }
+
+    fn signer_strategy() -> impl Strategy<Value = SmartAccountSigner> {
+        (
+            0..NUM_KEYS,
+            // Mostly known permissions, sometimes garbage.
+            prop_oneof![7 => 0u8..8, 1 => 8u8..=u8::MAX],
+        )
+            .prop_map(|(index, mask)| SmartAccountSigner {
+                key: key(index),
+                permissions: Permissions { mask },
+            })
+    }
+
+    /// Valid settings: the first signer has all permissions, the threshold is reachable.
+    fn settings_strategy() -> impl Strategy<Value = Settings> {
+        (
+            prop::sample::subsequence((0..NUM_KEYS).collect::<Vec<_>>(), 1..=NUM_KEYS as usize),
+            prop::collection::vec(0u8..8, NUM_KEYS as usize),
+            any::<u16>(),
+            0..=MAX_TIME_LOCK,
+            0u64..100,
+        )
+            .prop_map(
+                |(indexes, masks, threshold_seed, time_lock, transaction_index)| {
+                    let mut signers: Vec<SmartAccountSigner> = indexes
+                        .iter()
+                        .zip(masks)
+                        .map(|(index, mask)| SmartAccountSigner {
+                            key: key(*index),
+                            permissions: Permissions { mask },
+                        })
+                        .collect();
+                    signers[0].permissions = Permissions::from_vec(&[
+                        Permission::Initiate,
+                        Permission::Vote,
+                        Permission::Execute,
+                    ]);
+                    signers.sort_by_key(|s| s.key);
+
+                    let num_voters = Settings::num_voters(&signers) as u16;
+
+                    SettingsBuilder::new()
+                        .threshold(1 + threshold_seed % num_voters)
+                        .time_lock(time_lock)
+                        .transaction_index(transaction_index)
+                        .signers(signers)
+                        .build()
+                },
+            )
+    }
+
+    fn action_strategy() -> impl Strategy<Value = SettingsAction> {
+        prop_oneof![
+            signer_strategy().prop_map(|new_signer| SettingsAction::AddSigner { new_signer }),
+            (0..NUM_KEYS).prop_map(|index| SettingsAction::RemoveSigner {
+                old_signer: key(index)
+            }),
+            (0u16..=NUM_KEYS as u16 + 1)
+                .prop_map(|new_threshold| SettingsAction::ChangeThreshold { new_threshold }),
+            prop_oneof![0..=MAX_TIME_LOCK, MAX_TIME_LOCK + 1..=u32::MAX]
+                .prop_map(|new_time_lock| SettingsAction::SetTimeLock { new_time_lock }),
+            prop_oneof![
+                Just(TimeLockBypass::Disabled),
+                Just(TimeLockBypass::Unanimous),
+                (0u16..=NUM_KEYS as u16 + 1).prop_map(TimeLockBypass::Threshold),
+            ]
+            .prop_map(|new_time_lock_bypass| SettingsAction::SetTimeLockBypass {
+                new_time_lock_bypass
+            }),
+        ]
+    }
+
+    /// Number of signers with the `Vote` permission, counted from the raw masks.
+    fn count_voters(settings: &Settings) -> usize {
+        settings
+            .signers
+            .iter()
+            .filter(|signer| signer.permissions.mask & 0b010 != 0)
+            .count()
+    }
+
+    /// Ways to break valid settings.
+    #[derive(Clone, Debug)]
+    enum Corruption {
+        DuplicateSigner(prop::sample::Index),
+        UnknownPermission(prop::sample::Index, u8),
+        /// Takes the permission with this mask away from every signer.
+        RevokePermission(u8),
+        ZeroThreshold,
+        ThresholdAboveVoters(u16),
+        StaleTransactionIndexAhead(u64),
+        TimeLockAboveMax(u32),
+        BypassThresholdNotStricter(u16),
+        BypassThresholdAboveVoters(u16),
+    }
+
+    impl Corruption {
+        /// Breaks `settings` and returns the error the invariant must report for them.
+        fn apply(self, settings: &mut Settings) -> SmartAccountError {
+            let voters = count_voters(settings) as u16;
+            match self {
+                Corruption::DuplicateSigner(index) => {
+                    let index = index.index(settings.signers.len());
+                    let duplicate = settings.signers[index].clone();
+                    settings.signers.insert(index, duplicate);
+                    SmartAccountError::DuplicateSigner
+                }
+                Corruption::UnknownPermission(index, bit) => {
+                    let index = index.index(settings.signers.len());
+                    settings.signers[index].permissions.mask |= 8 << (bit % 5);
+                    SmartAccountError::UnknownPermission
+                }
+                Corruption::RevokePermission(mask) => {
+                    for signer in &mut settings.signers {
+                        signer.permissions.mask &= !mask;
+                    }
+                    match mask {
+                        0b001 => SmartAccountError::NoProposers,
+                        0b010 => SmartAccountError::NoVoters,
+                        _ => SmartAccountError::NoExecutors,
+                    }
+                }
+                Corruption::ZeroThreshold => {
+                    settings.threshold = 0;
+                    SmartAccountError::InvalidThreshold
+                }
+                Corruption::ThresholdAboveVoters(excess) => {
+                    settings.threshold = voters + 1 + excess;
+                    SmartAccountError::InvalidThreshold
+                }
+                Corruption::StaleTransactionIndexAhead(excess) => {
+                    settings.stale_transaction_index = settings.transaction_index + 1 + excess;
+                    SmartAccountError::InvalidStaleTransactionIndex
+                }
+                Corruption::TimeLockAboveMax(time_lock) => {
+                    settings.time_lock = time_lock;
+                    SmartAccountError::TimeLockExceedsMaxAllowed
+                }
+                Corruption::BypassThresholdNotStricter(shortfall) => {
+                    let bypass_threshold =
+                        settings.threshold - shortfall % (settings.threshold + 1);
+                    settings.set_time_lock_bypass(TimeLockBypass::Threshold(bypass_threshold));
+                    SmartAccountError::InvalidTimeLockBypassThreshold
+                }
+                Corruption::BypassThresholdAboveVoters(excess) => {
+                    settings.set_time_lock_bypass(TimeLockBypass::Threshold(voters + 1 + excess));
+                    SmartAccountError::InvalidTimeLockBypassThreshold
+                }
+            }
+        }
+    }
+
+    fn corruption_strategy() -> impl Strategy<Value = Corruption> {
+        prop_oneof![
+            any::<prop::sample::Index>().prop_map(Corruption::DuplicateSigner),
+            (any::<prop::sample::Index>(), any::<u8>())
+                .prop_map(|(index, bit)| Corruption::UnknownPermission(index, bit)),
+            prop_oneof![Just(0b001), Just(0b010), Just(0b100)]
+                .prop_map(Corruption::RevokePermission),
+            Just(Corruption::ZeroThreshold),
+            (0u16..100).prop_map(Corruption::ThresholdAboveVoters),
+            (0u64..100).prop_map(Corruption::StaleTransactionIndexAhead),
+            (MAX_TIME_LOCK + 1..=u32::MAX).prop_map(Corruption::TimeLockAboveMax),
+            any::<u16>().prop_map(Corruption::BypassThresholdNotStricter),
+            (0u16..100).prop_map(Corruption::BypassThresholdAboveVoters),
+        ]
+    }
+
+    /// Applies `actions` the way a settings transaction does: one by one, then checks the invariant.
+    fn apply(settings: &mut Settings, actions: &[SettingsAction]) -> Result<()> {
+        for action in actions {
+            settings.modify_consensus_with_action(action)?;
+        }
+        settings.invariant()
+    }
+
+    proptest! {
+        #[test]
+        fn test_invariant_reports_corruption(
+            settings in settings_strategy(),
+            corruption in corruption_strategy(),
+        ) {
+            prop_assert!(settings.invariant().is_ok());
+
+            let mut corrupted = settings;
+            let expected = corruption.apply(&mut corrupted);
+
+            prop_assert_eq!(corrupted.invariant(), Err(expected.into()));
+        }
+
+        #[test]
+        fn test_threshold_and_time_lock_changes(
+            settings in settings_strategy(),
+            new_threshold in 0u16..=NUM_KEYS as u16 + 1,
+            new_time_lock in prop_oneof![0..=MAX_TIME_LOCK, MAX_TIME_LOCK + 1..=u32::MAX],
+        ) {
+            let voters = count_voters(&settings);
+            let expected = if new_threshold == 0 || usize::from(new_threshold) > voters {
+                Err(SmartAccountError::InvalidThreshold.into())
+            } else if new_time_lock > MAX_TIME_LOCK {
+                Err(SmartAccountError::TimeLockExceedsMaxAllowed.into())
+            } else {
+                Ok(())
+            };
+
+            let mut modified = settings;
+            let result = apply(
+                &mut modified,
+                &[
+                    SettingsAction::ChangeThreshold { new_threshold },
+                    SettingsAction::SetTimeLock { new_time_lock },
+                ],
+            );
+
+            prop_assert_eq!(result, expected);
+            prop_assert_eq!(modified.threshold, new_threshold);
+            prop_assert_eq!(modified.time_lock, new_time_lock);
+            // Every consensus change makes the prior transactions stale.
+            prop_assert_eq!(modified.stale_transaction_index, modified.transaction_index);
+        }
+
+        #[test]
+        fn test_cutoff_and_threshold_are_exclusive(
+            settings in settings_strategy(),
+            actions in prop::collection::vec(action_strategy(), 0..8),
+        ) {
+            let mut modified = Settings::try_from_slice(&settings.try_to_vec().unwrap()).unwrap();
+            prop_assume!(apply(&mut modified, &actions).is_ok());
+
+            let num_voters = Settings::num_voters(&modified.signers);
+            let threshold = usize::from(modified.threshold);
+            let cutoff = modified.cutoff();
+
+            // Both outcomes must be reachable...
+            prop_assert!(threshold <= num_voters);
+            prop_assert!(cutoff <= num_voters);
+            // ...but never at the same time: approvals and rejections come from disjoint sets of voters.
+            for approvals in 0..=num_voters {
+                let rejections = num_voters - approvals;
+                prop_assert!(!(approvals >= threshold && rejections >= cutoff));
+            }
+        }
+    }
+
+    #[test]
+    fn test_removing_voter_below_threshold_is_rejected() {
+        let all =
+            Permissions::from_vec(&[Permission::Initiate, Permission::Vote, Permission::Execute]);
+        let mut settings = SettingsBuilder::new()
+            .threshold(2)
+            .signer(key(0), all)
+            .signer(key(1), all)
+            .build();
+
+        let result = apply(
+            &mut settings,
+            &[SettingsAction::RemoveSigner { old_signer: key(1) }],
+        );
+
+        assert_eq!(result, Err(SmartAccountError::InvalidThreshold.into()));
+    }
+
+    #[test]
+    fn test_consensus_actions_reject_actions_requiring_accounts() {
+        let mut settings = SettingsBuilder::new().build();
+
+        let result = settings.modify_consensus_with_action(&SettingsAction::RemoveSpendingLimit {
+            spending_limit: Pubkey::default(),
+        });
+
+        assert_eq!(
+            result,
+            Err(SmartAccountError::UnsupportedSettingsAction.into())
+        );
+    }
+
+    /// Layout of `Settings` before `SettingsExtension` was introduced.
+    #[derive(AnchorSerialize)]
+    struct LegacySettings {
+        seed: u128,
+        settings_authority: Pubkey,
+        threshold: u16,
+        time_lock: u32,
+        transaction_index: u64,
+        stale_transaction_index: u64,
+        archival_authority: Option<Pubkey>,
+        archivable_after: u64,
+        bump: u8,
+        signers: Vec<SmartAccountSigner>,
+        account_utilization: u8,
+        _reserved1: u8,
+        _reserved2: u8,
+    }
+
+    #[test]
+    fn test_deserialize_settings_created_before_extension() {
+        // Settings accounts created before `SettingsExtension` have the exact size of their layout.
+        let legacy_settings = LegacySettings {
+            seed: 1,
+            settings_authority: Pubkey::default(),
+            threshold: 1,
+            time_lock: 60,
+            transaction_index: 3,
+            stale_transaction_index: 2,
+            archival_authority: Some(Pubkey::default()),
+            archivable_after: 0,
+            bump: 255,
+            signers: vec![SmartAccountSigner {
+                key: Pubkey::new_unique(),
+                permissions: Permissions { mask: 7 },
+            }],
+            account_utilization: 1,
+            _reserved1: 0,
+            _reserved2: 0,
+        };
+        let mut data = Settings::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&legacy_settings.try_to_vec().unwrap());
+        assert_eq!(data.len(), Settings::size_without_extension(1));
+
+        let mut settings = Settings::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(settings.transaction_index, 3);
+        assert_eq!(settings.signers, legacy_settings.signers);
+        assert_eq!(settings.extension, SettingsExtension::None);
+        assert_eq!(settings.time_lock_bypass(), TimeLockBypass::Disabled);
+        assert_eq!(settings.time_lock_bypass_threshold(), None);
+
+        // Unchanged settings still fit the account.
+        settings.set_time_lock_bypass(TimeLockBypass::Disabled);
+        settings.try_serialize(&mut data.as_mut_slice()).unwrap();
+        assert_eq!(
+            Settings::try_deserialize(&mut data.as_slice())
+                .unwrap()
+                .extension,
+            SettingsExtension::None
+        );
+
+        // Setting a bypass switches to the extension, which needs a reallocation.
+        settings.set_time_lock_bypass(TimeLockBypass::Unanimous);
+        assert_eq!(settings.time_lock_bypass(), TimeLockBypass::Unanimous);
+        let serialized_size = settings.try_to_vec().unwrap().len() + 8;
+        assert!(serialized_size > data.len());
+        assert!(serialized_size <= Settings::size(1));
+    }
+}
@@ -40,7 +40,7 @@ impl SettingsTransaction {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 #[non_exhaustive]
 pub enum SettingsAction {
     /// Add a new member to the settings.
@@ -81,4 +81,36 @@ pub enum SettingsAction {
     RemoveSpendingLimit { spending_limit: Pubkey },
     /// Set the `archival_authority` config parameter of the settings.
     SetArchivalAuthority { new_archival_authority: Option<Pubkey> },
+    /// Change the `time_lock_bypass` of the settings.
+    SetTimeLockBypass {
+        new_time_lock_bypass: TimeLockBypass,
+    },
+    /// Update a spending limit of the settings in place, keeping track of what has been spent.
+    UpdateSpendingLimit {
+        spending_limit: Pubkey,
+        update: SpendingLimitUpdate,
+    },
+    /// Add a spending limit to the settings, configured beyond what `AddSpendingLimit` supports.
+    AddSpendingLimitWithOptions {
+        /// Key that is used to seed the SpendingLimit PDA.
+        seed: Pubkey,
+        /// The index of the account that the spending limit is for.
+        account_index: u8,
+        /// The token mint the spending limit is for.
+        mint: Pubkey,
+        /// The amount of tokens that can be spent in a period, in decimals of the mint.
+        amount: u64,
+        /// The reset period of the spending limit.
+        period: Period,
+        /// Members of the settings that can use the spending limit.
+        signers: Vec<Pubkey>,
+        /// The destination addresses the spending limit is allowed to sent funds to.
+        /// If empty, funds can be sent to any address.
+        destinations: Vec<Pubkey>,
+        /// The expiration timestamp of the spending limit.
+        /// Non expiring spending limits are set to `i64::MAX`.
+        expiration: i64,
+        /// Configuration of the spending limit beyond `AddSpendingLimit`.
+        options: SpendingLimitOptions,
+    },
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::*;
+use crate::utils::{AccountExtension, PriceFeed};
 
 #[account]
 pub struct SpendingLimit {
@@ -46,17 +48,215 @@ pub struct SpendingLimit {
 
     /// The expiration timestamp of the spending limit.
     pub expiration: i64,
+
+    /// Fields added after the original layout, absent from spending limits created before.
+    pub extension: AccountExtension<SpendingLimitExtension>,
+}
+
+/// State of a `SpendingLimit` added after its original layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SpendingLimitExtension {
+    /// Mints the spending limit covers in addition to `mint`,
+    /// each with its own amount per `period`.
+    pub additional_mints: Vec<MintAllowance>,
+
+    /// Spends within the current window of a `Period::SlidingWindow` spending limit,
+    /// `SLIDING_WINDOW_BUCKETS` buckets in that case, empty otherwise.
+    pub sliding_window: Vec<SlidingWindowBucket>,
+
+    /// Optional caps on what individual `signers` can spend per `period`,
+    /// checked in addition to the amounts shared by all signers.
+    pub signer_allowances: Vec<SignerAllowance>,
+
+    /// Whether transfers of mints with a Token-2022 transfer fee are charged
+    /// on the amount sent or on the amount received after the fee.
+    pub transfer_fee_charging: TransferFeeCharging,
+
+    /// If set, the amounts of the spending limit are denominated in the quote currency
+    /// of the oracle, and spends of `mint` are converted using its price.
+    pub price_oracle: Option<PriceOracle>,
+
+    /// Signers of the settings that can pause the spending limit without reaching threshold,
+    /// in addition to the `settings_authority` of controlled smart accounts.
+    pub pausers: Vec<Pubkey>,
+
+    /// Whether the spending limit is paused and can't be used.
+    /// Resuming it takes a spending limit update.
+    pub paused: bool,
+
+    /// Numbers of NFTs of a collection or creator that can be transferred per `period`.
+    pub nft_allowances: Vec<NftAllowance>,
+
+    /// Maximum number of uses kept in `usage_history`, set at creation.
+    pub usage_history_capacity: u16,
+
+    /// Total number of uses recorded in `usage_history`.
+    pub usage_count: u64,
+
+    /// Ring buffer of the most recent uses of the spending limit.
+    /// Once it holds `usage_history_capacity` uses, the oldest one,
+    /// at `usage_count % usage_history_capacity`, is overwritten first.
+    pub usage_history: Vec<SpendingLimitUse>,
+
+    /// Number of `signers` that must sign a use of the spending limit:
+    /// the signer using it and `threshold - 1` co-signers.
+    pub threshold: u8,
+
+    /// Programs the instructions executed through `use_spending_limit_sync`
+    /// and `use_compressed_nft_spending_limit` can invoke, none if empty.
+    pub allowed_programs: Vec<Pubkey>,
+}
+
+impl Default for SpendingLimitExtension {
+    /// State of spending limits created before the extension,
+    /// which only cover `mint` and can be used by any of their `signers` alone.
+    fn default() -> Self {
+        Self {
+            additional_mints: vec![],
+            sliding_window: vec![],
+            signer_allowances: vec![],
+            transfer_fee_charging: TransferFeeCharging::Gross,
+            price_oracle: None,
+            pausers: vec![],
+            paused: false,
+            nft_allowances: vec![],
+            usage_history_capacity: 0,
+            usage_count: 0,
+            usage_history: vec![],
+            threshold: 1,
+            allowed_programs: vec![],
+        }
+    }
+}
+
+impl SpendingLimitExtension {
+    /// Extension of a new spending limit with `period`, configured with `options`.
+    pub fn new(period: Period, options: &SpendingLimitOptions) -> Self {
+        let num_allowances = 1
+            + options.additional_mints.len()
+            + options.signer_allowances.len()
+            + options.nft_allowances.len();
+        Self {
+            additional_mints: options
+                .additional_mints
+                .iter()
+                .copied()
+                .map(MintAllowance::from)
+                .collect(),
+            sliding_window: vec![
+                SlidingWindowBucket {
+                    index: 0,
+                    spent: vec![0; num_allowances],
+                };
+                period.sliding_window_buckets()
+            ],
+            signer_allowances: options
+                .signer_allowances
+                .iter()
+                .copied()
+                .map(SignerAllowance::from)
+                .collect(),
+            transfer_fee_charging: options.transfer_fee_charging,
+            price_oracle: options.price_oracle,
+            pausers: options.pausers.clone(),
+            paused: false,
+            nft_allowances: options
+                .nft_allowances
+                .iter()
+                .copied()
+                .map(NftAllowance::from)
+                .collect(),
+            usage_history_capacity: options.usage_history_capacity,
+            usage_count: 0,
+            usage_history: vec![],
+            threshold: options.threshold,
+            allowed_programs: options.allowed_programs.clone(),
+        }
+    }
+
+    /// Size of the extension with `usage_history` filled up to its capacity.
+    pub fn size(&self) -> usize {
+        4  + // additional_mints vector length
+        self.additional_mints.len() * MintAllowance::INIT_SPACE + // additional_mints
+        4  + // sliding_window vector length
+        self.sliding_window // sliding_window
+            .iter()
+            .map(|bucket| SlidingWindowBucket::size(bucket.spent.len()))
+            .sum::<usize>() +
+        4  + // signer_allowances vector length
+        self.signer_allowances.len() * SignerAllowance::INIT_SPACE + // signer_allowances
+        TransferFeeCharging::INIT_SPACE + // transfer_fee_charging
+        1  + PriceOracle::INIT_SPACE + // price_oracle
+        4  + // pausers vector length
+        self.pausers.len() * 32 + // pausers
+        1  + // paused
+        4  + // nft_allowances vector length
+        self.nft_allowances.len() * NftAllowance::INIT_SPACE + // nft_allowances
+        2  + // usage_history_capacity
+        8  + // usage_count
+        4  + // usage_history vector length
+        self.usage_history_capacity as usize * SpendingLimitUse::INIT_SPACE + // usage_history
+        1  + // threshold
+        4  + // allowed_programs vector length
+        self.allowed_programs.len() * 32 // allowed_programs
+    }
+}
+
+/// Configuration of a new spending limit beyond what its original layout supported.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SpendingLimitOptions {
+    /// Mints the spending limit covers in addition to `mint`, with their amounts per period.
+    pub additional_mints: Vec<MintAmount>,
+    /// Optional caps on what individual signers can spend per period,
+    /// in addition to the amounts shared by all signers.
+    pub signer_allowances: Vec<SignerAmount>,
+    /// Whether transfers of mints with a transfer fee are charged gross or net of the fee.
+    pub transfer_fee_charging: TransferFeeCharging,
+    /// Oracle to denominate the spending limit in a quote currency with, if any.
+    /// The amounts of the spending limit are then expressed in the quote currency.
+    pub price_oracle: Option<PriceOracle>,
+    /// Signers of the settings that can pause the spending limit without reaching threshold.
+    pub pausers: Vec<Pubkey>,
+    /// Numbers of NFTs of a collection or creator that can be transferred per period.
+    pub nft_allowances: Vec<NftCount>,
+    /// Number of recent uses the spending limit keeps a record of,
+    /// at most `MAX_USAGE_HISTORY_CAPACITY`.
+    pub usage_history_capacity: u16,
+    /// Number of signers that must sign a use of the spending limit,
+    /// 1 for any signer to be able to use it alone.
+    pub threshold: u8,
+    /// Programs the instructions executed through `use_spending_limit_sync`
+    /// and `use_compressed_nft_spending_limit` can invoke.
+    pub allowed_programs: Vec<Pubkey>,
+}
+
+impl Default for SpendingLimitOptions {
+    fn default() -> Self {
+        Self {
+            additional_mints: vec![],
+            signer_allowances: vec![],
+            transfer_fee_charging: TransferFeeCharging::Gross,
+            price_oracle: None,
+            pausers: vec![],
+            nft_allowances: vec![],
+            usage_history_capacity: 0,
+            threshold: 1,
+            allowed_programs: vec![],
+        }
+    }
 }
 
 impl SpendingLimit {
-    pub fn size(signers_length: usize, destinations_length: usize) -> usize {
+    /// Size of the account as laid out before `SpendingLimitExtension`.
+    /// Spending limits created before it only have the 1-byte periods of the original layout.
+    pub fn size(signers_length: usize, destinations_length: usize, period: &Period) -> usize {
         8  + // anchor discriminator
         32 + // settings
         32 + // seed
         1  + // account_index
         32 + // mint
         8  + // amount
-        1  + // period
+        period.size() + // period
         8  + // remaining_amount
         8  + // last_reset
         1  + // bump
@@ -67,11 +267,23 @@ impl SpendingLimit {
         8    // expiration
     }
 
+    /// Size of the account of a new spending limit with `period`, configured with `options`.
+    pub fn new_size(
+        signers_length: usize,
+        destinations_length: usize,
+        period: Period,
+        options: &SpendingLimitOptions,
+    ) -> usize {
+        Self::size(signers_length, destinations_length, &period)
+            + AccountExtension::<SpendingLimitExtension>::size(
+                SpendingLimitExtension::new(period, options).size(),
+            )
+    }
+
     pub fn invariant(&self) -> Result<()> {
-        // Amount must be a non-zero value.
-        require_neq!(
-            self.amount,
-            0,
+        // Amount must be a non-zero value, unless the spending limit is only meant for NFTs.
+        require!(
+            self.amount != 0 || !self.extension.nft_allowances.is_empty(),
             SmartAccountError::SpendingLimitInvalidAmount
         );
 
@@ -81,12 +293,773 @@ impl SpendingLimit {
         let has_duplicates = self.signers.windows(2).any(|win| win[0] == win[1]);
         require!(!has_duplicates, SmartAccountError::DuplicateSigner);
 
+        // Threshold must be between 1 and the number of signers.
+        require!(
+            self.extension.threshold > 0 && self.extension.threshold as usize <= self.signers.len(),
+            SmartAccountError::InvalidThreshold
+        );
+
+        for (i, allowance) in self.extension.additional_mints.iter().enumerate() {
+            require_neq!(
+                allowance.amount,
+                0,
+                SmartAccountError::SpendingLimitInvalidAmount
+            );
+            // Every mint can only be covered once.
+            require!(
+                allowance.mint != self.mint
+                    && self.extension.additional_mints[..i]
+                        .iter()
+                        .all(|other| other.mint != allowance.mint),
+                SmartAccountError::InvalidMint
+            );
+        }
+
+        for (i, allowance) in self.extension.signer_allowances.iter().enumerate() {
+            require_neq!(
+                allowance.amount,
+                0,
+                SmartAccountError::SpendingLimitInvalidAmount
+            );
+            require!(
+                self.signers.binary_search(&allowance.signer).is_ok(),
+                SmartAccountError::NotASigner
+            );
+            require!(
+                self.covers_mint(&allowance.mint),
+                SmartAccountError::InvalidMint
+            );
+            // Every signer can only have one allowance per mint.
+            require!(
+                self.extension.signer_allowances[..i]
+                    .iter()
+                    .all(|other| other.signer != allowance.signer || other.mint != allowance.mint),
+                SmartAccountError::DuplicateSigner
+            );
+        }
+
+        for (i, allowance) in self.extension.nft_allowances.iter().enumerate() {
+            require_neq!(
+                allowance.count,
+                0,
+                SmartAccountError::SpendingLimitInvalidAmount
+            );
+            // Every group can only be covered once.
+            require!(
+                self.extension.nft_allowances[..i]
+                    .iter()
+                    .all(|other| other.group != allowance.group),
+                SmartAccountError::InvalidNft
+            );
+        }
+
+        // There must be no duplicate pausers.
+        require!(
+            self.extension
+                .pausers
+                .iter()
+                .enumerate()
+                .all(|(i, pauser)| !self.extension.pausers[..i].contains(pauser)),
+            SmartAccountError::DuplicateSigner
+        );
+
+        if let Some(price_oracle) = &self.extension.price_oracle {
+            // Only `mint` has a price to convert spends with.
+            require!(
+                self.extension.additional_mints.is_empty(),
+                SmartAccountError::InvalidMint
+            );
+            price_oracle.invariant()?;
+        }
+
+        self.period.invariant()?;
+
+        require!(
+            self.extension.usage_history_capacity <= MAX_USAGE_HISTORY_CAPACITY,
+            SmartAccountError::InvalidUsageHistoryCapacity
+        );
+
+        // Sliding window spending limits must have a bucket for every slot,
+        // tracking the spends of every allowance.
+        require_eq!(
+            self.extension.sliding_window.len(),
+            self.period.sliding_window_buckets(),
+            SmartAccountError::SpendingLimitInvalidPeriod
+        );
+        require!(
+            self.extension
+                .sliding_window
+                .iter()
+                .all(|bucket| bucket.spent.len() == self.allowance_keys().len()),
+            SmartAccountError::SpendingLimitInvalidPeriod
+        );
+
         Ok(())
     }
+
+    /// Returns what a spend of `amount` of `mint` with `decimals` is charged to the spending
+    /// limit: its value according to `price_account` if the spending limit has a `price_oracle`,
+    /// `amount` itself otherwise.
+    pub fn value_of(
+        &self,
+        amount: u64,
+        decimals: u8,
+        price_account: Option<&AccountInfo>,
+        now: i64,
+    ) -> Result<u64> {
+        let Some(price_oracle) = &self.extension.price_oracle else {
+            return Ok(amount);
+        };
+
+        let price_account = price_account.ok_or(SmartAccountError::MissingAccount)?;
+        require_keys_eq!(
+            *price_account.key,
+            price_oracle.price_account,
+            SmartAccountError::InvalidPriceOracle
+        );
+        let price_feed = PriceFeed::read(price_account, &price_oracle.oracle_program)?;
+        price_feed.check_bounds(
+            now,
+            price_oracle.max_staleness,
+            price_oracle.max_confidence_bps,
+        )?;
+        price_feed.quote_amount(amount, decimals, price_oracle.quote_decimals)
+    }
+
+    /// Returns the empty sliding window for the current `period` and allowances.
+    pub fn empty_sliding_window(&self) -> Vec<SlidingWindowBucket> {
+        vec![
+            SlidingWindowBucket {
+                index: 0,
+                spent: vec![0; self.allowance_keys().len()],
+            };
+            self.period.sliding_window_buckets()
+        ]
+    }
+
+    /// Identifies every amount tracked by the spending limit, in the order of their positions:
+    /// the shared amount of `mint`, followed by `additional_mints`,
+    /// followed by `signer_allowances`, followed by `nft_allowances`.
+    fn allowance_keys(&self) -> Vec<AllowanceKey> {
+        std::iter::once(AllowanceKey::Mint(self.mint))
+            .chain(
+                self.extension
+                    .additional_mints
+                    .iter()
+                    .map(|allowance| AllowanceKey::Mint(allowance.mint)),
+            )
+            .chain(
+                self.extension
+                    .signer_allowances
+                    .iter()
+                    .map(|allowance| AllowanceKey::Signer(allowance.signer, allowance.mint)),
+            )
+            .chain(
+                self.extension
+                    .nft_allowances
+                    .iter()
+                    .map(|allowance| AllowanceKey::Nft(allowance.group)),
+            )
+            .collect()
+    }
+
+    /// Position of `mint` among the mints covered by the spending limit:
+    /// `0` for `mint`, followed by `additional_mints`.
+    fn mint_position(&self, mint: &Pubkey) -> Option<usize> {
+        if self.mint == *mint {
+            return Some(0);
+        }
+        self.extension
+            .additional_mints
+            .iter()
+            .position(|allowance| allowance.mint == *mint)
+            .map(|position| position + 1)
+    }
+
+    /// Position of the allowance of `signer` for `mint`, if there is one.
+    fn signer_allowance_position(&self, signer: &Pubkey, mint: &Pubkey) -> Option<usize> {
+        self.extension
+            .signer_allowances
+            .iter()
+            .position(|allowance| allowance.signer == *signer && allowance.mint == *mint)
+            .map(|position| position + 1 + self.extension.additional_mints.len())
+    }
+
+    /// Position of the allowance of the NFT `group`, if there is one.
+    fn nft_allowance_position(&self, group: &NftGroup) -> Option<usize> {
+        let extension = &self.extension;
+        extension
+            .nft_allowances
+            .iter()
+            .position(|allowance| allowance.group == *group)
+            .map(|position| {
+                position + 1 + extension.additional_mints.len() + extension.signer_allowances.len()
+            })
+    }
+
+    /// Returns the amount per period and the remaining amount of the allowance at `position`.
+    fn allowance_at_mut(&mut self, position: usize) -> (u64, &mut u64) {
+        let num_mints = 1 + self.extension.additional_mints.len();
+        let num_fungible = num_mints + self.extension.signer_allowances.len();
+        if position == 0 {
+            (self.amount, &mut self.remaining_amount)
+        } else if position < num_mints {
+            let allowance = &mut self.extension.additional_mints[position - 1];
+            (allowance.amount, &mut allowance.remaining_amount)
+        } else if position < num_fungible {
+            let allowance = &mut self.extension.signer_allowances[position - num_mints];
+            (allowance.amount, &mut allowance.remaining_amount)
+        } else {
+            let allowance = &mut self.extension.nft_allowances[position - num_fungible];
+            (allowance.count, &mut allowance.remaining_count)
+        }
+    }
+
+    /// Whether the spending limit covers `mint`.
+    pub fn covers_mint(&self, mint: &Pubkey) -> bool {
+        self.mint_position(mint).is_some()
+    }
+
+    /// Returns the amount of `mint` that can still be spent in the current period,
+    /// or `None` if the spending limit doesn't cover `mint`.
+    pub fn remaining_amount_of(&self, mint: &Pubkey) -> Option<u64> {
+        match self.mint_position(mint)? {
+            0 => Some(self.remaining_amount),
+            position => Some(self.extension.additional_mints[position - 1].remaining_amount),
+        }
+    }
+
+    /// Returns the amount of `mint` that `signer` can still spend in the current period
+    /// according to their own allowance, or `None` if they don't have one.
+    /// The shared `remaining_amount_of` the mint applies in addition.
+    pub fn signer_remaining_amount_of(&self, signer: &Pubkey, mint: &Pubkey) -> Option<u64> {
+        self.extension
+            .signer_allowances
+            .iter()
+            .find(|allowance| allowance.signer == *signer && allowance.mint == *mint)
+            .map(|allowance| allowance.remaining_amount)
+    }
+
+    /// Deducts `amount` of `mint` spent by `signer` from the amounts that can still be spent
+    /// in the current period: the shared one and, if any, the allowance of `signer`.
+    /// Must be called after `reset_if_period_passed` with the same `now`.
+    pub fn debit(&mut self, signer: &Pubkey, mint: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        let positions = [
+            Some(
+                self.mint_position(mint)
+                    .ok_or(SmartAccountError::InvalidMint)?,
+            ),
+            self.signer_allowance_position(signer, mint),
+        ];
+        self.debit_positions(&positions, amount, now)
+    }
+
+    /// Returns the allowance of the NFT `group`, if the spending limit has one.
+    pub fn nft_allowance(&self, group: &NftGroup) -> Option<&NftAllowance> {
+        self.extension
+            .nft_allowances
+            .iter()
+            .find(|allowance| allowance.group == *group)
+    }
+
+    /// Deducts `count` NFTs of `group` from the number that can still be transferred
+    /// in the current period.
+    /// Must be called after `reset_if_period_passed` with the same `now`.
+    pub fn debit_nfts(&mut self, group: &NftGroup, count: u64, now: i64) -> Result<()> {
+        let position = self
+            .nft_allowance_position(group)
+            .ok_or(SmartAccountError::InvalidNft)?;
+        self.debit_positions(&[Some(position)], count, now)
+    }
+
+    /// Splits `remaining_accounts` into the `threshold - 1` co-signers leading them
+    /// and the accounts that follow.
+    pub fn split_cosigners<'a, 'info>(
+        &self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+        let num_cosigners = (self.extension.threshold as usize).saturating_sub(1);
+        require!(
+            remaining_accounts.len() >= num_cosigners,
+            SmartAccountError::InvalidSignerCount
+        );
+        Ok(remaining_accounts.split_at(num_cosigners))
+    }
+
+    /// Checks that `cosigners` signed and are `signers` of the spending limit,
+    /// distinct from each other and from `signer`.
+    pub fn check_cosigners(&self, signer: &Pubkey, cosigners: &[AccountInfo]) -> Result<()> {
+        for (i, cosigner) in cosigners.iter().enumerate() {
+            require!(
+                cosigner.is_signer && self.signers.contains(cosigner.key),
+                SmartAccountError::Unauthorized
+            );
+            require!(
+                cosigner.key != signer
+                    && cosigners[..i].iter().all(|other| other.key != cosigner.key),
+                SmartAccountError::DuplicateSigner
+            );
+        }
+        Ok(())
+    }
+
+    /// Records `spending_limit_use` in `usage_history`,
+    /// overwriting the oldest use once the history is full.
+    pub fn record_use(&mut self, spending_limit_use: SpendingLimitUse) {
+        let capacity = self.extension.usage_history_capacity as u64;
+        if capacity == 0 {
+            return;
+        }
+        if (self.extension.usage_history.len() as u64) < capacity {
+            self.extension.usage_history.push(spending_limit_use);
+        } else {
+            let oldest = (self.extension.usage_count % capacity) as usize;
+            self.extension.usage_history[oldest] = spending_limit_use;
+        }
+        self.extension.usage_count = self.extension.usage_count.saturating_add(1);
+    }
+
+    /// Returns the uses in `usage_history`, oldest first.
+    pub fn recent_uses(&self) -> impl Iterator<Item = &SpendingLimitUse> {
+        // Until the history is full, `usage_count` is its length and the oldest use comes first.
+        let oldest = match self.extension.usage_history.len() {
+            0 => 0,
+            len => (self.extension.usage_count % len as u64) as usize,
+        };
+        let (newer, older) = self.extension.usage_history.split_at(oldest);
+        older.iter().chain(newer)
+    }
+
+    fn debit_positions(
+        &mut self,
+        positions: &[Option<usize>],
+        amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        // Check every allowance before debiting any, so a failed debit leaves no trace.
+        for &position in positions.iter().flatten() {
+            let (_, remaining_amount) = self.allowance_at_mut(position);
+            require!(
+                *remaining_amount >= amount,
+                SmartAccountError::SpendingLimitExceeded
+            );
+        }
+
+        for &position in positions.iter().flatten() {
+            let (_, remaining_amount) = self.allowance_at_mut(position);
+            *remaining_amount -= amount;
+
+            // Record the spend, so it's credited back once it leaves the window.
+            if let Some((bucket_index, slot)) = self.period.sliding_window_slot(now) {
+                let bucket = &mut self.extension.sliding_window[slot];
+                bucket.index = bucket_index;
+                bucket.spent[position] = bucket.spent[position].checked_add(amount).unwrap();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resets `remaining_amount` if the `period` has passed since `last_reset`.
+    /// For sliding windows, credits back the spends that have left the window instead.
+    pub fn reset_if_period_passed(&mut self, now: i64) {
+        if let Some((current_index, _)) = self.period.sliding_window_slot(now) {
+            let num_buckets = self.extension.sliding_window.len() as i64;
+            for bucket_position in 0..self.extension.sliding_window.len() {
+                let bucket = &mut self.extension.sliding_window[bucket_position];
+                if bucket.index + num_buckets > current_index {
+                    continue;
+                }
+                let spent = std::mem::take(&mut bucket.spent);
+                for (position, spent) in spent.iter().enumerate() {
+                    let (amount, remaining_amount) = self.allowance_at_mut(position);
+                    *remaining_amount = remaining_amount.saturating_add(*spent).min(amount);
+                }
+                self.extension.sliding_window[bucket_position].spent = vec![0; spent.len()];
+            }
+            return;
+        }
+
+        let current_period_start = match self.period.current_period_start(self.last_reset, now) {
+            Some(current_period_start) => current_period_start,
+            None => return,
+        };
+
+        if current_period_start > self.last_reset {
+            for position in 0..self.allowance_keys().len() {
+                let (amount, remaining_amount) = self.allowance_at_mut(position);
+                *remaining_amount = amount;
+            }
+            self.last_reset = current_period_start;
+        }
+    }
+
+    /// Size of the account holding the spending limit in its current state.
+    /// Spending limits created before `SpendingLimitExtension` only need room for it once it's set.
+    pub fn current_size(&self) -> usize {
+        let size = Self::size(self.signers.len(), self.destinations.len(), &self.period);
+        if !self.extension.is_stored() && *self.extension == SpendingLimitExtension::default() {
+            return size;
+        }
+        size + AccountExtension::<SpendingLimitExtension>::size(self.extension.size())
+    }
+
+    /// Applies `update` in place.
+    ///
+    /// The amounts spent in the current period (or window) are carried over: they keep counting
+    /// against the updated amounts of their mints and signer allowances, and a changed `period`
+    /// starts counting them from the current period of the new `period`.
+    pub fn update(&mut self, update: &SpendingLimitUpdate, now: i64) -> Result<()> {
+        // Make sure only the spends of the current period are carried over.
+        self.reset_if_period_passed(now);
+
+        let keys_before = self.allowance_keys();
+        let spent_before: Vec<u64> = (0..keys_before.len())
+            .map(|position| {
+                let (amount, remaining_amount) = self.allowance_at_mut(position);
+                amount.saturating_sub(*remaining_amount)
+            })
+            .collect();
+        let position_before =
+            |key: &AllowanceKey| keys_before.iter().position(|key_before| key_before == key);
+        let period_before = self.period;
+
+        if let Some(amount) = update.amount {
+            self.amount = amount;
+        }
+        if let Some(period) = update.period {
+            self.period = period;
+        }
+        if let Some(signers) = &update.signers {
+            let mut signers = signers.clone();
+            signers.sort();
+            self.signers = signers;
+        }
+        if let Some(destinations) = &update.destinations {
+            self.destinations = destinations.clone();
+        }
+        if let Some(expiration) = update.expiration {
+            self.expiration = expiration;
+        }
+        if let Some(additional_mints) = &update.additional_mints {
+            self.extension.additional_mints = additional_mints
+                .iter()
+                .copied()
+                .map(MintAllowance::from)
+                .collect();
+        }
+        if let Some(signer_allowances) = &update.signer_allowances {
+            self.extension.signer_allowances = signer_allowances
+                .iter()
+                .copied()
+                .map(SignerAllowance::from)
+                .collect();
+        }
+        if let Some(transfer_fee_charging) = update.transfer_fee_charging {
+            self.extension.transfer_fee_charging = transfer_fee_charging;
+        }
+        if let Some(pausers) = &update.pausers {
+            self.extension.pausers = pausers.clone();
+        }
+        if let Some(paused) = update.paused {
+            self.extension.paused = paused;
+        }
+        if let Some(threshold) = update.threshold {
+            self.extension.threshold = threshold;
+        }
+        if let Some(nft_allowances) = &update.nft_allowances {
+            self.extension.nft_allowances = nft_allowances
+                .iter()
+                .copied()
+                .map(NftAllowance::from)
+                .collect();
+        }
+
+        let keys = self.allowance_keys();
+        let spent: Vec<u64> = keys
+            .iter()
+            .map(|key| position_before(key).map_or(0, |position| spent_before[position]))
+            .collect();
+
+        for (position, spent) in spent.iter().enumerate() {
+            let (amount, remaining_amount) = self.allowance_at_mut(position);
+            *remaining_amount = amount.saturating_sub(*spent);
+        }
+
+        if self.period == period_before {
+            // Keep tracking the spends in their buckets, for the allowances that are still there.
+            for bucket in self.extension.sliding_window.iter_mut() {
+                bucket.spent = keys
+                    .iter()
+                    .map(|key| position_before(key).map_or(0, |position| bucket.spent[position]))
+                    .collect();
+            }
+        } else {
+            self.extension.sliding_window = self.empty_sliding_window();
+            // The carried over spends leave the new window as if they were made now.
+            if let Some((bucket_index, slot)) = self.period.sliding_window_slot(now) {
+                self.extension.sliding_window[slot] = SlidingWindowBucket {
+                    index: bucket_index,
+                    spent,
+                };
+            }
+        }
+
+        self.invariant()
+    }
 }
 
-/// The reset period of the spending limit.
+/// Changes to apply to a spending limit, `None` leaving the field as is.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpendingLimitUpdate {
+    /// The new amount of `mint` that can be spent in a period.
+    pub amount: Option<u64>,
+    /// The new reset period.
+    pub period: Option<Period>,
+    /// The new signers that can use the spending limit.
+    pub signers: Option<Vec<Pubkey>>,
+    /// The new destination addresses, empty meaning any address.
+    pub destinations: Option<Vec<Pubkey>>,
+    /// The new expiration timestamp, `i64::MAX` meaning no expiration.
+    pub expiration: Option<i64>,
+    /// The new additional mints and their amounts per period.
+    pub additional_mints: Option<Vec<MintAmount>>,
+    /// The new per-signer allowances.
+    pub signer_allowances: Option<Vec<SignerAmount>>,
+    /// The new way of charging transfer fees.
+    pub transfer_fee_charging: Option<TransferFeeCharging>,
+    /// The new signers that can pause the spending limit.
+    pub pausers: Option<Vec<Pubkey>>,
+    /// Pauses or resumes the spending limit.
+    pub paused: Option<bool>,
+    /// The new NFT allowances.
+    pub nft_allowances: Option<Vec<NftCount>>,
+    /// The new number of signers that must sign a use.
+    pub threshold: Option<u8>,
+}
+
+/// Identifies an amount tracked by a spending limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AllowanceKey {
+    /// Amount of a mint shared by all signers.
+    Mint(Pubkey),
+    /// Amount of a mint (second) a signer (first) can spend.
+    Signer(Pubkey, Pubkey),
+    /// Number of NFTs of a group.
+    Nft(NftGroup),
+}
+
+/// Group of NFTs an NFT allowance covers.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NftGroup {
+    /// NFTs that are verified members of a Metaplex collection.
+    Collection(Pubkey),
+    /// NFTs with a verified Metaplex creator.
+    Creator(Pubkey),
+}
+
+/// Number of NFTs of a group that can be transferred per period through a spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftAllowance {
+    pub group: NftGroup,
+    /// Program that verifies transfers of compressed NFTs of the group,
+    /// `None` if only SPL NFTs can be transferred.
+    pub compressed_verifier: Option<Pubkey>,
+    /// Number of NFTs that can be transferred per period.
+    pub count: u64,
+    /// Number of NFTs that can still be transferred in the current period.
+    pub remaining_count: u64,
+}
+
+/// Number of NFTs of a group to allow per period, as passed when creating or updating
+/// a spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftCount {
+    pub group: NftGroup,
+    /// Program that verifies transfers of compressed NFTs of the group,
+    /// `None` if only SPL NFTs can be transferred.
+    pub compressed_verifier: Option<Pubkey>,
+    pub count: u64,
+}
+
+impl From<NftCount> for NftAllowance {
+    fn from(nft_count: NftCount) -> Self {
+        Self {
+            group: nft_count.group,
+            compressed_verifier: nft_count.compressed_verifier,
+            count: nft_count.count,
+            remaining_count: nft_count.count,
+        }
+    }
+}
+
+/// Largest `usage_history_capacity` of a spending limit.
+/// Each use takes `SpendingLimitUse::INIT_SPACE` (144) bytes, so a full history takes 9 KiB,
+/// leaving room for the rest of the account within the 10 KiB it can be created with.
+pub const MAX_USAGE_HISTORY_CAPACITY: u16 = 64;
+
+/// A use of a spending limit, as recorded in its `usage_history`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpendingLimitUse {
+    /// The signer that used the spending limit.
+    pub signer: Pubkey,
+    /// The mint that was spent, `Pubkey::default()` for SOL.
+    /// For NFTs, the mint of the NFT, `Pubkey::default()` for compressed NFTs.
+    pub mint: Pubkey,
+    /// The destination of the funds, `Pubkey::default()` if it isn't known,
+    /// e.g. for spending limits used to execute arbitrary transactions.
+    pub destination: Pubkey,
+    /// The amount that was sent, or the number of NFTs.
+    pub amount: u64,
+    /// Unix timestamp of the use.
+    pub timestamp: i64,
+    /// SHA-256 hash of the memo of the use, all zeroes if it had none.
+    pub memo_hash: [u8; 32],
+}
+
+impl SpendingLimitUse {
+    /// Hash of `memo` as recorded in `memo_hash`.
+    pub fn memo_hash(memo: &Option<String>) -> [u8; 32] {
+        memo.as_ref()
+            .map_or([0; 32], |memo| hash(memo.as_bytes()).to_bytes())
+    }
+}
+
+/// Oracle a spending limit denominated in a quote currency, e.g. USD, converts spends with.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceOracle {
+    /// Pyth-style price account of `mint` in the quote currency.
+    pub price_account: Pubkey,
+    /// Program that must own `price_account`.
+    pub oracle_program: Pubkey,
+    /// Decimals the amounts of the spending limit are expressed in,
+    /// e.g. 6 for amounts in millionths of a dollar.
+    pub quote_decimals: u8,
+    /// Maximum age of the price, in seconds.
+    pub max_staleness: i64,
+    /// Maximum width of the confidence interval of the price, in basis points of the price.
+    pub max_confidence_bps: u16,
+}
+
+impl PriceOracle {
+    pub fn invariant(&self) -> Result<()> {
+        require!(
+            self.max_staleness >= 0 && self.max_confidence_bps <= 10_000,
+            SmartAccountError::InvalidPriceOracle
+        );
+        Ok(())
+    }
+}
+
+/// What a transfer of a mint with a Token-2022 transfer fee is charged to a spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFeeCharging {
+    /// The amount sent, including the fee.
+    Gross,
+    /// The amount received by the destination, excluding the fee.
+    Net,
+}
+
+impl TransferFeeCharging {
+    /// The amount to charge for a transfer of `amount` on which `fee` is withheld.
+    pub fn charged_amount(&self, amount: u64, fee: u64) -> u64 {
+        match self {
+            Self::Gross => amount,
+            Self::Net => amount.saturating_sub(fee),
+        }
+    }
+}
+
+/// Amount of an additional mint that can be spent per period through a spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintAllowance {
+    /// The token mint. Pubkey::default() means SOL.
+    pub mint: Pubkey,
+    /// The amount of tokens that can be spent in a period, in decimals of the mint.
+    pub amount: u64,
+    /// The remaining amount of tokens that can be spent in the current period.
+    pub remaining_amount: u64,
+}
+
+/// A mint and the amount of it that can be spent per period, used to configure
+/// the additional mints of a spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintAmount {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl From<MintAmount> for MintAllowance {
+    fn from(mint_amount: MintAmount) -> Self {
+        Self {
+            mint: mint_amount.mint,
+            amount: mint_amount.amount,
+            remaining_amount: mint_amount.amount,
+        }
+    }
+}
+
+/// Amount of a mint that a single signer can spend per period through a spending limit,
+/// on top of the amount shared by all signers.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignerAllowance {
+    /// One of the `signers` of the spending limit.
+    pub signer: Pubkey,
+    /// One of the mints covered by the spending limit.
+    pub mint: Pubkey,
+    /// The amount of tokens the signer can spend in a period, in decimals of the mint.
+    pub amount: u64,
+    /// The remaining amount of tokens the signer can spend in the current period.
+    pub remaining_amount: u64,
+}
+
+/// A signer, a mint and the amount of it the signer can spend per period, used to configure
+/// the per-signer allowances of a spending limit.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignerAmount {
+    pub signer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl From<SignerAmount> for SignerAllowance {
+    fn from(signer_amount: SignerAmount) -> Self {
+        Self {
+            signer: signer_amount.signer,
+            mint: signer_amount.mint,
+            amount: signer_amount.amount,
+            remaining_amount: signer_amount.amount,
+        }
+    }
+}
+
+/// Spends of a sliding window spending limit within one slot of the window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SlidingWindowBucket {
+    /// Index of the slot the spends were made in, counted from the unix epoch.
+    pub index: i64,
+    /// Amount spent against every allowance of the spending limit: `mint` first,
+    /// followed by `additional_mints`, followed by `signer_allowances`,
+    /// followed by `nft_allowances`.
+    pub spent: Vec<u64>,
+}
+
+impl SlidingWindowBucket {
+    pub fn size(allowances_length: usize) -> usize {
+        8 + // index
+        4 + // spent vector length
+        allowances_length * 8 // spent
+    }
+}
+
+/// Number of buckets the spends of a `Period::SlidingWindow` spending limit are tracked in.
+pub const SLIDING_WINDOW_BUCKETS: usize = 8;
+
+/// Longest `seconds` of a `Period::Custom` or `Period::SlidingWindow`.
+pub const MAX_PERIOD_SECONDS: i64 = 10 * 365 * 24 * 60 * 60; // 10 years
+
+/// The reset period of the spending limit.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Period {
     /// The spending limit can only be used once.
     OneTime,
@@ -96,15 +1069,935 @@ pub enum Period {
     Week,
     /// The spending limit is reset every month (30 days).
     Month,
+    /// The spending limit is reset every `seconds` seconds.
+    Custom { seconds: i64 },
+    /// The spending limit is reset every calendar month (UTC),
+    /// on the day of the month and at the time of day of `anchor`.
+    /// Anchors past the 28th reset on the last day of shorter months.
+    CalendarMonth { anchor: i64 },
+    /// The spending limit is never reset, instead the amount spent within
+    /// any `seconds` long window is capped.
+    ///
+    /// Spends are tracked in `SLIDING_WINDOW_BUCKETS` buckets of
+    /// `seconds / (SLIDING_WINDOW_BUCKETS - 1)` seconds (rounded up), and a spend is credited
+    /// back once its whole bucket has left the window, so the cap is enforced conservatively.
+    SlidingWindow { seconds: i64 },
 }
 
 impl Period {
+    /// Borsh-serialized size of the period, which depends on its variant.
+    pub fn size(&self) -> usize {
+        match self {
+            Period::OneTime | Period::Day | Period::Week | Period::Month => 1,
+            Period::Custom { .. } | Period::CalendarMonth { .. } | Period::SlidingWindow { .. } => {
+                1 + 8
+            }
+        }
+    }
+
+    /// Length of the period in seconds, for periods of a fixed length.
     pub fn to_seconds(&self) -> Option<i64> {
         match self {
             Period::OneTime => None,
             Period::Day => Some(24 * 60 * 60),
             Period::Week => Some(7 * 24 * 60 * 60),
             Period::Month => Some(30 * 24 * 60 * 60),
+            Period::Custom { seconds } => Some(*seconds),
+            Period::CalendarMonth { .. } => None,
+            Period::SlidingWindow { .. } => None,
+        }
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        match self {
+            Period::Custom { seconds } | Period::SlidingWindow { seconds } => {
+                require!(
+                    *seconds > 0 && *seconds <= MAX_PERIOD_SECONDS,
+                    SmartAccountError::SpendingLimitInvalidPeriod
+                );
+                // A sliding window needs a bucket for the current slot and for the ones before.
+                require!(
+                    self.sliding_window_buckets() == 0 || self.sliding_window_buckets() >= 2,
+                    SmartAccountError::SpendingLimitInvalidPeriod
+                );
+            }
+            Period::CalendarMonth { anchor } => {
+                require!(*anchor >= 0, SmartAccountError::SpendingLimitInvalidPeriod);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the start of the period `now` falls into, given the start of a previous period,
+    /// or `None` if the period is never reset.
+    pub fn current_period_start(&self, last_reset: i64, now: i64) -> Option<i64> {
+        match self {
+            Period::CalendarMonth { anchor } => Some(
+                calendar_month_start(*anchor, now)
+                    .map_or(last_reset, |period_start| period_start.max(last_reset)),
+            ),
+            _ => {
+                let reset_period = self.to_seconds()?;
+                let passed_since_last_reset = now.checked_sub(last_reset).unwrap();
+                if passed_since_last_reset <= reset_period {
+                    return Some(last_reset);
+                }
+
+                let periods_passed = passed_since_last_reset.checked_div(reset_period).unwrap();
+
+                // last_reset + periods_passed * reset_period
+                Some(
+                    last_reset
+                        .checked_add(periods_passed.checked_mul(reset_period).unwrap())
+                        .unwrap(),
+                )
+            }
+        }
+    }
+
+    /// Number of `SlidingWindowBucket`s a spending limit with this period keeps.
+    pub fn sliding_window_buckets(&self) -> usize {
+        match self {
+            Period::SlidingWindow { .. } => SLIDING_WINDOW_BUCKETS,
+            _ => 0,
+        }
+    }
+
+    /// For sliding windows, returns the index of the bucket `now` falls into,
+    /// and the position of that bucket in `SpendingLimit::sliding_window`.
+    fn sliding_window_slot(&self, now: i64) -> Option<(i64, usize)> {
+        match self {
+            Period::SlidingWindow { seconds } => {
+                let num_buckets = SLIDING_WINDOW_BUCKETS as i64;
+                // Rounded up, so that the buckets other than the current one span the whole window.
+                // `seconds` and `num_buckets` are bounded by `Period::invariant`.
+                let bucket_seconds = seconds
+                    .checked_add(num_buckets - 2)?
+                    .checked_div(num_buckets - 1)?;
+                let index = now.checked_div_euclid(bucket_seconds)?;
+                Some((index, index.checked_rem_euclid(num_buckets)? as usize))
+            }
+            _ => None,
         }
     }
 }
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Returns the latest monthly anniversary of `anchor` at or before `now`,
+/// or `None` if `now` is before `anchor`.
+fn calendar_month_start(anchor: i64, now: i64) -> Option<i64> {
+    if now < anchor {
+        return None;
+    }
+
+    let (anchor_year, anchor_month, anchor_day) =
+        civil_from_days(anchor.div_euclid(SECONDS_PER_DAY));
+    let time_of_day = anchor.rem_euclid(SECONDS_PER_DAY);
+    let (mut year, mut month, _) = civil_from_days(now.div_euclid(SECONDS_PER_DAY));
+
+    loop {
+        let day = anchor_day.min(days_in_month(year, month));
+        let period_start = days_from_civil(year, month, day) * SECONDS_PER_DAY + time_of_day;
+        if period_start <= now {
+            return Some(period_start);
+        }
+        // The anniversary in the month of `now` is still ahead, so the period started
+        // in the previous month, which is never before the month of `anchor`.
+        if (year, month) <= (anchor_year, anchor_month) {
+            return Some(anchor);
+        }
+        (year, month) = if month == 1 {
+            (year - 1, 12)
+        } else {
+            (year, month - 1)
+        };
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts days since the unix epoch to a (year, month, day) date
+/// in the proleptic Gregorian calendar.
+/// See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date in the proleptic Gregorian calendar
+/// to days since the unix epoch.
+/// See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn spending_limit() -> SpendingLimit {
+        SpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 0,
+            mint: Pubkey::default(),
+            amount: 100,
+            period: Period::Day,
+            remaining_amount: 100,
+            last_reset: 0,
+            bump: 255,
+            signers: vec![Pubkey::new_unique()],
+            destinations: vec![],
+            expiration: i64::MAX,
+            extension: AccountExtension::new(SpendingLimitExtension::default()),
+        }
+    }
+
+    #[test]
+    fn test_debits_the_selected_mint() {
+        let usdc = Pubkey::new_unique();
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        spending_limit.extension.additional_mints = vec![MintAmount {
+            mint: usdc,
+            amount: 1_000,
+        }
+        .into()];
+        spending_limit.invariant().unwrap();
+
+        spending_limit.debit(&signer, &usdc, 400, 0).unwrap();
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 40, 0)
+            .unwrap();
+
+        assert_eq!(spending_limit.remaining_amount_of(&usdc), Some(600));
+        assert_eq!(
+            spending_limit.remaining_amount_of(&Pubkey::default()),
+            Some(60)
+        );
+        assert!(spending_limit.debit(&signer, &usdc, 601, 0).is_err());
+        assert!(spending_limit
+            .debit(&signer, &Pubkey::new_unique(), 1, 0)
+            .is_err());
+
+        spending_limit.reset_if_period_passed(Period::Day.to_seconds().unwrap() + 1);
+
+        assert_eq!(spending_limit.remaining_amount_of(&usdc), Some(1_000));
+        assert_eq!(
+            spending_limit.remaining_amount_of(&Pubkey::default()),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn test_mints_are_covered_once() {
+        let mut spending_limit = spending_limit();
+        spending_limit.extension.additional_mints = vec![MintAmount {
+            mint: spending_limit.mint,
+            amount: 1,
+        }
+        .into()];
+        assert!(spending_limit.invariant().is_err());
+
+        let usdc = Pubkey::new_unique();
+        spending_limit.extension.additional_mints = vec![
+            MintAmount {
+                mint: usdc,
+                amount: 1,
+            }
+            .into(),
+            MintAmount {
+                mint: usdc,
+                amount: 2,
+            }
+            .into(),
+        ];
+        assert!(spending_limit.invariant().is_err());
+    }
+
+    /// Unix timestamp of the given UTC date and time.
+    fn timestamp(year: i64, month: i64, day: i64, seconds: i64) -> i64 {
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + seconds
+    }
+
+    #[test]
+    fn test_civil_date_conversions() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        // 2024-02-29T00:00:00Z
+        assert_eq!(timestamp(2024, 2, 29, 0), 1_709_164_800);
+        for days in -1_000_000..1_000_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_custom_period() {
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        spending_limit.period = Period::Custom { seconds: 90 };
+        spending_limit.invariant().unwrap();
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 100, 0)
+            .unwrap();
+
+        spending_limit.reset_if_period_passed(90);
+        assert_eq!(spending_limit.remaining_amount, 0);
+
+        spending_limit.reset_if_period_passed(200);
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(spending_limit.last_reset, 180);
+
+        spending_limit.period = Period::Custom { seconds: 0 };
+        assert!(spending_limit.invariant().is_err());
+    }
+
+    #[test]
+    fn test_period_seconds_are_bounded() {
+        for period in [
+            Period::Custom { seconds: i64::MAX },
+            Period::SlidingWindow { seconds: i64::MAX },
+            Period::SlidingWindow {
+                seconds: MAX_PERIOD_SECONDS + 1,
+            },
+        ] {
+            assert!(period.invariant().is_err());
+        }
+
+        let period = Period::SlidingWindow {
+            seconds: MAX_PERIOD_SECONDS,
+        };
+        period.invariant().unwrap();
+        assert!(period.sliding_window_slot(i64::MAX).is_some());
+        assert!(period.sliding_window_slot(i64::MIN).is_some());
+    }
+
+    #[test]
+    fn test_calendar_month_period() {
+        // Resets at noon on the 31st, or the last day of shorter months.
+        let anchor = timestamp(2024, 1, 31, 12 * 60 * 60);
+        let period = Period::CalendarMonth { anchor };
+
+        assert_eq!(period.current_period_start(anchor, anchor), Some(anchor));
+        assert_eq!(
+            period.current_period_start(anchor, timestamp(2024, 2, 29, 11 * 60 * 60)),
+            Some(anchor)
+        );
+        assert_eq!(
+            period.current_period_start(anchor, timestamp(2024, 2, 29, 12 * 60 * 60)),
+            Some(timestamp(2024, 2, 29, 12 * 60 * 60))
+        );
+        assert_eq!(
+            period.current_period_start(anchor, timestamp(2024, 4, 15, 0)),
+            Some(timestamp(2024, 3, 31, 12 * 60 * 60))
+        );
+        assert_eq!(
+            period.current_period_start(anchor, timestamp(2025, 1, 1, 0)),
+            Some(timestamp(2024, 12, 31, 12 * 60 * 60))
+        );
+
+        // A spending limit created mid-period is reset on the next anniversary.
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        spending_limit.period = period;
+        spending_limit.last_reset = timestamp(2024, 3, 10, 0);
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 100, spending_limit.last_reset)
+            .unwrap();
+
+        spending_limit.reset_if_period_passed(timestamp(2024, 3, 31, 0));
+        assert_eq!(spending_limit.remaining_amount, 0);
+
+        spending_limit.reset_if_period_passed(timestamp(2024, 4, 2, 0));
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(
+            spending_limit.last_reset,
+            timestamp(2024, 3, 31, 12 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn test_sliding_window_period() {
+        let usdc = Pubkey::new_unique();
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        // Buckets of 10 seconds.
+        spending_limit.period = Period::SlidingWindow { seconds: 70 };
+        spending_limit.extension.additional_mints = vec![MintAmount {
+            mint: usdc,
+            amount: 1_000,
+        }
+        .into()];
+        assert!(spending_limit.invariant().is_err());
+        spending_limit.extension.sliding_window = spending_limit.empty_sliding_window();
+        spending_limit.invariant().unwrap();
+
+        let mut spend = |now: i64, mint: &Pubkey, amount: u64| {
+            spending_limit.reset_if_period_passed(now);
+            spending_limit.debit(&signer, mint, amount, now)
+        };
+
+        spend(1_005, &Pubkey::default(), 60).unwrap();
+        spend(1_035, &Pubkey::default(), 40).unwrap();
+        spend(1_035, &usdc, 1_000).unwrap();
+        // The window is full until the first spend has left it.
+        assert!(spend(1_079, &Pubkey::default(), 1).is_err());
+        spend(1_080, &Pubkey::default(), 60).unwrap();
+        assert!(spend(1_080, &Pubkey::default(), 1).is_err());
+        assert!(spend(1_109, &usdc, 1).is_err());
+        spend(1_110, &usdc, 1_000).unwrap();
+        spend(1_110, &Pubkey::default(), 40).unwrap();
+
+        // Much later, everything has been credited back.
+        spending_limit.reset_if_period_passed(10_000);
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(spending_limit.remaining_amount_of(&usdc), Some(1_000));
+    }
+
+    #[test]
+    fn test_update_carries_over_spends() {
+        let usdc = Pubkey::new_unique();
+        let usdt = Pubkey::new_unique();
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        spending_limit.extension.additional_mints = vec![MintAmount {
+            mint: usdc,
+            amount: 1_000,
+        }
+        .into()];
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 30, 0)
+            .unwrap();
+        spending_limit.debit(&signer, &usdc, 900, 0).unwrap();
+
+        let new_signer = Pubkey::new_unique();
+        spending_limit
+            .update(
+                &SpendingLimitUpdate {
+                    amount: Some(50),
+                    signers: Some(vec![new_signer, signer]),
+                    additional_mints: Some(vec![
+                        MintAmount {
+                            mint: usdt,
+                            amount: 10,
+                        },
+                        MintAmount {
+                            mint: usdc,
+                            amount: 500,
+                        },
+                    ]),
+                    ..SpendingLimitUpdate::default()
+                },
+                60,
+            )
+            .unwrap();
+
+        assert_eq!(spending_limit.remaining_amount, 20);
+        assert_eq!(spending_limit.remaining_amount_of(&usdc), Some(0));
+        assert_eq!(spending_limit.remaining_amount_of(&usdt), Some(10));
+        assert_eq!(spending_limit.signers.len(), 2);
+        assert!(spending_limit.signers.windows(2).all(|win| win[0] < win[1]));
+        assert_eq!(spending_limit.last_reset, 0);
+        assert!(spending_limit.current_size() >= spending_limit.try_to_vec().unwrap().len() + 8);
+
+        // Switching to a sliding window keeps the spends until they leave the window.
+        spending_limit
+            .update(
+                &SpendingLimitUpdate {
+                    period: Some(Period::SlidingWindow { seconds: 70 }),
+                    ..SpendingLimitUpdate::default()
+                },
+                100,
+            )
+            .unwrap();
+        assert_eq!(spending_limit.remaining_amount, 20);
+        spending_limit.reset_if_period_passed(179);
+        assert_eq!(spending_limit.remaining_amount, 20);
+        spending_limit.reset_if_period_passed(180);
+        assert_eq!(spending_limit.remaining_amount, 50);
+        assert_eq!(spending_limit.remaining_amount_of(&usdc), Some(500));
+
+        // Updates must keep the spending limit valid.
+        assert!(spending_limit
+            .update(
+                &SpendingLimitUpdate {
+                    amount: Some(0),
+                    ..SpendingLimitUpdate::default()
+                },
+                200,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_signer_allowances() {
+        let mut spending_limit = spending_limit();
+        let capped_signer = Pubkey::new_unique();
+        let signer = spending_limit.signers[0];
+        spending_limit.signers.push(capped_signer);
+        spending_limit.signers.sort();
+        spending_limit.period = Period::SlidingWindow { seconds: 70 };
+        spending_limit.extension.signer_allowances = vec![SignerAmount {
+            signer: capped_signer,
+            mint: Pubkey::default(),
+            amount: 30,
+        }
+        .into()];
+        spending_limit.extension.sliding_window = spending_limit.empty_sliding_window();
+        spending_limit.invariant().unwrap();
+
+        spending_limit
+            .debit(&capped_signer, &Pubkey::default(), 20, 0)
+            .unwrap();
+        assert!(spending_limit
+            .debit(&capped_signer, &Pubkey::default(), 11, 0)
+            .is_err());
+        // Other signers are only bound by the shared amount.
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 75, 0)
+            .unwrap();
+        assert_eq!(
+            spending_limit.signer_remaining_amount_of(&capped_signer, &Pubkey::default()),
+            Some(10)
+        );
+        assert_eq!(
+            spending_limit.signer_remaining_amount_of(&signer, &Pubkey::default()),
+            None
+        );
+        // The shared amount still binds signers with an allowance.
+        assert!(spending_limit
+            .debit(&capped_signer, &Pubkey::default(), 10, 0)
+            .is_err());
+
+        spending_limit.reset_if_period_passed(80);
+        assert_eq!(spending_limit.remaining_amount, 100);
+        assert_eq!(
+            spending_limit.signer_remaining_amount_of(&capped_signer, &Pubkey::default()),
+            Some(30)
+        );
+
+        // Allowances must belong to signers of the spending limit, for covered mints.
+        spending_limit.extension.signer_allowances[0].signer = Pubkey::new_unique();
+        assert!(spending_limit.invariant().is_err());
+        spending_limit.extension.signer_allowances[0].signer = capped_signer;
+        spending_limit.extension.signer_allowances[0].mint = Pubkey::new_unique();
+        assert!(spending_limit.invariant().is_err());
+    }
+
+    #[test]
+    fn test_price_oracle() {
+        let mut spending_limit = spending_limit();
+        // Without a price oracle, spends are charged in raw amounts.
+        assert_eq!(spending_limit.value_of(1_000, 9, None, 0).unwrap(), 1_000);
+
+        let price_oracle = PriceOracle {
+            price_account: Pubkey::new_unique(),
+            oracle_program: Pubkey::new_unique(),
+            quote_decimals: 6,
+            max_staleness: 60,
+            max_confidence_bps: 100,
+        };
+        spending_limit.extension.price_oracle = Some(price_oracle);
+        spending_limit.invariant().unwrap();
+
+        // The price account is required, and must be the configured one.
+        assert!(spending_limit.value_of(1_000, 9, None, 0).is_err());
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![];
+        let other_price_account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &price_oracle.oracle_program,
+            false,
+            0,
+        );
+        assert!(spending_limit
+            .value_of(1_000, 9, Some(&other_price_account), 0)
+            .is_err());
+
+        // Only `mint` can be converted.
+        spending_limit.extension.additional_mints = vec![MintAmount {
+            mint: Pubkey::new_unique(),
+            amount: 1,
+        }
+        .into()];
+        spending_limit.extension.sliding_window = spending_limit.empty_sliding_window();
+        assert!(spending_limit.invariant().is_err());
+        spending_limit.extension.additional_mints = vec![];
+        spending_limit.extension.sliding_window = spending_limit.empty_sliding_window();
+
+        spending_limit.extension.price_oracle = Some(PriceOracle {
+            max_confidence_bps: 10_001,
+            ..price_oracle
+        });
+        assert!(spending_limit.invariant().is_err());
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let mut spending_limit = spending_limit();
+        let pauser = Pubkey::new_unique();
+        spending_limit.extension.pausers = vec![pauser, pauser];
+        assert!(spending_limit.invariant().is_err());
+
+        spending_limit.extension.pausers = vec![pauser];
+        spending_limit.extension.paused = true;
+        spending_limit.invariant().unwrap();
+
+        // Updates that don't touch `paused` keep the spending limit paused.
+        spending_limit
+            .update(
+                &SpendingLimitUpdate {
+                    amount: Some(200),
+                    ..SpendingLimitUpdate::default()
+                },
+                0,
+            )
+            .unwrap();
+        assert!(spending_limit.extension.paused);
+
+        spending_limit
+            .update(
+                &SpendingLimitUpdate {
+                    paused: Some(false),
+                    pausers: Some(vec![]),
+                    ..SpendingLimitUpdate::default()
+                },
+                0,
+            )
+            .unwrap();
+        assert!(!spending_limit.extension.paused);
+        assert!(spending_limit.extension.pausers.is_empty());
+    }
+
+    #[test]
+    fn test_nft_allowances() {
+        let collection = NftGroup::Collection(Pubkey::new_unique());
+        let creator = NftGroup::Creator(Pubkey::new_unique());
+        let mut spending_limit = spending_limit();
+        spending_limit.extension.nft_allowances = vec![
+            NftCount {
+                group: collection,
+                compressed_verifier: None,
+                count: 2,
+            }
+            .into(),
+            NftCount {
+                group: collection,
+                compressed_verifier: None,
+                count: 1,
+            }
+            .into(),
+        ];
+        // Groups must be unique.
+        assert!(spending_limit.invariant().is_err());
+
+        spending_limit.extension.nft_allowances[1].group = creator;
+        spending_limit.invariant().unwrap();
+        // NFT-only spending limits don't need a fungible amount.
+        spending_limit.amount = 0;
+        spending_limit.remaining_amount = 0;
+        spending_limit.invariant().unwrap();
+
+        spending_limit.debit_nfts(&collection, 2, 0).unwrap();
+        assert!(spending_limit.debit_nfts(&collection, 1, 0).is_err());
+        assert!(spending_limit
+            .debit_nfts(&NftGroup::Creator(Pubkey::new_unique()), 1, 0)
+            .is_err());
+        spending_limit.debit_nfts(&creator, 1, 0).unwrap();
+
+        // Counts are restored once the period has passed.
+        spending_limit.reset_if_period_passed(SECONDS_PER_DAY + 1);
+        let remaining_count = |group| spending_limit.nft_allowance(group).unwrap().remaining_count;
+        assert_eq!(remaining_count(&collection), 2);
+        assert_eq!(remaining_count(&creator), 1);
+
+        // Allowances with a zero count are rejected.
+        spending_limit.extension.nft_allowances[0].count = 0;
+        assert!(spending_limit.invariant().is_err());
+    }
+
+    #[test]
+    fn test_usage_history() {
+        let mut spending_limit = spending_limit();
+        let signer = spending_limit.signers[0];
+        let spending_limit_use = |amount: u64| SpendingLimitUse {
+            signer,
+            mint: Pubkey::default(),
+            destination: Pubkey::new_unique(),
+            amount,
+            timestamp: amount as i64,
+            memo_hash: SpendingLimitUse::memo_hash(&Some(format!("use {amount}"))),
+        };
+        let recent_amounts = |spending_limit: &SpendingLimit| {
+            spending_limit
+                .recent_uses()
+                .map(|spending_limit_use| spending_limit_use.amount)
+                .collect::<Vec<_>>()
+        };
+
+        // Nothing is recorded without a capacity.
+        spending_limit.record_use(spending_limit_use(1));
+        assert!(spending_limit.extension.usage_history.is_empty());
+        assert_eq!(spending_limit.extension.usage_count, 0);
+
+        spending_limit.extension.usage_history_capacity = 3;
+        for amount in 1..=2 {
+            spending_limit.record_use(spending_limit_use(amount));
+        }
+        assert_eq!(recent_amounts(&spending_limit), vec![1, 2]);
+
+        // Once full, the oldest uses are overwritten first.
+        for amount in 3..=7 {
+            spending_limit.record_use(spending_limit_use(amount));
+        }
+        assert_eq!(spending_limit.extension.usage_history.len(), 3);
+        assert_eq!(spending_limit.extension.usage_count, 7);
+        assert_eq!(recent_amounts(&spending_limit), vec![5, 6, 7]);
+
+        // The account has room for a full history.
+        assert!(spending_limit.current_size() >= spending_limit.try_to_vec().unwrap().len() + 8);
+
+        assert_eq!(SpendingLimitUse::memo_hash(&None), [0; 32]);
+    }
+
+    #[test]
+    fn test_max_usage_history_capacity() {
+        let mut spending_limit = spending_limit();
+        spending_limit.extension.usage_history_capacity = MAX_USAGE_HISTORY_CAPACITY;
+        spending_limit.invariant().unwrap();
+
+        // A full history still fits an account created within an instruction.
+        assert!(
+            spending_limit.current_size()
+                <= anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE
+        );
+
+        spending_limit.extension.usage_history_capacity = MAX_USAGE_HISTORY_CAPACITY + 1;
+        assert_eq!(
+            spending_limit.invariant(),
+            Err(SmartAccountError::InvalidUsageHistoryCapacity.into())
+        );
+    }
+
+    #[test]
+    fn test_cosigners() {
+        let mut spending_limit = spending_limit();
+        let mut signers = vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        signers.sort();
+        spending_limit.signers = signers.clone();
+        spending_limit.extension.threshold = 4;
+        assert!(spending_limit.invariant().is_err());
+        spending_limit.extension.threshold = 0;
+        assert!(spending_limit.invariant().is_err());
+        spending_limit.extension.threshold = 2;
+        spending_limit.invariant().unwrap();
+
+        let outsider = Pubkey::new_unique();
+        let owner = Pubkey::default();
+        let mut lamports = [0; 4];
+        let mut data: [Vec<u8>; 4] = Default::default();
+        let keys = [signers[1], signers[2], outsider, signers[1]];
+        let accounts: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, true, false, lamports, data, &owner, false, 0)
+            })
+            .collect();
+
+        // The leading `threshold - 1` accounts are the co-signers.
+        let (cosigners, rest) = spending_limit.split_cosigners(&accounts).unwrap();
+        assert_eq!(cosigners.len(), 1);
+        assert_eq!(rest.len(), 3);
+        spending_limit
+            .check_cosigners(&signers[0], cosigners)
+            .unwrap();
+        assert!(spending_limit.split_cosigners(&accounts[..0]).is_err());
+
+        // The signer can't co-sign their own use.
+        assert!(spending_limit
+            .check_cosigners(&signers[1], cosigners)
+            .is_err());
+        // Co-signers must be distinct signers of the spending limit.
+        assert!(spending_limit
+            .check_cosigners(&signers[0], &accounts[1..3])
+            .is_err());
+        assert!(spending_limit
+            .check_cosigners(&signers[0], &[accounts[0].clone(), accounts[3].clone()])
+            .is_err());
+
+        // Co-signers must sign.
+        let mut unsigned = accounts[0].clone();
+        unsigned.is_signer = false;
+        assert!(spending_limit
+            .check_cosigners(&signers[0], &[unsigned])
+            .is_err());
+    }
+
+    /// Layout of `Period` before periods carrying seconds were introduced.
+    #[derive(AnchorSerialize)]
+    enum LegacyPeriod {
+        OneTime,
+        Day,
+        Week,
+        Month,
+    }
+
+    /// Layout of `SpendingLimit` before `SpendingLimitExtension` was introduced.
+    #[derive(AnchorSerialize)]
+    struct LegacySpendingLimit {
+        settings: Pubkey,
+        seed: Pubkey,
+        account_index: u8,
+        mint: Pubkey,
+        amount: u64,
+        period: LegacyPeriod,
+        remaining_amount: u64,
+        last_reset: i64,
+        bump: u8,
+        signers: Vec<Pubkey>,
+        destinations: Vec<Pubkey>,
+        expiration: i64,
+    }
+
+    #[test]
+    fn test_deserialize_spending_limit_created_before_extension() {
+        // Spending limits created before `SpendingLimitExtension` have the exact size of their
+        // layout.
+        let legacy_spending_limit = LegacySpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 0,
+            mint: Pubkey::default(),
+            amount: 100,
+            period: LegacyPeriod::Day,
+            remaining_amount: 40,
+            last_reset: 0,
+            bump: 255,
+            signers: vec![Pubkey::new_unique()],
+            destinations: vec![],
+            expiration: i64::MAX,
+        };
+        let mut data = SpendingLimit::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&legacy_spending_limit.try_to_vec().unwrap());
+
+        let mut spending_limit = SpendingLimit::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(spending_limit.remaining_amount, 40);
+        assert_eq!(spending_limit.signers, legacy_spending_limit.signers);
+        assert!(!spending_limit.extension.is_stored());
+        assert_eq!(*spending_limit.extension, SpendingLimitExtension::default());
+        assert_eq!(spending_limit.current_size(), data.len());
+
+        // Uses still fit the account.
+        let signer = spending_limit.signers[0];
+        spending_limit
+            .debit(&signer, &Pubkey::default(), 40, 0)
+            .unwrap();
+        spending_limit
+            .try_serialize(&mut data.as_mut_slice())
+            .unwrap();
+        let spending_limit = SpendingLimit::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(spending_limit.remaining_amount, 0);
+        assert!(!spending_limit.extension.is_stored());
+
+        // Pausing it switches to the extension, which needs a reallocation.
+        let mut spending_limit = spending_limit;
+        spending_limit.extension.paused = true;
+        let serialized_size = spending_limit.try_to_vec().unwrap().len() + 8;
+        assert!(serialized_size > data.len());
+        assert!(serialized_size <= spending_limit.current_size());
+    }
+
+    #[test]
+    fn test_legacy_spending_limit_round_trips_and_reallocs_to_exact_size() {
+        // Periods are no longer a fixed-size enum: `Period::Day` still takes a single byte,
+        // while periods carrying seconds take 9.
+        let legacy_spending_limit = LegacySpendingLimit {
+            settings: Pubkey::new_unique(),
+            seed: Pubkey::new_unique(),
+            account_index: 1,
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            period: LegacyPeriod::Day,
+            remaining_amount: 1_000,
+            last_reset: 1_700_000_000,
+            bump: 254,
+            signers: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            destinations: vec![Pubkey::new_unique()],
+            expiration: i64::MAX,
+        };
+        let mut data = SpendingLimit::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&legacy_spending_limit.try_to_vec().unwrap());
+
+        let mut spending_limit = SpendingLimit::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(spending_limit.period, Period::Day);
+        assert_eq!(spending_limit.current_size(), data.len());
+
+        // Written back unchanged, the account keeps its exact bytes.
+        let mut written = vec![0; spending_limit.current_size()];
+        spending_limit
+            .try_serialize(&mut written.as_mut_slice())
+            .unwrap();
+        assert_eq!(written, data);
+
+        // Switching to a period carrying seconds reallocates by exactly the 8 bytes it needs.
+        spending_limit.period = Period::Custom { seconds: 60 * 60 };
+        assert_eq!(spending_limit.current_size(), data.len() + 8);
+        let mut written = vec![0; spending_limit.current_size()];
+        spending_limit
+            .try_serialize(&mut written.as_mut_slice())
+            .unwrap();
+        assert_eq!(
+            spending_limit.try_to_vec().unwrap().len() + 8,
+            written.len()
+        );
+        let spending_limit = SpendingLimit::try_deserialize(&mut written.as_slice()).unwrap();
+        assert_eq!(spending_limit.period, Period::Custom { seconds: 60 * 60 });
+        assert!(!spending_limit.extension.is_stored());
+    }
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::borsh0_10::get_instance_packed_len;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::*;
 use crate::instructions::{CompiledInstruction, MessageAddressTableLookup, TransactionMessage};
+use crate::utils::{AccountExtension, CompressedTransactionMessage};
 
 /// Stores data required for tracking the voting and execution status of a smart
 ///account transaction.
@@ -36,27 +38,100 @@ pub struct Transaction {
     /// thus "signing" on behalf of these PDAs.
     pub ephemeral_signer_bumps: Vec<u8>,
     /// data required for executing the transaction.
+    /// Empty if the transaction was created with a compressed message or with only its hash.
     pub message: SmartAccountTransactionMessage,
+    /// Fields appended after compressed messages were introduced.
+    pub extension: AccountExtension<TransactionExtension>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub struct TransactionExtension {
+    /// `message` encoded as a `CompressedTransactionMessage`,
+    /// if the transaction was created with a compressed message.
+    pub compressed_message: Vec<u8>,
+    /// Hash of the serialized `TransactionMessage`, if the transaction was created with
+    /// only the hash of its message. The message is then revealed at execution.
+    pub message_hash: Option<[u8; 32]>,
 }
 
 impl Transaction {
-    pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
-        let transaction_message: SmartAccountTransactionMessage =
-            TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
-        let message_size = get_instance_packed_len(&transaction_message).unwrap_or_default();
-
-        Ok(
-            8 +   // anchor account discriminator
-            32 +  // settings
-            32 +  // creator
-            32 +  // rent_collector
-            8 +   // index
-            1 +   // bump
-            1 +   // account_index
-            1 +   // account_bump
-            (4 + usize::from(ephemeral_signers_length)) +   // ephemeral_signers_bumps vec
-            message_size, // message
-        )
+    pub fn size(
+        ephemeral_signers_length: u8,
+        transaction_message: &[u8],
+        compress_message: bool,
+        message_hash: Option<[u8; 32]>,
+    ) -> Result<usize> {
+        let (message_size, compressed_message_size) = if message_hash.is_some() {
+            (
+                3 + 4 + 4 + 4, // empty message
+                0,
+            )
+        } else if compress_message {
+            let transaction_message: SmartAccountTransactionMessage =
+                TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
+
+            (
+                3 + 4 + 4 + 4, // empty message
+                CompressedTransactionMessage::compress(&transaction_message).len(),
+            )
+        } else {
+            let transaction_message: SmartAccountTransactionMessage =
+                TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
+            (
+                get_instance_packed_len(&transaction_message).unwrap_or_default(),
+                0,
+            )
+        };
+
+        Ok(Self::size_without_message(ephemeral_signers_length)
+            + message_size
+            + compressed_message_size)
+    }
+
+    /// Size of a `Transaction` without its message, i.e. without the `message` and
+    /// the `compressed_message` bytes.
+    pub const fn size_without_message(ephemeral_signers_length: u8) -> usize {
+        let extension_size = AccountExtension::<TransactionExtension>::size(
+            4 + // compressed_message vec length
+            (1 + 32), // message_hash
+        );
+
+        8 +   // anchor account discriminator
+        32 +  // settings
+        32 +  // creator
+        32 +  // rent_collector
+        8 +   // index
+        1 +   // bump
+        1 +   // account_index
+        1 +   // account_bump
+        (4 + ephemeral_signers_length as usize) +   // ephemeral_signers_bumps vec
+        extension_size // extension
+    }
+
+    /// Moves the message out of the Transaction, decompressing it if it's compressed.
+    /// If the Transaction only stores the hash of its message, the message must be
+    /// revealed as `revealed_message`, the serialized `TransactionMessage` with that hash.
+    pub fn take_message(
+        &mut self,
+        revealed_message: Option<&[u8]>,
+    ) -> Result<SmartAccountTransactionMessage> {
+        match (self.extension.message_hash, revealed_message) {
+            (Some(message_hash), Some(revealed_message)) => {
+                require!(
+                    hash(revealed_message).to_bytes() == message_hash,
+                    SmartAccountError::TransactionMessageHashMismatch
+                );
+                TransactionMessage::deserialize(&mut &revealed_message[..])?.try_into()
+            }
+            (None, None) if self.extension.compressed_message.is_empty() => {
+                Ok(core::mem::take(&mut self.message))
+            }
+            (None, None) => CompressedTransactionMessage::decompress(&core::mem::take(
+                &mut self.extension.compressed_message,
+            )),
+            (None, Some(_)) => err!(SmartAccountError::TransactionMessageNotHashCommitted),
+            (Some(_), None) => err!(SmartAccountError::InvalidInstructionArgs),
+        }
     }
     /// Reduces the Transaction to its default empty value and moves
     /// ownership of the data to the caller/return value.
@@ -242,3 +317,131 @@ impl From<MessageAddressTableLookup> for SmartAccountMessageAddressTableLookup {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    fn transaction_message() -> Vec<u8> {
+        TransactionMessage {
+            num_signers: 1,
+            num_writable_signers: 1,
+            num_writable_non_signers: 1,
+            account_keys: vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ]
+            .into(),
+            instructions: vec![CompiledInstruction {
+                program_id_index: 2,
+                account_indexes: vec![0, 1].into(),
+                data: vec![2, 0, 0, 0, 64, 66, 15, 0, 0, 0, 0, 0].into(),
+            }]
+            .into(),
+            address_table_lookups: vec![].into(),
+        }
+        .try_to_vec()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_take_hash_committed_message() {
+        let message = transaction_message();
+        let transaction = Transaction {
+            extension: AccountExtension::new(TransactionExtension {
+                message_hash: Some(hash(&message).to_bytes()),
+                ..TransactionExtension::default()
+            }),
+            ..Transaction::default()
+        };
+
+        // The message must be revealed, and match the hash.
+        assert!(transaction.clone().take_message(None).is_err());
+        let mut other_message = message.clone();
+        other_message[3] ^= 1;
+        assert!(transaction
+            .clone()
+            .take_message(Some(&other_message))
+            .is_err());
+
+        let revealed = transaction.clone().take_message(Some(&message)).unwrap();
+        assert_eq!(revealed.account_keys.len(), 3);
+        assert_eq!(revealed.instructions[0].account_indexes, vec![0, 1]);
+
+        // Messages stored on-chain can't be revealed.
+        let mut transaction = Transaction {
+            extension: AccountExtension::new(TransactionExtension {
+                compressed_message: CompressedTransactionMessage::compress(&revealed),
+                ..TransactionExtension::default()
+            }),
+            ..Transaction::default()
+        };
+        assert!(transaction.clone().take_message(Some(&message)).is_err());
+        assert_eq!(
+            transaction
+                .take_message(None)
+                .unwrap()
+                .try_to_vec()
+                .unwrap(),
+            revealed.try_to_vec().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_transaction_created_before_extension() {
+        let message: SmartAccountTransactionMessage =
+            TransactionMessage::deserialize(&mut transaction_message().as_slice())
+                .unwrap()
+                .try_into()
+                .unwrap();
+        // Transactions created before `TransactionExtension` have the exact size of their layout.
+        #[derive(AnchorSerialize)]
+        struct LegacyTransaction {
+            settings: Pubkey,
+            creator: Pubkey,
+            rent_collector: Pubkey,
+            index: u64,
+            bump: u8,
+            account_index: u8,
+            account_bump: u8,
+            ephemeral_signer_bumps: Vec<u8>,
+            message: SmartAccountTransactionMessage,
+        }
+        let legacy_transaction = LegacyTransaction {
+            settings: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            rent_collector: Pubkey::new_unique(),
+            index: 1,
+            bump: 255,
+            account_index: 0,
+            account_bump: 255,
+            ephemeral_signer_bumps: vec![],
+            message: message.clone(),
+        };
+        let mut data = Transaction::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&legacy_transaction.try_to_vec().unwrap());
+
+        let transaction = Transaction::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(transaction.index, 1);
+        assert!(!transaction.extension.is_stored());
+        assert_eq!(transaction.try_to_vec().unwrap().len() + 8, data.len());
+        // The stored message is executed as is, and can't be revealed.
+        assert!(matches!(
+            transaction.clone().take_message(Some(&transaction_message())),
+            Err(error) if error == SmartAccountError::TransactionMessageNotHashCommitted.into()
+        ));
+        assert_eq!(
+            transaction
+                .clone()
+                .take_message(None)
+                .unwrap()
+                .try_to_vec()
+                .unwrap(),
+            message.try_to_vec().unwrap()
+        );
+    }
+}
@@ -1,10 +1,34 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::SmartAccountError;
+use crate::state::Transaction;
+use crate::utils::AccountExtension;
 
+/// Maximum size of the buffer a `TransactionBuffer` account is created with.
+/// Larger buffers grow as they're extended.
 pub const MAX_BUFFER_SIZE: usize = 4000;
 
+/// Upper bound of how much larger a `TransactionMessage` gets once stored in a `Transaction`
+/// as a `SmartAccountTransactionMessage`, whose vectors have `u32` lengths instead of the `u8`
+/// lengths (`u16` for instruction data) of `SmallVec`: 3 bytes for each of the 3 top-level
+/// vectors, 5 for each instruction and 6 for each address table lookup, of which there are
+/// at most `u8::MAX` each. Compressed messages expand less.
+pub const MAX_MESSAGE_EXPANSION: usize = 3 * 3 + 5 * u8::MAX as usize + 6 * u8::MAX as usize;
+
+/// Maximum size of the final assembled transaction message.
+/// The transaction account created from the buffer is created and grown within a single
+/// instruction, so it can't exceed `MAX_PERMITTED_DATA_INCREASE` (10 KiB). Derived from the
+/// worst case, so that the message of any complete buffer fits it.
+pub const MAX_FINAL_BUFFER_SIZE: usize = MAX_PERMITTED_DATA_INCREASE
+    - Transaction::size_without_message(u8::MAX)
+    - MAX_MESSAGE_EXPANSION;
+
+/// Size of the chunks a buffer can be written in at any offset.
+/// The last chunk of a buffer can be shorter.
+pub const BUFFER_CHUNK_SIZE: usize = 512;
+
 #[account]
 #[derive(Default, Debug)]
 pub struct TransactionBuffer {
@@ -22,12 +46,35 @@ pub struct TransactionBuffer {
     pub final_buffer_size: u16,
     /// The buffer of the transaction message.
     pub buffer: Vec<u8>,
+    /// Bitmap of the `BUFFER_CHUNK_SIZE` chunks of the buffer that have been written,
+    /// the lowest bit of the first byte being the first chunk.
+    /// Not stored by buffers created before chunk writes, which were only appended to
+    /// until their first chunk write.
+    pub written_chunks: AccountExtension<Vec<u8>>,
 }
 
 impl TransactionBuffer {
     pub fn size(final_message_buffer_size: u16) -> Result<usize> {
-        // Make sure final size is not greater than MAX_BUFFER_SIZE bytes.
-        if (final_message_buffer_size as usize) > MAX_BUFFER_SIZE {
+        Self::size_with_buffer(
+            final_message_buffer_size,
+            final_message_buffer_size as usize,
+        )
+    }
+
+    fn size_with_buffer(final_message_buffer_size: u16, buffer_length: usize) -> Result<usize> {
+        Ok(
+            Self::size_without_written_chunks(final_message_buffer_size, buffer_length)?
+                + Self::written_chunks_size(final_message_buffer_size),
+        )
+    }
+
+    /// Size of buffers created before chunk writes.
+    fn size_without_written_chunks(
+        final_message_buffer_size: u16,
+        buffer_length: usize,
+    ) -> Result<usize> {
+        // Make sure final size is not greater than MAX_FINAL_BUFFER_SIZE bytes.
+        if (final_message_buffer_size as usize) > MAX_FINAL_BUFFER_SIZE {
             return err!(SmartAccountError::FinalBufferSizeExceeded);
         }
         Ok(
@@ -39,10 +86,109 @@ impl TransactionBuffer {
             32 +  // transaction_message_hash
             2 +  // final_buffer_size
             4 + // vec length bytes
-            final_message_buffer_size as usize, // buffer
+            buffer_length, // buffer
         )
     }
 
+    fn written_chunks_size(final_message_buffer_size: u16) -> usize {
+        AccountExtension::<Vec<u8>>::size(
+            4 + // vec length bytes
+            Self::written_chunks_length(final_message_buffer_size),
+        )
+    }
+
+    /// Number of `BUFFER_CHUNK_SIZE` chunks of a buffer of `final_message_buffer_size`.
+    pub fn num_chunks(final_message_buffer_size: u16) -> usize {
+        (final_message_buffer_size as usize).div_ceil(BUFFER_CHUNK_SIZE)
+    }
+
+    /// Length of the `written_chunks` bitmap of a buffer of `final_message_buffer_size`.
+    pub fn written_chunks_length(final_message_buffer_size: u16) -> usize {
+        Self::num_chunks(final_message_buffer_size).div_ceil(8)
+    }
+
+    /// Size the account is created with, enough for the whole buffer
+    /// unless it's larger than `MAX_BUFFER_SIZE`.
+    pub fn initial_size(final_message_buffer_size: u16) -> Result<usize> {
+        require!(
+            final_message_buffer_size as usize <= MAX_FINAL_BUFFER_SIZE,
+            SmartAccountError::FinalBufferSizeExceeded
+        );
+        Self::size_with_buffer(
+            final_message_buffer_size,
+            (final_message_buffer_size as usize).min(MAX_BUFFER_SIZE),
+        )
+    }
+
+    /// Size the account needs to hold the current buffer.
+    pub fn current_size(&self) -> Result<usize> {
+        if self.written_chunks.is_stored() {
+            Self::size_with_buffer(self.final_buffer_size, self.buffer.len())
+        } else {
+            Self::size_without_written_chunks(self.final_buffer_size, self.buffer.len())
+        }
+    }
+
+    /// Marks the chunks that end within `start..=end` of the buffer as written,
+    /// `start..end` being the range that was just written.
+    /// Appends must be contiguous from the start of the buffer,
+    /// so that every chunk ending within the appended range is complete.
+    /// Buffers created before chunk writes don't track appends, as they're contiguous.
+    pub fn mark_written(&mut self, start: usize, end: usize) {
+        if !self.written_chunks.is_stored() {
+            return;
+        }
+        for chunk_index in 0..Self::num_chunks(self.final_buffer_size) {
+            let chunk_end =
+                ((chunk_index + 1) * BUFFER_CHUNK_SIZE).min(self.final_buffer_size as usize);
+            if chunk_end > start && chunk_end <= end {
+                self.written_chunks[chunk_index / 8] |= 1 << (chunk_index % 8);
+            }
+        }
+    }
+
+    /// Writes `chunk` at `offset`, which must be the start of a `BUFFER_CHUNK_SIZE` chunk,
+    /// the chunk being written whole.
+    /// The buffer is first filled up to its final size with zeroes, so that chunks can be
+    /// written in any order, and written again.
+    pub fn write_chunk(&mut self, offset: usize, chunk: &[u8]) -> Result<()> {
+        let final_buffer_size = self.final_buffer_size as usize;
+        require!(
+            offset % BUFFER_CHUNK_SIZE == 0 && offset < final_buffer_size,
+            SmartAccountError::InvalidBufferChunk
+        );
+        require_eq!(
+            chunk.len(),
+            BUFFER_CHUNK_SIZE.min(final_buffer_size - offset),
+            SmartAccountError::InvalidBufferChunk
+        );
+
+        // Buffers created before chunk writes start tracking them with what was appended so far.
+        if !self.written_chunks.is_stored() {
+            self.written_chunks =
+                AccountExtension::new(vec![0; Self::written_chunks_length(self.final_buffer_size)]);
+            self.mark_written(0, self.buffer.len());
+        }
+
+        self.buffer.resize(final_buffer_size, 0);
+        self.buffer[offset..offset + chunk.len()].copy_from_slice(chunk);
+        self.mark_written(offset, offset + chunk.len());
+        Ok(())
+    }
+
+    /// Buffers that don't track written chunks were only appended to, so they're complete
+    /// once `validate_size` passes.
+    pub fn validate_written(&self) -> Result<()> {
+        if !self.written_chunks.is_stored() {
+            return Ok(());
+        }
+        let all_written = (0..Self::num_chunks(self.final_buffer_size)).all(|chunk_index| {
+            self.written_chunks[chunk_index / 8] & (1 << (chunk_index % 8)) != 0
+        });
+        require!(all_written, SmartAccountError::TransactionBufferIncomplete);
+        Ok(())
+    }
+
     pub fn validate_hash(&self) -> Result<()> {
         let message_buffer_hash = hash(&self.buffer);
         require!(
@@ -62,7 +208,7 @@ impl TransactionBuffer {
 
     pub fn invariant(&self) -> Result<()> {
         require!(
-            self.final_buffer_size as usize <= MAX_BUFFER_SIZE,
+            self.final_buffer_size as usize <= MAX_FINAL_BUFFER_SIZE,
             SmartAccountError::FinalBufferSizeExceeded
         );
        
@@ -71,6 +217,226 @@ impl TransactionBuffer {
             SmartAccountError::FinalBufferSizeMismatch
         );
 
+        if self.written_chunks.is_stored() {
+            require_eq!(
+                self.written_chunks.len(),
+                Self::written_chunks_length(self.final_buffer_size),
+                SmartAccountError::FinalBufferSizeMismatch
+            );
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_buffers_larger_than_max_buffer_size_grow() {
+        // Buffers up to `MAX_BUFFER_SIZE` are allocated whole at creation.
+        assert_eq!(
+            TransactionBuffer::initial_size(1000).unwrap(),
+            TransactionBuffer::size(1000).unwrap()
+        );
+        // Larger ones start at `MAX_BUFFER_SIZE`.
+        assert_eq!(
+            TransactionBuffer::initial_size(MAX_FINAL_BUFFER_SIZE as u16).unwrap(),
+            TransactionBuffer::size_with_buffer(MAX_FINAL_BUFFER_SIZE as u16, MAX_BUFFER_SIZE)
+                .unwrap()
+        );
+        assert!(TransactionBuffer::initial_size(MAX_FINAL_BUFFER_SIZE as u16 + 1).is_err());
+
+        let mut transaction_buffer = TransactionBuffer {
+            final_buffer_size: MAX_FINAL_BUFFER_SIZE as u16,
+            buffer: vec![1; MAX_BUFFER_SIZE + 1],
+            written_chunks: AccountExtension::new(vec![
+                0;
+                TransactionBuffer::written_chunks_length(
+                    MAX_FINAL_BUFFER_SIZE as u16
+                )
+            ]),
+            ..TransactionBuffer::default()
+        };
+        transaction_buffer.invariant().unwrap();
+        assert_eq!(
+            transaction_buffer.current_size().unwrap(),
+            transaction_buffer.try_to_vec().unwrap().len() + 8
+        );
+
+        transaction_buffer.final_buffer_size = MAX_FINAL_BUFFER_SIZE as u16 + 1;
+        assert!(transaction_buffer.invariant().is_err());
+    }
+
+    #[test]
+    fn test_max_final_buffer_fits_transaction_at_max_instruction_count() {
+        use crate::instructions::{
+            CompiledInstruction, MessageAddressTableLookup, TransactionMessage,
+        };
+
+        // The most instructions and address table lookups a message of
+        // `MAX_FINAL_BUFFER_SIZE` bytes can hold, each as small as possible.
+        let mut instructions = vec![
+            CompiledInstruction {
+                program_id_index: 0,
+                account_indexes: vec![].into(),
+                data: vec![].into(),
+            };
+            u8::MAX as usize
+        ];
+        let mut message = TransactionMessage {
+            num_signers: 0,
+            num_writable_signers: 0,
+            num_writable_non_signers: 0,
+            account_keys: vec![Pubkey::new_unique()].into(),
+            instructions: instructions.clone().into(),
+            address_table_lookups: vec![].into(),
+        };
+        let lookup_size = 32 + 1 + 1;
+        let num_lookups =
+            (MAX_FINAL_BUFFER_SIZE - message.try_to_vec().unwrap().len()) / lookup_size;
+        message.address_table_lookups = vec![
+            MessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![].into(),
+                readonly_indexes: vec![].into(),
+            };
+            num_lookups.min(u8::MAX as usize)
+        ]
+        .into();
+        // Pad the message to `MAX_FINAL_BUFFER_SIZE` with instruction data.
+        let padding = MAX_FINAL_BUFFER_SIZE - message.try_to_vec().unwrap().len();
+        instructions[0].data = vec![1; padding].into();
+        message.instructions = instructions.into();
+        let message = message.try_to_vec().unwrap();
+        assert_eq!(message.len(), MAX_FINAL_BUFFER_SIZE);
+
+        for compress_message in [false, true] {
+            let transaction_size =
+                Transaction::size(u8::MAX, &message, compress_message, None).unwrap();
+
+            assert!(transaction_size <= MAX_PERMITTED_DATA_INCREASE);
+        }
+    }
+    #[test]
+    fn test_chunks_can_be_written_in_any_order() {
+        let final_buffer_size = 2 * BUFFER_CHUNK_SIZE + 10;
+        let mut transaction_buffer = TransactionBuffer {
+            final_buffer_size: final_buffer_size as u16,
+            written_chunks: AccountExtension::new(vec![
+                0;
+                TransactionBuffer::written_chunks_length(
+                    final_buffer_size as u16
+                )
+            ]),
+            ..TransactionBuffer::default()
+        };
+
+        // Chunks must start at a chunk boundary and be written whole.
+        assert!(transaction_buffer
+            .write_chunk(1, &[2; BUFFER_CHUNK_SIZE])
+            .is_err());
+        assert!(transaction_buffer.write_chunk(0, &[1; 10]).is_err());
+        assert!(transaction_buffer
+            .write_chunk(2 * BUFFER_CHUNK_SIZE, &[3; BUFFER_CHUNK_SIZE])
+            .is_err());
+        assert!(transaction_buffer
+            .write_chunk(final_buffer_size, &[])
+            .is_err());
+
+        // Last chunk first, then the first one, written twice.
+        transaction_buffer
+            .write_chunk(2 * BUFFER_CHUNK_SIZE, &[3; 10])
+            .unwrap();
+        transaction_buffer
+            .write_chunk(0, &[0; BUFFER_CHUNK_SIZE])
+            .unwrap();
+        transaction_buffer
+            .write_chunk(0, &[1; BUFFER_CHUNK_SIZE])
+            .unwrap();
+        transaction_buffer.invariant().unwrap();
+        transaction_buffer.validate_size().unwrap();
+        assert!(transaction_buffer.validate_written().is_err());
+
+        transaction_buffer
+            .write_chunk(BUFFER_CHUNK_SIZE, &[2; BUFFER_CHUNK_SIZE])
+            .unwrap();
+        transaction_buffer.validate_written().unwrap();
+        assert_eq!(
+            transaction_buffer.buffer,
+            [
+                vec![1; BUFFER_CHUNK_SIZE],
+                vec![2; BUFFER_CHUNK_SIZE],
+                vec![3; 10]
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_appends_mark_complete_chunks_written() {
+        let final_buffer_size = 2 * BUFFER_CHUNK_SIZE;
+        let mut transaction_buffer = TransactionBuffer {
+            final_buffer_size: final_buffer_size as u16,
+            buffer: vec![1; BUFFER_CHUNK_SIZE - 1],
+            written_chunks: AccountExtension::new(vec![
+                0;
+                TransactionBuffer::written_chunks_length(
+                    final_buffer_size as u16
+                )
+            ]),
+            ..TransactionBuffer::default()
+        };
+        transaction_buffer.mark_written(0, BUFFER_CHUNK_SIZE - 1);
+        assert_eq!(*transaction_buffer.written_chunks, vec![0]);
+
+        transaction_buffer.buffer.resize(final_buffer_size, 1);
+        transaction_buffer.mark_written(BUFFER_CHUNK_SIZE - 1, final_buffer_size);
+        assert_eq!(*transaction_buffer.written_chunks, vec![0b11]);
+        transaction_buffer.validate_written().unwrap();
+    }
+
+    #[test]
+    fn test_buffers_created_before_chunk_writes() {
+        let final_buffer_size = 2 * BUFFER_CHUNK_SIZE + 10;
+        let mut data = TransactionBuffer::DISCRIMINATOR.to_vec();
+        // Layout without `written_chunks`, appended to up to the middle of the second chunk.
+        data.extend_from_slice(&[0; 32 + 32 + 1 + 1 + 32]);
+        data.extend_from_slice(&(final_buffer_size as u16).to_le_bytes());
+        data.extend_from_slice(&(BUFFER_CHUNK_SIZE as u32 + 1).to_le_bytes());
+        data.extend_from_slice(&[1; BUFFER_CHUNK_SIZE + 1]);
+
+        let mut transaction_buffer =
+            TransactionBuffer::try_deserialize(&mut data.as_slice()).unwrap();
+        assert!(!transaction_buffer.written_chunks.is_stored());
+        transaction_buffer.invariant().unwrap();
+        assert_eq!(transaction_buffer.current_size().unwrap(), data.len());
+
+        // Appends are contiguous, so they only need the size to be checked.
+        transaction_buffer.buffer.resize(final_buffer_size, 2);
+        transaction_buffer.mark_written(BUFFER_CHUNK_SIZE + 1, final_buffer_size);
+        transaction_buffer.validate_written().unwrap();
+        transaction_buffer.validate_size().unwrap();
+        assert_eq!(
+            transaction_buffer.try_to_vec().unwrap().len() + 8,
+            transaction_buffer.current_size().unwrap()
+        );
+
+        // Chunk writes start tracking what was appended before them.
+        transaction_buffer.buffer.truncate(BUFFER_CHUNK_SIZE + 1);
+        transaction_buffer
+            .write_chunk(2 * BUFFER_CHUNK_SIZE, &[3; 10])
+            .unwrap();
+        assert!(transaction_buffer.written_chunks.is_stored());
+        assert_eq!(*transaction_buffer.written_chunks, vec![0b101]);
+        assert!(transaction_buffer.validate_written().is_err());
+        transaction_buffer.invariant().unwrap();
+        assert_eq!(
+            transaction_buffer.try_to_vec().unwrap().len() + 8,
+            transaction_buffer.current_size().unwrap()
+        );
+    }
+}
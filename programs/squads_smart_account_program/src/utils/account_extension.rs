@@ -0,0 +1,147 @@
+use std::io::{Read, Write};
+use std::ops::{Deref, DerefMut};
+
+use anchor_lang::prelude::*;
+
+/// Fields appended to an account after its original layout was frozen.
+///
+/// Accounts created before hold none of these fields, usually without room for them.
+/// They read as `T::default()`, and are written back without them as long as they're left
+/// at their defaults. Otherwise the fields are stored after an `ACCOUNT_EXTENSION_VERSION` byte,
+/// so that accounts zero-padded past their original layout read as not holding them either.
+///
+/// Since its absence is only told apart by the end of the data, it must be the last field of
+/// the account, and the account the last field of any event embedding it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountExtension<T> {
+    fields: T,
+    /// Whether the account holds the fields.
+    stored: bool,
+}
+
+/// Version byte the fields of an `AccountExtension` are stored after.
+pub const ACCOUNT_EXTENSION_VERSION: u8 = 1;
+
+impl<T> AccountExtension<T> {
+    /// Extension of an account created with the current layout.
+    pub fn new(fields: T) -> Self {
+        Self {
+            fields,
+            stored: true,
+        }
+    }
+
+    /// Whether the account holds the fields, i.e. it wasn't created before they were introduced.
+    pub fn is_stored(&self) -> bool {
+        self.stored
+    }
+
+    /// Space taken in the account by fields of `fields_size` bytes.
+    pub const fn size(fields_size: usize) -> usize {
+        1 + // version
+        fields_size
+    }
+}
+
+impl<T> Deref for AccountExtension<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.fields
+    }
+}
+
+impl<T> DerefMut for AccountExtension<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.fields
+    }
+}
+
+impl<T: AnchorSerialize + Default + PartialEq> AnchorSerialize for AccountExtension<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Leave accounts created before the extension as they are, unless the fields were set.
+        if !self.stored && self.fields == T::default() {
+            return Ok(());
+        }
+        writer.write_all(&[ACCOUNT_EXTENSION_VERSION])?;
+        self.fields.serialize(writer)
+    }
+}
+
+impl<T: AnchorDeserialize + Default> AnchorDeserialize for AccountExtension<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut version = [0u8];
+        // Nothing left in the account, or zero padding.
+        if reader.read(&mut version)? == 0 || version[0] == 0 {
+            return Ok(Self::default());
+        }
+        if version[0] != ACCOUNT_EXTENSION_VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("unknown account extension version {}", version[0]),
+            ));
+        }
+        Ok(Self::new(T::deserialize_reader(reader)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+    struct Account {
+        original: u64,
+        extension: AccountExtension<Fields>,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+    struct Fields {
+        appended: u32,
+    }
+
+    #[test]
+    fn test_reads_accounts_created_before_the_extension() {
+        for data in [
+            7u64.to_le_bytes().to_vec(),
+            [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0].to_vec(),
+        ] {
+            let account = Account::deserialize(&mut data.as_slice()).unwrap();
+
+            assert_eq!(account.original, 7);
+            assert!(!account.extension.is_stored());
+            assert_eq!(account.extension.appended, 0);
+        }
+    }
+
+    #[test]
+    fn test_writes_back_unchanged_accounts_created_before_the_extension() {
+        let data = 7u64.to_le_bytes().to_vec();
+        let mut account = Account::deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(account.try_to_vec().unwrap(), data);
+
+        account.extension.appended = 3;
+        let data = account.try_to_vec().unwrap();
+        assert_eq!(data.len(), 8 + AccountExtension::<Fields>::size(4));
+        let account = Account::deserialize(&mut data.as_slice()).unwrap();
+        assert!(account.extension.is_stored());
+        assert_eq!(account.extension.appended, 3);
+    }
+
+    #[test]
+    fn test_round_trips_new_accounts() {
+        let account = Account {
+            original: 7,
+            extension: AccountExtension::new(Fields::default()),
+        };
+
+        let data = account.try_to_vec().unwrap();
+
+        assert_eq!(data.len(), 8 + AccountExtension::<Fields>::size(4));
+        assert_eq!(Account::try_from_slice(&data).unwrap(), account);
+        let mut unknown_version = data;
+        unknown_version[8] = ACCOUNT_EXTENSION_VERSION + 1;
+        assert!(Account::try_from_slice(&unknown_version).is_err());
+    }
+}
@@ -0,0 +1,450 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Ids;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::errors::*;
+use crate::state::{MintAmount, SmartAccountCompiledInstruction};
+
+/// Tags of the SPL Token and Token-2022 instructions that hand over control of a token account:
+/// `Approve`, `SetAuthority` and `ApproveChecked`.
+const TOKEN_AUTHORITY_INSTRUCTIONS: [u8; 3] = [4, 6, 13];
+
+/// Checks that `instructions`, indexing into `accounts`, only invoke `allowed_programs`,
+/// and don't hand over control of token accounts, which wouldn't show up as an outflow.
+pub fn check_spending_limit_instructions(
+    allowed_programs: &[Pubkey],
+    instructions: &[SmartAccountCompiledInstruction],
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    for instruction in instructions {
+        let program_id = accounts[instruction.program_id_index as usize].key;
+        require!(
+            allowed_programs.contains(program_id),
+            SmartAccountError::ProgramNotAllowed
+        );
+        if TokenInterface::ids().contains(program_id) {
+            require!(
+                !instruction
+                    .data
+                    .first()
+                    .is_some_and(|tag| TOKEN_AUTHORITY_INSTRUCTIONS.contains(tag)),
+                SmartAccountError::TokenAuthorityChange
+            );
+        }
+    }
+    Ok(())
+}
+
+/// State of a token account owned by the smart account, as far as asset custody is concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TokenAccountSnapshot {
+    key: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+    delegate: Option<Pubkey>,
+    close_authority: Option<Pubkey>,
+}
+
+impl TokenAccountSnapshot {
+    /// Reads a token account owned by `smart_account`.
+    /// Returns `None` for any other account.
+    fn read(account: &AccountInfo, smart_account: &Pubkey) -> Option<Self> {
+        if !TokenInterface::ids().contains(account.owner) {
+            return None;
+        }
+        let data = account.try_borrow_data().ok()?;
+        let token_account = TokenAccount::try_deserialize_unchecked(&mut &data[..]).ok()?;
+        if token_account.owner != *smart_account {
+            return None;
+        }
+
+        Some(Self {
+            key: *account.key,
+            mint: token_account.mint,
+            amount: token_account.amount,
+            delegate: token_account.delegate.into(),
+            close_authority: token_account.close_authority.into(),
+        })
+    }
+}
+
+/// Whether `account` is owned by the `smart_account` or stores its key, which is how programs
+/// record the authority over the assets they hold.
+fn is_under_authority(account: &AccountInfo, smart_account: &Pubkey) -> bool {
+    account.owner == smart_account
+        || account.try_borrow_data().map_or(true, |data| {
+            data.windows(32)
+                .any(|window| window == smart_account.as_ref())
+        })
+}
+
+/// Snapshot of the assets a smart account holds in a set of accounts, used to measure
+/// what a transaction executed on behalf of the smart account has moved out of it.
+///
+/// Tracks the lamports of the smart account itself and the balances of the writable
+/// SPL Token and Token-2022 accounts owned by it.
+pub struct SmartAccountAssetBalances {
+    smart_account: Pubkey,
+    lamports: u64,
+    /// Owner and data length of the smart account, which must be left as they are.
+    owner: Pubkey,
+    data_len: usize,
+    token_accounts: Vec<TokenAccountSnapshot>,
+}
+
+impl SmartAccountAssetBalances {
+    /// Fails if any other writable account is owned by the smart account or references it,
+    /// such as stake accounts it is the authority of, or token accounts delegated to it,
+    /// as the assets they hold couldn't be measured.
+    pub fn snapshot(smart_account: &AccountInfo, accounts: &[AccountInfo]) -> Result<Self> {
+        let mut token_accounts: Vec<TokenAccountSnapshot> = Vec::new();
+        for account in accounts.iter().filter(|account| account.is_writable) {
+            if account.key == smart_account.key
+                || token_accounts
+                    .iter()
+                    .any(|snapshot| snapshot.key == *account.key)
+            {
+                continue;
+            }
+            if let Some(snapshot) = TokenAccountSnapshot::read(account, smart_account.key) {
+                token_accounts.push(snapshot);
+                continue;
+            }
+            require!(
+                !is_under_authority(account, smart_account.key),
+                SmartAccountError::UntrackedSmartAccountAsset
+            );
+        }
+
+        Ok(Self {
+            smart_account: *smart_account.key,
+            lamports: smart_account.lamports(),
+            owner: *smart_account.owner,
+            data_len: smart_account.data_len(),
+            token_accounts,
+        })
+    }
+
+    /// Returns the net outflow of every asset that has left the smart account since the
+    /// snapshot was taken, by mint, `Pubkey::default()` standing for SOL held directly by the
+    /// smart account.
+    ///
+    /// Fails if the smart account was assigned or allocated, or if a tracked token account has
+    /// changed hands, had a delegate or close authority set, or was closed. Inflows are not
+    /// credited against outflows of other token accounts of the same mint.
+    pub fn outflows(
+        &self,
+        smart_account: &AccountInfo,
+        accounts: &[AccountInfo],
+    ) -> Result<Vec<MintAmount>> {
+        require_keys_eq!(
+            *smart_account.key,
+            self.smart_account,
+            SmartAccountError::InvalidAccount
+        );
+        require!(
+            *smart_account.owner == self.owner && smart_account.data_len() == self.data_len,
+            SmartAccountError::SmartAccountModified
+        );
+
+        let mut outflows: Vec<MintAmount> = Vec::new();
+        let mut add_outflow = |mint: Pubkey, amount: u64| {
+            if amount == 0 {
+                return;
+            }
+            match outflows.iter_mut().find(|outflow| outflow.mint == mint) {
+                Some(outflow) => outflow.amount = outflow.amount.checked_add(amount).unwrap(),
+                None => outflows.push(MintAmount { mint, amount }),
+            }
+        };
+
+        add_outflow(
+            Pubkey::default(),
+            self.lamports.saturating_sub(smart_account.lamports()),
+        );
+
+        for before in &self.token_accounts {
+            let after = accounts
+                .iter()
+                .find(|account| account.key == &before.key)
+                .and_then(|account| TokenAccountSnapshot::read(account, &self.smart_account))
+                .ok_or(SmartAccountError::SpendingLimitInvalidOutflow)?;
+
+            require!(
+                after.mint == before.mint
+                    && after.delegate == before.delegate
+                    && after.close_authority == before.close_authority,
+                SmartAccountError::SpendingLimitInvalidOutflow
+            );
+
+            add_outflow(before.mint, before.amount.saturating_sub(after.amount));
+        }
+
+        Ok(outflows)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+    use anchor_spl::token::spl_token::state::{Account, AccountState};
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn system(key: Pubkey, lamports: u64) -> Self {
+            Self {
+                key,
+                owner: Pubkey::default(),
+                lamports,
+                data: vec![],
+            }
+        }
+
+        fn token(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+            let mut data = vec![0; Account::LEN];
+            Account {
+                mint,
+                owner,
+                amount,
+                state: AccountState::Initialized,
+                ..Account::default()
+            }
+            .pack_into_slice(&mut data);
+            Self {
+                key,
+                owner: spl_token::ID,
+                lamports: 0,
+                data,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                false,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn set_token_account(account: &AccountInfo, update: impl FnOnce(&mut Account)) {
+        let mut data = account.try_borrow_mut_data().unwrap();
+        let mut token_account = Account::unpack(&data).unwrap();
+        update(&mut token_account);
+        token_account.pack_into_slice(&mut data);
+    }
+
+    #[test]
+    fn test_measures_outflows_by_mint() {
+        let smart_account_key = Pubkey::new_unique();
+        let usdc = Pubkey::new_unique();
+        let mut smart_account = TestAccount::system(smart_account_key, 1_000);
+        let mut usdc_account =
+            TestAccount::token(Pubkey::new_unique(), usdc, smart_account_key, 100);
+        let mut foreign_account =
+            TestAccount::token(Pubkey::new_unique(), usdc, Pubkey::new_unique(), 100);
+
+        let smart_account = smart_account.info();
+        let accounts = vec![usdc_account.info(), foreign_account.info()];
+        let balances = SmartAccountAssetBalances::snapshot(&smart_account, &accounts).unwrap();
+
+        set_token_account(&accounts[0], |account| account.amount = 60);
+        // Accounts not owned by the smart account are not tracked.
+        set_token_account(&accounts[1], |account| account.amount = 0);
+
+        assert_eq!(
+            balances.outflows(&smart_account, &accounts).unwrap(),
+            vec![MintAmount {
+                mint: usdc,
+                amount: 40
+            }]
+        );
+
+        **smart_account.try_borrow_mut_lamports().unwrap() = 900;
+        assert_eq!(
+            balances.outflows(&smart_account, &accounts).unwrap(),
+            vec![
+                MintAmount {
+                    mint: Pubkey::default(),
+                    amount: 100
+                },
+                MintAmount {
+                    mint: usdc,
+                    amount: 40
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_measures_outflow_of_sol() {
+        let smart_account_key = Pubkey::new_unique();
+        let mut smart_account = TestAccount::system(smart_account_key, 1_000);
+        let mut usdc_account = TestAccount::token(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            smart_account_key,
+            100,
+        );
+
+        let smart_account = smart_account.info();
+        let accounts = vec![usdc_account.info()];
+        let balances = SmartAccountAssetBalances::snapshot(&smart_account, &accounts).unwrap();
+
+        **smart_account.try_borrow_mut_lamports().unwrap() = 750;
+        // Inflows of other assets are fine.
+        set_token_account(&accounts[0], |account| account.amount = 150);
+
+        assert_eq!(
+            balances.outflows(&smart_account, &accounts).unwrap(),
+            vec![MintAmount {
+                mint: Pubkey::default(),
+                amount: 250
+            }]
+        );
+    }
+
+    #[test]
+    fn test_rejects_custody_changes() {
+        let smart_account_key = Pubkey::new_unique();
+        let usdc = Pubkey::new_unique();
+        let mut smart_account = TestAccount::system(smart_account_key, 1_000);
+        let mut usdc_account =
+            TestAccount::token(Pubkey::new_unique(), usdc, smart_account_key, 100);
+
+        let smart_account = smart_account.info();
+        let accounts = vec![usdc_account.info()];
+        let balances = SmartAccountAssetBalances::snapshot(&smart_account, &accounts).unwrap();
+
+        set_token_account(&accounts[0], |account| {
+            account.delegate = COption::Some(Pubkey::new_unique());
+            account.delegated_amount = 100;
+        });
+        assert!(balances.outflows(&smart_account, &accounts).is_err());
+
+        set_token_account(&accounts[0], |account| {
+            account.delegate = COption::None;
+            account.owner = Pubkey::new_unique();
+        });
+        assert!(balances.outflows(&smart_account, &accounts).is_err());
+    }
+
+    #[test]
+    fn test_rejects_changes_of_the_smart_account() {
+        let smart_account_key = Pubkey::new_unique();
+        let mut smart_account = TestAccount::system(smart_account_key, 1_000);
+        let mut allocated = TestAccount::system(smart_account_key, 1_000);
+        allocated.data = vec![0; 8];
+        let mut assigned = TestAccount::system(smart_account_key, 1_000);
+        assigned.owner = Pubkey::new_unique();
+
+        let smart_account = smart_account.info();
+        let balances = SmartAccountAssetBalances::snapshot(&smart_account, &[]).unwrap();
+
+        assert!(balances.outflows(&smart_account, &[]).is_ok());
+        assert!(balances.outflows(&allocated.info(), &[]).is_err());
+        assert!(balances.outflows(&assigned.info(), &[]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_untracked_accounts_under_authority() {
+        let smart_account_key = Pubkey::new_unique();
+        let mut smart_account = TestAccount::system(smart_account_key, 1_000);
+        let smart_account = smart_account.info();
+
+        // A stake account or anything else the smart account is the authority of.
+        let mut stake_account = TestAccount::system(Pubkey::new_unique(), 1_000);
+        stake_account.owner = Pubkey::new_unique();
+        stake_account.data = [[1; 12].as_ref(), smart_account_key.as_ref()].concat();
+        // A token account of someone else delegated to the smart account.
+        let mut delegated_account = TestAccount::token(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+        );
+        set_token_account(&delegated_account.info(), |account| {
+            account.delegate = COption::Some(smart_account_key);
+        });
+        // An account owned by the smart account.
+        let mut owned_account = TestAccount::system(Pubkey::new_unique(), 1_000);
+        owned_account.owner = smart_account_key;
+        let mut unrelated_account = TestAccount::system(Pubkey::new_unique(), 1_000);
+        unrelated_account.data = vec![1; 64];
+
+        for account in [
+            &mut stake_account,
+            &mut delegated_account,
+            &mut owned_account,
+        ] {
+            assert_eq!(
+                SmartAccountAssetBalances::snapshot(&smart_account, &[account.info()]).err(),
+                Some(SmartAccountError::UntrackedSmartAccountAsset.into())
+            );
+        }
+
+        // Unrelated accounts can be written to, and any account can be read.
+        SmartAccountAssetBalances::snapshot(&smart_account, &[unrelated_account.info()]).unwrap();
+        let mut read_only = stake_account.info();
+        read_only.is_writable = false;
+        SmartAccountAssetBalances::snapshot(&smart_account, &[read_only]).unwrap();
+    }
+
+    #[test]
+    fn test_checks_spending_limit_instructions() {
+        let allowed_program = Pubkey::new_unique();
+        let mut accounts = [
+            TestAccount::system(allowed_program, 0),
+            TestAccount::system(spl_token::ID, 0),
+            TestAccount::system(Pubkey::new_unique(), 0),
+        ];
+        let accounts: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let instruction = |program_id_index: u8, data: Vec<u8>| SmartAccountCompiledInstruction {
+            program_id_index,
+            account_indexes: vec![],
+            data,
+        };
+
+        check_spending_limit_instructions(
+            &[allowed_program, spl_token::ID],
+            &[instruction(0, vec![4]), instruction(1, vec![3])],
+            &accounts,
+        )
+        .unwrap();
+
+        // Programs must be allowed, even if they only read accounts.
+        assert!(check_spending_limit_instructions(
+            &[allowed_program],
+            &[instruction(2, vec![])],
+            &accounts
+        )
+        .is_err());
+        assert!(
+            check_spending_limit_instructions(&[], &[instruction(0, vec![])], &accounts).is_err()
+        );
+
+        // Delegating or handing over token accounts is rejected.
+        for tag in TOKEN_AUTHORITY_INSTRUCTIONS {
+            assert!(check_spending_limit_instructions(
+                &[spl_token::ID],
+                &[instruction(1, vec![tag])],
+                &accounts
+            )
+            .is_err());
+        }
+    }
+}
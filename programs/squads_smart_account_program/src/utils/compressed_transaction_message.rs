@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{
+    SmartAccountCompiledInstruction, SmartAccountMessageAddressTableLookup,
+    SmartAccountTransactionMessage,
+};
+
+/// Compact encoding of a `SmartAccountTransactionMessage`, used to save rent on `Transaction`
+/// accounts holding large messages.
+///
+/// Compared to the Borsh encoding:
+/// - vec lengths and `program_id_index` are LEB128 varints instead of `u32`s and `u8`s;
+/// - instruction data and account index lists that are repeated across instructions
+///   are only stored once, later occurrences referencing the first one.
+///
+/// ```plaintext
+/// num_signers: u8, num_writable_signers: u8, num_writable_non_signers: u8
+/// varint(account_keys.len()), account_keys
+/// varint(instructions.len()), for each instruction:
+///     varint(program_id_index), blob(account_indexes), blob(data)
+/// varint(address_table_lookups.len()), for each lookup:
+///     account_key, varint(writable_indexes.len()), writable_indexes,
+///     varint(readonly_indexes.len()), readonly_indexes
+/// ```
+///
+/// A `blob` is either `varint(len << 1)` followed by `len` bytes,
+/// or `varint(index << 1 | 1)` referencing the `index`th blob stored inline before it.
+pub struct CompressedTransactionMessage;
+
+impl CompressedTransactionMessage {
+    pub fn compress(message: &SmartAccountTransactionMessage) -> Vec<u8> {
+        let mut out = vec![
+            message.num_signers,
+            message.num_writable_signers,
+            message.num_writable_non_signers,
+        ];
+
+        write_varint(&mut out, message.account_keys.len());
+        for account_key in &message.account_keys {
+            out.extend_from_slice(account_key.as_ref());
+        }
+
+        let mut blobs: Vec<&[u8]> = vec![];
+        write_varint(&mut out, message.instructions.len());
+        for instruction in &message.instructions {
+            write_varint(&mut out, usize::from(instruction.program_id_index));
+            write_blob(&mut out, &mut blobs, &instruction.account_indexes);
+            write_blob(&mut out, &mut blobs, &instruction.data);
+        }
+
+        write_varint(&mut out, message.address_table_lookups.len());
+        for lookup in &message.address_table_lookups {
+            out.extend_from_slice(lookup.account_key.as_ref());
+            write_varint(&mut out, lookup.writable_indexes.len());
+            out.extend_from_slice(&lookup.writable_indexes);
+            write_varint(&mut out, lookup.readonly_indexes.len());
+            out.extend_from_slice(&lookup.readonly_indexes);
+        }
+
+        out
+    }
+
+    pub fn decompress(data: &[u8]) -> Result<SmartAccountTransactionMessage> {
+        let mut input = data;
+
+        let header = read_bytes(&mut input, 3)?;
+        let (num_signers, num_writable_signers, num_writable_non_signers) =
+            (header[0], header[1], header[2]);
+
+        let num_account_keys = read_varint(&mut input)?;
+        let account_keys = (0..num_account_keys)
+            .map(|_| read_pubkey(&mut input))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut blobs: Vec<&[u8]> = vec![];
+        let num_instructions = read_varint(&mut input)?;
+        let mut instructions = Vec::with_capacity(num_instructions);
+        for _ in 0..num_instructions {
+            let program_id_index = u8::try_from(read_varint(&mut input)?)
+                .map_err(|_| SmartAccountError::InvalidTransactionMessage)?;
+            let account_indexes = read_blob(&mut input, &mut blobs)?.to_vec();
+            let data = read_blob(&mut input, &mut blobs)?.to_vec();
+            instructions.push(SmartAccountCompiledInstruction {
+                program_id_index,
+                account_indexes,
+                data,
+            });
+        }
+
+        let num_lookups = read_varint(&mut input)?;
+        let mut address_table_lookups = Vec::with_capacity(num_lookups);
+        for _ in 0..num_lookups {
+            let account_key = read_pubkey(&mut input)?;
+            let num_writable_indexes = read_varint(&mut input)?;
+            let writable_indexes = read_bytes(&mut input, num_writable_indexes)?.to_vec();
+            let num_readonly_indexes = read_varint(&mut input)?;
+            let readonly_indexes = read_bytes(&mut input, num_readonly_indexes)?.to_vec();
+            address_table_lookups.push(SmartAccountMessageAddressTableLookup {
+                account_key,
+                writable_indexes,
+                readonly_indexes,
+            });
+        }
+
+        // The whole input must be consumed.
+        require!(
+            input.is_empty(),
+            SmartAccountError::InvalidTransactionMessage
+        );
+
+        Ok(SmartAccountTransactionMessage {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+            address_table_lookups,
+        })
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Writes `blob` inline, unless it's equal to one of the `blobs` already written inline.
+fn write_blob<'a>(out: &mut Vec<u8>, blobs: &mut Vec<&'a [u8]>, blob: &'a [u8]) {
+    match blobs.iter().position(|written| *written == blob) {
+        Some(index) => write_varint(out, index << 1 | 1),
+        None => {
+            write_varint(out, blob.len() << 1);
+            out.extend_from_slice(blob);
+            blobs.push(blob);
+        }
+    }
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<usize> {
+    let mut value: usize = 0;
+    // `u32` values at most, which take up to 5 bytes.
+    for shift in (0..35).step_by(7) {
+        let byte = read_bytes(input, 1)?[0];
+        value |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return u32::try_from(value)
+                .map(|_| value)
+                .map_err(|_| SmartAccountError::InvalidTransactionMessage.into());
+        }
+    }
+    err!(SmartAccountError::InvalidTransactionMessage)
+}
+
+fn read_blob<'a>(input: &mut &'a [u8], blobs: &mut Vec<&'a [u8]>) -> Result<&'a [u8]> {
+    let tag = read_varint(input)?;
+    if tag & 1 == 1 {
+        return blobs
+            .get(tag >> 1)
+            .copied()
+            .ok_or(SmartAccountError::InvalidTransactionMessage.into());
+    }
+    let blob = read_bytes(input, tag >> 1)?;
+    blobs.push(blob);
+    Ok(blob)
+}
+
+fn read_bytes<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    require!(
+        input.len() >= len,
+        SmartAccountError::InvalidTransactionMessage
+    );
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+fn read_pubkey(input: &mut &[u8]) -> Result<Pubkey> {
+    let bytes: [u8; 32] = read_bytes(input, 32)?.try_into().unwrap();
+    Ok(Pubkey::new_from_array(bytes))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn token_transfers(count: u8, amount: u64) -> SmartAccountTransactionMessage {
+        // [smart_account, source, destinations.., token_program]
+        let mut account_keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        account_keys.extend((0..count).map(|_| Pubkey::new_unique()));
+        account_keys.push(anchor_spl::token::ID);
+
+        let mut data = vec![3]; // `Transfer`
+        data.extend_from_slice(&amount.to_le_bytes());
+        let instructions = (0..count)
+            .map(|index| SmartAccountCompiledInstruction {
+                program_id_index: count + 2,
+                account_indexes: vec![1, index + 2, 0],
+                data: data.clone(),
+            })
+            .collect();
+
+        SmartAccountTransactionMessage {
+            num_signers: 1,
+            num_writable_signers: 1,
+            num_writable_non_signers: count + 1,
+            account_keys,
+            instructions,
+            address_table_lookups: vec![SmartAccountMessageAddressTableLookup {
+                account_key: Pubkey::new_unique(),
+                writable_indexes: vec![0, 200],
+                readonly_indexes: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut message = token_transfers(4, 1_000_000);
+        message.instructions.push(SmartAccountCompiledInstruction {
+            program_id_index: 5,
+            account_indexes: vec![],
+            data: vec![7; 300],
+        });
+
+        let compressed = CompressedTransactionMessage::compress(&message);
+        let decompressed = CompressedTransactionMessage::decompress(&compressed).unwrap();
+        assert_eq!(
+            decompressed.try_to_vec().unwrap(),
+            message.try_to_vec().unwrap()
+        );
+
+        // Truncated and padded inputs are rejected.
+        assert!(
+            CompressedTransactionMessage::decompress(&compressed[..compressed.len() - 1]).is_err()
+        );
+        assert!(
+            CompressedTransactionMessage::decompress(&[&compressed[..], &[0]].concat()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_varints() {
+        for value in [0, 1, 0x7f, 0x80, 0x3fff, 0x4000, u32::MAX as usize] {
+            let mut out = vec![];
+            write_varint(&mut out, value);
+            assert_eq!(read_varint(&mut out.as_slice()).unwrap(), value);
+        }
+        // Only `u32` values are accepted.
+        assert!(read_varint(&mut [0xff, 0xff, 0xff, 0xff, 0x7f].as_slice()).is_err());
+        assert!(read_varint(&mut [0x80, 0x80, 0x80, 0x80, 0x80, 0x00].as_slice()).is_err());
+        // References must point to blobs stored before them.
+        assert!(read_blob(&mut [0x01].as_slice(), &mut vec![]).is_err());
+    }
+
+    /// Rent saved by compressing messages of token transfers, the instruction data of which
+    /// is deduplicated.
+    #[test]
+    fn test_rent_saved() {
+        let rent = Rent::default();
+        for (count, raw_size, compressed_size) in [(1, 206, 185), (8, 577, 451), (20, 1213, 907)] {
+            let message = token_transfers(count, 1_000_000);
+            let raw = message.try_to_vec().unwrap();
+            let compressed = CompressedTransactionMessage::compress(&message);
+            assert_eq!(raw.len(), raw_size);
+            assert_eq!(compressed.len(), compressed_size);
+
+            let rent_saved =
+                rent.minimum_balance(raw.len()) - rent.minimum_balance(compressed.len());
+            assert_eq!(
+                rent_saved,
+                (raw_size - compressed_size) as u64 * 6960 // lamports per byte
+            );
+        }
+    }
+}
@@ -6,11 +6,17 @@ pub fn validate_synchronous_consensus(
     num_signers: u8,
     remaining_accounts: &[AccountInfo],
 ) -> Result<()> {
-    // Settings must not be time locked
-    require_eq!(settings.time_lock, 0, SmartAccountError::TimeLockNotZero);
+    // Settings must not be time locked, unless the signers can bypass the time lock,
+    // in which case they have to reach the stricter bypass threshold.
+    let required_signer_count = if settings.time_lock == 0 {
+        settings.threshold as usize
+    } else {
+        settings
+            .time_lock_bypass_threshold()
+            .ok_or(SmartAccountError::TimeLockNotZero)?
+    };
 
     // Get signers from remaining accounts using threshold
-    let required_signer_count = settings.threshold as usize;
     let signer_count = num_signers as usize;
     require!(
         signer_count >= required_signer_count,
@@ -60,7 +66,7 @@ pub fn validate_synchronous_consensus(
 
     // Verify threshold is met across all voting permissions
     require!(
-        vote_permission_count >= settings.threshold as usize,
+        vote_permission_count >= required_signer_count,
         SmartAccountError::InsufficientVotePermissions
     );
 
@@ -81,7 +87,32 @@ pub fn validate_settings_actions(actions: &Vec<SettingsAction>) -> Result<()> {
             );
         }
         // Expiration must be greater than the current timestamp.
-        if let SettingsAction::AddSpendingLimit { expiration, .. } = action {
+        if let SettingsAction::AddSpendingLimit { expiration, .. }
+        | SettingsAction::AddSpendingLimitWithOptions { expiration, .. } = action
+        {
+            if *expiration != i64::MAX {
+                require!(
+                    *expiration > current_timestamp,
+                    SmartAccountError::SpendingLimitExpired
+                );
+            }
+        }
+        // The usage history must fit the spending limit account.
+        if let SettingsAction::AddSpendingLimitWithOptions { options, .. } = action {
+            require!(
+                options.usage_history_capacity <= MAX_USAGE_HISTORY_CAPACITY,
+                SmartAccountError::InvalidUsageHistoryCapacity
+            );
+        }
+        if let SettingsAction::UpdateSpendingLimit {
+            update:
+                SpendingLimitUpdate {
+                    expiration: Some(expiration),
+                    ..
+                },
+            ..
+        } = action
+        {
             if *expiration != i64::MAX {
                 require!(
                     *expiration > current_timestamp,
@@ -1,13 +1,25 @@
+mod account_extension;
+mod asset_balances;
+mod compressed_transaction_message;
 mod ephemeral_signers;
 mod executable_transaction_message;
+mod nft_metadata;
+mod price_feed;
 mod small_vec;
 mod system;
 mod synchronous_transaction_message;
+mod token_transfer;
 mod context_validation;
 
+pub use account_extension::*;
+pub use asset_balances::*;
+pub use compressed_transaction_message::*;
 pub use context_validation::*;
 pub use ephemeral_signers::*;
 pub use executable_transaction_message::*;
+pub use nft_metadata::*;
+pub use price_feed::*;
 pub use small_vec::*;
 pub use system::*;
-pub use synchronous_transaction_message::*;
\ No newline at end of file
+pub use synchronous_transaction_message::*;
+pub use token_transfer::*;
\ No newline at end of file
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+use crate::errors::*;
+use crate::state::NftGroup;
+
+/// The Metaplex Token Metadata program.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Account key of a Metaplex `MetadataV1` account.
+const METADATA_V1_KEY: u8 = 4;
+
+#[derive(AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NftCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NftCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// The leading fields of a Metaplex metadata account, up to the collection.
+#[derive(AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NftMetadata {
+    pub key: u8,
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<NftCreator>>,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<NftCollection>,
+}
+
+impl NftMetadata {
+    /// Reads the Metaplex metadata account of `mint`.
+    pub fn read(account: &AccountInfo, mint: &Pubkey) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            TOKEN_METADATA_PROGRAM_ID,
+            SmartAccountError::IllegalAccountOwner
+        );
+        let (metadata_key, _) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                mint.as_ref(),
+            ],
+            &TOKEN_METADATA_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            *account.key,
+            metadata_key,
+            SmartAccountError::InvalidAccount
+        );
+
+        let metadata = Self::deserialize(&mut &account.try_borrow_data()?[..])
+            .map_err(|_| SmartAccountError::InvalidAccount)?;
+        require!(
+            metadata.key == METADATA_V1_KEY && metadata.mint == *mint,
+            SmartAccountError::InvalidAccount
+        );
+
+        Ok(metadata)
+    }
+
+    /// Whether the NFT is a verified member of `group`.
+    pub fn belongs_to(&self, group: &NftGroup) -> bool {
+        match group {
+            NftGroup::Collection(collection) => self
+                .collection
+                .as_ref()
+                .is_some_and(|c| c.verified && c.key == *collection),
+            NftGroup::Creator(creator) => self
+                .creators
+                .iter()
+                .flatten()
+                .any(|c| c.verified && c.address == *creator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Serializes the metadata the way the Token Metadata program lays it out.
+    fn metadata_data(metadata: &NftMetadata) -> Vec<u8> {
+        let mut data = vec![metadata.key];
+        data.extend(metadata.update_authority.to_bytes());
+        data.extend(metadata.mint.to_bytes());
+        for string in [&metadata.name, &metadata.symbol, &metadata.uri] {
+            data.extend((string.len() as u32).to_le_bytes());
+            data.extend(string.as_bytes());
+        }
+        data.extend(metadata.seller_fee_basis_points.to_le_bytes());
+        match &metadata.creators {
+            None => data.push(0),
+            Some(creators) => {
+                data.push(1);
+                data.extend((creators.len() as u32).to_le_bytes());
+                for creator in creators {
+                    data.extend(creator.address.to_bytes());
+                    data.push(creator.verified.into());
+                    data.push(creator.share);
+                }
+            }
+        }
+        data.push(metadata.primary_sale_happened.into());
+        data.push(metadata.is_mutable.into());
+        data.extend([1, metadata.edition_nonce.unwrap()]);
+        data.extend([1, metadata.token_standard.unwrap()]);
+        match &metadata.collection {
+            None => data.push(0),
+            Some(collection) => {
+                data.push(1);
+                data.push(collection.verified.into());
+                data.extend(collection.key.to_bytes());
+            }
+        }
+        // Trailing fields and padding.
+        data.resize(679, 0);
+        data
+    }
+
+    fn metadata(mint: Pubkey, creator: Pubkey, collection: Pubkey) -> NftMetadata {
+        NftMetadata {
+            key: METADATA_V1_KEY,
+            update_authority: Pubkey::new_unique(),
+            mint,
+            name: "Ticket #1\0\0\0".to_string(),
+            symbol: "TIX".to_string(),
+            uri: "https://example.com/1.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: Some(vec![
+                NftCreator {
+                    address: Pubkey::new_unique(),
+                    verified: false,
+                    share: 0,
+                },
+                NftCreator {
+                    address: creator,
+                    verified: true,
+                    share: 100,
+                },
+            ]),
+            primary_sale_happened: true,
+            is_mutable: true,
+            edition_nonce: Some(255),
+            token_standard: Some(0),
+            collection: Some(NftCollection {
+                verified: true,
+                key: collection,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_reads_metadata() {
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let expected = metadata(mint, creator, collection);
+
+        let (key, _) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                mint.as_ref(),
+            ],
+            &TOKEN_METADATA_PROGRAM_ID,
+        );
+        let mut lamports = 0;
+        let mut data = metadata_data(&expected);
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &TOKEN_METADATA_PROGRAM_ID,
+            false,
+            0,
+        );
+
+        let metadata = NftMetadata::read(&account, &mint).unwrap();
+        assert_eq!(metadata, expected);
+        // The metadata account must be the one of the mint.
+        assert!(NftMetadata::read(&account, &Pubkey::new_unique()).is_err());
+
+        assert!(metadata.belongs_to(&NftGroup::Collection(collection)));
+        assert!(metadata.belongs_to(&NftGroup::Creator(creator)));
+        assert!(!metadata.belongs_to(&NftGroup::Collection(Pubkey::new_unique())));
+        // Unverified creators don't count.
+        let unverified_creator = metadata.creators.as_ref().unwrap()[0].address;
+        assert!(!metadata.belongs_to(&NftGroup::Creator(unverified_creator)));
+    }
+
+    #[test]
+    fn test_unverified_collection() {
+        let collection = Pubkey::new_unique();
+        let mut metadata = metadata(Pubkey::new_unique(), Pubkey::new_unique(), collection);
+        metadata.collection = Some(NftCollection {
+            verified: false,
+            key: collection,
+        });
+        assert!(!metadata.belongs_to(&NftGroup::Collection(collection)));
+        metadata.collection = None;
+        assert!(!metadata.belongs_to(&NftGroup::Collection(collection)));
+    }
+}
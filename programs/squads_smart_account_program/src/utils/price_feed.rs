@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// Magic number at the start of a Pyth price account.
+const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+/// Account type of a Pyth price account.
+const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+/// Status of a Pyth aggregate price that can be used.
+const PYTH_STATUS_TRADING: u32 = 1;
+
+// Offsets of the fields read from a Pyth price account.
+const PYTH_MAGIC_OFFSET: usize = 0;
+const PYTH_ACCOUNT_TYPE_OFFSET: usize = 8;
+const PYTH_EXPONENT_OFFSET: usize = 20;
+const PYTH_TIMESTAMP_OFFSET: usize = 96;
+const PYTH_AGGREGATE_PRICE_OFFSET: usize = 208;
+const PYTH_AGGREGATE_CONFIDENCE_OFFSET: usize = 216;
+const PYTH_AGGREGATE_STATUS_OFFSET: usize = 224;
+const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// A price read from an oracle price account: `price ± confidence` times `10^exponent`
+/// units of the quote currency per whole token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    /// Unix timestamp the price was published at.
+    pub publish_time: i64,
+}
+
+impl PriceFeed {
+    /// Reads the aggregate price of a Pyth-style price account owned by `oracle_program`.
+    pub fn read(account: &AccountInfo, oracle_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            *oracle_program,
+            SmartAccountError::InvalidPriceOracle
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN,
+            SmartAccountError::InvalidPriceOracle
+        );
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        require!(
+            read_u32(PYTH_MAGIC_OFFSET) == PYTH_MAGIC
+                && read_u32(PYTH_ACCOUNT_TYPE_OFFSET) == PYTH_PRICE_ACCOUNT_TYPE,
+            SmartAccountError::InvalidPriceOracle
+        );
+        // Prices that are not being traded are not updated anymore.
+        require!(
+            read_u32(PYTH_AGGREGATE_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+            SmartAccountError::PriceOutOfBounds
+        );
+
+        Ok(Self {
+            price: read_u64(PYTH_AGGREGATE_PRICE_OFFSET) as i64,
+            confidence: read_u64(PYTH_AGGREGATE_CONFIDENCE_OFFSET),
+            exponent: read_u32(PYTH_EXPONENT_OFFSET) as i32,
+            publish_time: read_u64(PYTH_TIMESTAMP_OFFSET) as i64,
+        })
+    }
+
+    /// Checks that the price is positive, at most `max_staleness` seconds old at `now`,
+    /// and that its confidence interval is at most `max_confidence_bps` basis points of it.
+    pub fn check_bounds(
+        &self,
+        now: i64,
+        max_staleness: i64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        require!(self.price > 0, SmartAccountError::PriceOutOfBounds);
+        require!(
+            now.saturating_sub(self.publish_time) <= max_staleness,
+            SmartAccountError::PriceOutOfBounds
+        );
+        require!(
+            (self.confidence as u128) * 10_000
+                <= (self.price as u128) * (max_confidence_bps as u128),
+            SmartAccountError::PriceOutOfBounds
+        );
+        Ok(())
+    }
+
+    /// Converts `amount` of a token with `decimals` into units of the quote currency with
+    /// `quote_decimals`.
+    /// Uses the upper bound of the confidence interval and rounds up, so that the value is
+    /// never underestimated.
+    pub fn quote_amount(&self, amount: u64, decimals: u8, quote_decimals: u8) -> Result<u64> {
+        let upper_price = (self.price as u128)
+            .checked_add(self.confidence as u128)
+            .ok_or(SmartAccountError::PriceOutOfBounds)?;
+        let value = (amount as u128)
+            .checked_mul(upper_price)
+            .ok_or(SmartAccountError::PriceOutOfBounds)?;
+
+        let exponent = self.exponent as i64 + quote_decimals as i64 - decimals as i64;
+        let scale = 10_u128
+            .checked_pow(exponent.unsigned_abs().try_into().unwrap_or(u32::MAX))
+            .ok_or(SmartAccountError::PriceOutOfBounds)?;
+        let quote_amount = if exponent >= 0 {
+            value
+                .checked_mul(scale)
+                .ok_or(SmartAccountError::PriceOutOfBounds)?
+        } else {
+            // Ceiling division.
+            value / scale + u128::from(value % scale != 0)
+        };
+
+        Ok(quote_amount
+            .try_into()
+            .map_err(|_| SmartAccountError::PriceOutOfBounds)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builds the data of a Pyth-style price account.
+    fn price_account_data(feed: PriceFeed, status: u32) -> Vec<u8> {
+        let mut data = vec![0; 3312];
+        data[PYTH_MAGIC_OFFSET..][..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[PYTH_ACCOUNT_TYPE_OFFSET..][..4]
+            .copy_from_slice(&PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes());
+        data[PYTH_EXPONENT_OFFSET..][..4].copy_from_slice(&feed.exponent.to_le_bytes());
+        data[PYTH_TIMESTAMP_OFFSET..][..8].copy_from_slice(&feed.publish_time.to_le_bytes());
+        data[PYTH_AGGREGATE_PRICE_OFFSET..][..8].copy_from_slice(&feed.price.to_le_bytes());
+        data[PYTH_AGGREGATE_CONFIDENCE_OFFSET..][..8]
+            .copy_from_slice(&feed.confidence.to_le_bytes());
+        data[PYTH_AGGREGATE_STATUS_OFFSET..][..4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    fn sol_usd() -> PriceFeed {
+        // 150.00000000 ± 0.15 USD per SOL.
+        PriceFeed {
+            price: 15_000_000_000,
+            confidence: 15_000_000,
+            exponent: -8,
+            publish_time: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_reads_price_account() {
+        let key = Pubkey::new_unique();
+        let oracle_program = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = price_account_data(sol_usd(), PYTH_STATUS_TRADING);
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &oracle_program,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            PriceFeed::read(&account, &oracle_program).unwrap(),
+            sol_usd()
+        );
+        // Price accounts must be owned by the oracle program.
+        assert!(PriceFeed::read(&account, &Pubkey::new_unique()).is_err());
+
+        // Halted prices can't be used.
+        account.try_borrow_mut_data().unwrap()[PYTH_AGGREGATE_STATUS_OFFSET] = 2;
+        assert!(PriceFeed::read(&account, &oracle_program).is_err());
+    }
+
+    #[test]
+    fn test_checks_bounds() {
+        let feed = sol_usd();
+        feed.check_bounds(1_060, 60, 10).unwrap();
+        // Stale.
+        assert!(feed.check_bounds(1_061, 60, 10).is_err());
+        // Confidence interval too wide.
+        assert!(feed.check_bounds(1_000, 60, 9).is_err());
+        // Non-positive price.
+        assert!(PriceFeed { price: 0, ..feed }
+            .check_bounds(1_000, 60, 10)
+            .is_err());
+    }
+
+    #[test]
+    fn test_quote_amount() {
+        let feed = sol_usd();
+        // 2 SOL at 150.15 USD is 300.30 USD, in micro-dollars.
+        assert_eq!(feed.quote_amount(2_000_000_000, 9, 6).unwrap(), 300_300_000);
+        // Rounds up.
+        assert_eq!(feed.quote_amount(1, 9, 6).unwrap(), 1);
+        assert_eq!(feed.quote_amount(0, 9, 6).unwrap(), 0);
+        // Positive exponents.
+        let feed = PriceFeed {
+            price: 3,
+            confidence: 0,
+            exponent: 2,
+            publish_time: 0,
+        };
+        assert_eq!(feed.quote_amount(5, 0, 6).unwrap(), 1_500_000_000);
+        assert!(feed.quote_amount(u64::MAX, 0, 30).is_err());
+    }
+}
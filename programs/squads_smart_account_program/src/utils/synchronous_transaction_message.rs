@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table;
+use anchor_lang::solana_program::address_lookup_table::state::AddressLookupTable;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::invoke_signed;
 
@@ -18,6 +20,7 @@ impl<'info> SynchronousTransactionMessage<'info> {
         smart_account_pubkey: &Pubkey,
         instructions: Vec<SmartAccountCompiledInstruction>,
         remaining_accounts: &[AccountInfo<'info>],
+        ephemeral_signer_pdas: &[Pubkey],
     ) -> Result<Self> {
         // Validate instruction indices first
         for instruction in &instructions {
@@ -40,10 +43,10 @@ impl<'info> SynchronousTransactionMessage<'info> {
             let mut account_info = account.clone();
 
             // For remaining accounts:
-            // - Set account as signer
+            // - Set smart account and ephemeral signers as signer
             // - Remove signer privilege from any smart account signers
             // - Set smart account as non-writable
-            if account.key == smart_account_pubkey {
+            if account.key == smart_account_pubkey || ephemeral_signer_pdas.contains(account.key) {
                 account_info.is_signer = true;
             } else if account.key == settings_key {
                 // This prevents dangerous re-entrancy
@@ -63,8 +66,132 @@ impl<'info> SynchronousTransactionMessage<'info> {
         })
     }
 
+    /// Same as `new_validated`, but additionally loads accounts through address lookup tables.
+    ///
+    /// `remaining_accounts` must be laid out as follows:
+    /// 1. Accounts referenced by the instructions and not loaded from lookup tables,
+    ///    starting with the signers.
+    /// 2. Accounts loaded from lookup tables, in the order of `address_table_lookups`,
+    ///    writable accounts of a lookup first, then its readonly ones.
+    /// 3. The lookup table accounts, in the order of `address_table_lookups`.
+    ///
+    /// The instructions index into 1. and 2. combined.
+    pub fn new_validated_with_lookups(
+        settings_key: &Pubkey,
+        settings: &Settings,
+        smart_account_pubkey: &Pubkey,
+        instructions: Vec<SmartAccountCompiledInstruction>,
+        address_table_lookups: &[SmartAccountMessageAddressTableLookup],
+        remaining_accounts: &[AccountInfo<'info>],
+        ephemeral_signer_pdas: &[Pubkey],
+    ) -> Result<Self> {
+        let num_message_accounts = remaining_accounts
+            .len()
+            .checked_sub(address_table_lookups.len())
+            .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
+        let (message_accounts, lookup_table_account_infos) =
+            remaining_accounts.split_at(num_message_accounts);
+
+        let num_loaded_accounts = address_table_lookups
+            .iter()
+            .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+            .sum::<usize>();
+        let mut cursor = message_accounts
+            .len()
+            .checked_sub(num_loaded_accounts)
+            .ok_or(SmartAccountError::InvalidNumberOfAccounts)?;
+
+        // Indexes into `message_accounts` of the accounts loaded as readonly.
+        let mut loaded_readonly_indexes = Vec::new();
+
+        for (lookup, lookup_table_account_info) in
+            address_table_lookups.iter().zip(lookup_table_account_infos)
+        {
+            // The lookup table account must be owned by SolanaAddressLookupTableProgram.
+            require!(
+                lookup_table_account_info.owner == &address_lookup_table::program::ID,
+                SmartAccountError::InvalidAccount
+            );
+            // The lookup table must be the one mentioned in the lookup.
+            require_keys_eq!(
+                *lookup_table_account_info.key,
+                lookup.account_key,
+                SmartAccountError::InvalidAccount
+            );
+
+            let lookup_table_data = &lookup_table_account_info.data.borrow()[..];
+            let lookup_table = AddressLookupTable::deserialize(lookup_table_data)
+                .map_err(|_| SmartAccountError::InvalidAccount)?;
+
+            for (index_in_lookup_table, is_writable) in lookup
+                .writable_indexes
+                .iter()
+                .map(|index| (index, true))
+                .chain(lookup.readonly_indexes.iter().map(|index| (index, false)))
+            {
+                let loaded_account_info = &message_accounts[cursor];
+                // Accounts listed as writable in the lookup, should be loaded as writable.
+                if is_writable {
+                    require!(
+                        loaded_account_info.is_writable,
+                        SmartAccountError::InvalidAccount
+                    );
+                } else {
+                    loaded_readonly_indexes.push(cursor);
+                }
+                // Check that the pubkey matches the one from the actual lookup table.
+                let pubkey_from_lookup_table = lookup_table
+                    .addresses
+                    .get(usize::from(*index_in_lookup_table))
+                    .ok_or(SmartAccountError::InvalidAccount)?;
+                require_keys_eq!(
+                    *loaded_account_info.key,
+                    *pubkey_from_lookup_table,
+                    SmartAccountError::InvalidAccount
+                );
+                cursor += 1;
+            }
+        }
+
+        let mut message = Self::new_validated(
+            settings_key,
+            settings,
+            smart_account_pubkey,
+            instructions,
+            message_accounts,
+            ephemeral_signer_pdas,
+        )?;
+
+        // Accounts loaded as readonly must not be passed as writable to the CPI calls.
+        for index in loaded_readonly_indexes {
+            message.accounts[index].is_writable = false;
+        }
+
+        Ok(message)
+    }
+
     /// Executes all instructions in the message via CPI calls
-    pub fn execute(&self, smart_account_seeds: &[&[u8]]) -> Result<()> {
+    /// # Arguments
+    /// * `smart_account_seeds` - Seeds for the smart account PDA.
+    /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs.
+    pub fn execute(
+        &self,
+        smart_account_seeds: &[&[u8]],
+        ephemeral_signer_seeds: &[Vec<Vec<u8>>],
+    ) -> Result<()> {
+        // First round of type conversion; from Vec<Vec<Vec<u8>>> to Vec<Vec<&[u8]>>.
+        let ephemeral_signer_seeds = &ephemeral_signer_seeds
+            .iter()
+            .map(|seeds| seeds.iter().map(Vec::as_slice).collect::<Vec<&[u8]>>())
+            .collect::<Vec<Vec<&[u8]>>>();
+        // Second round of type conversion; from Vec<Vec<&[u8]>> to Vec<&[&[u8]]>.
+        let mut signer_seeds = ephemeral_signer_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<&[&[u8]]>>();
+        // Add the smart account seeds.
+        signer_seeds.push(smart_account_seeds);
+
         for instruction in &self.instructions {
             let program_id = self.accounts[instruction.program_id_index as usize].key;
 
@@ -95,8 +222,198 @@ impl<'info> SynchronousTransactionMessage<'info> {
                 .map(|&idx| self.accounts[idx as usize].clone())
                 .collect();
 
-            invoke_signed(&ix, &accounts_slice, &[smart_account_seeds])?;
+            invoke_signed(&ix, &accounts_slice, &signer_seeds)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::solana_program::address_lookup_table::state::LookupTableMeta;
+    use std::borrow::Cow;
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_writable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, is_writable: bool) -> Self {
+            Self {
+                key,
+                owner: Pubkey::default(),
+                lamports: 0,
+                data: vec![],
+                is_writable,
+            }
+        }
+
+        fn lookup_table(key: Pubkey, addresses: &[Pubkey]) -> Self {
+            let lookup_table = AddressLookupTable {
+                meta: LookupTableMeta::default(),
+                addresses: Cow::Borrowed(addresses),
+            };
+            Self {
+                key,
+                owner: address_lookup_table::program::ID,
+                lamports: 0,
+                data: lookup_table.serialize_for_tests().unwrap(),
+                is_writable: false,
+            }
+        }
+
+        fn info(&mut self) -> AccountInfo {
+            AccountInfo::new(
+                &self.key,
+                false,
+                self.is_writable,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn lookup(
+        account_key: Pubkey,
+        writable_indexes: Vec<u8>,
+        readonly_indexes: Vec<u8>,
+    ) -> SmartAccountMessageAddressTableLookup {
+        SmartAccountMessageAddressTableLookup {
+            account_key,
+            writable_indexes,
+            readonly_indexes,
+        }
+    }
+
+    fn instructions(num_accounts: u8) -> Vec<SmartAccountCompiledInstruction> {
+        vec![SmartAccountCompiledInstruction {
+            program_id_index: 0,
+            account_indexes: (0..num_accounts).collect(),
+            data: vec![],
+        }]
+    }
+
+    #[test]
+    fn test_loads_accounts_from_lookup_table() {
+        let table_key = Pubkey::new_unique();
+        let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut program = TestAccount::new(Pubkey::new_unique(), false);
+        let mut writable = TestAccount::new(addresses[1], true);
+        // Readonly accounts passed as writable must be downgraded.
+        let mut readonly = TestAccount::new(addresses[0], true);
+        let mut table = TestAccount::lookup_table(table_key, &addresses);
+        let remaining_accounts = [
+            program.info(),
+            writable.info(),
+            readonly.info(),
+            table.info(),
+        ];
+
+        let message = SynchronousTransactionMessage::new_validated_with_lookups(
+            &Pubkey::new_unique(),
+            &SettingsBuilder::new().build(),
+            &Pubkey::new_unique(),
+            instructions(3),
+            &[lookup(table_key, vec![1], vec![0])],
+            &remaining_accounts,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(message.accounts.len(), 3);
+        assert_eq!(message.accounts[1].key, &addresses[1]);
+        assert!(message.accounts[1].is_writable);
+        assert_eq!(message.accounts[2].key, &addresses[0]);
+        assert!(!message.accounts[2].is_writable);
+    }
+
+    #[test]
+    fn test_rejects_invalid_lookups() {
+        let table_key = Pubkey::new_unique();
+        let addresses = [Pubkey::new_unique()];
+        let mut program = TestAccount::new(Pubkey::new_unique(), false);
+        let mut loaded = TestAccount::new(addresses[0], false);
+        let mut table = TestAccount::lookup_table(table_key, &addresses);
+        let mut not_a_table = TestAccount::new(table_key, false);
+
+        let validate = |lookups: &[SmartAccountMessageAddressTableLookup],
+                        remaining_accounts: &[AccountInfo]| {
+            SynchronousTransactionMessage::new_validated_with_lookups(
+                &Pubkey::new_unique(),
+                &SettingsBuilder::new().build(),
+                &Pubkey::new_unique(),
+                instructions(2),
+                lookups,
+                remaining_accounts,
+                &[],
+            )
+            .map(|_| ())
+        };
+
+        let accounts = [program.info(), loaded.info(), table.info()];
+        // Writable lookup of an account that isn't writable.
+        assert!(validate(&[lookup(table_key, vec![0], vec![])], &accounts).is_err());
+        // Lookup table key doesn't match the lookup.
+        assert!(validate(&[lookup(Pubkey::new_unique(), vec![], vec![0])], &accounts).is_err());
+        // Index out of the lookup table bounds.
+        assert!(validate(&[lookup(table_key, vec![], vec![1])], &accounts).is_err());
+        // Not enough accounts for the loaded addresses.
+        assert!(validate(&[lookup(table_key, vec![], vec![0, 0, 0])], &accounts).is_err());
+        // Valid.
+        assert!(validate(&[lookup(table_key, vec![], vec![0])], &accounts).is_ok());
+
+        // Lookup table account not owned by the lookup table program.
+        let accounts = [program.info(), loaded.info(), not_a_table.info()];
+        assert!(validate(&[lookup(table_key, vec![], vec![0])], &accounts).is_err());
+    }
+
+    #[test]
+    fn test_ephemeral_signers_are_marked_as_signers() {
+        let ephemeral_signer = Pubkey::new_unique();
+        let mut program = TestAccount::new(Pubkey::new_unique(), false);
+        let mut mint = TestAccount::new(ephemeral_signer, true);
+        let mut other = TestAccount::new(Pubkey::new_unique(), true);
+        let remaining_accounts = [program.info(), mint.info(), other.info()];
+
+        let message = SynchronousTransactionMessage::new_validated(
+            &Pubkey::new_unique(),
+            &SettingsBuilder::new().build(),
+            &Pubkey::new_unique(),
+            instructions(3),
+            &remaining_accounts,
+            &[ephemeral_signer],
+        )
+        .unwrap();
+
+        assert!(message.accounts[1].is_signer);
+        assert!(!message.accounts[2].is_signer);
+    }
+
+    #[test]
+    fn test_settings_are_passed_as_readonly() {
+        let settings_key = Pubkey::new_unique();
+        let mut program = TestAccount::new(Pubkey::new_unique(), false);
+        let mut settings_account = TestAccount::new(settings_key, true);
+        let remaining_accounts = [program.info(), settings_account.info()];
+
+        let message = SynchronousTransactionMessage::new_validated(
+            &settings_key,
+            &SettingsBuilder::new().build(),
+            &Pubkey::new_unique(),
+            instructions(2),
+            &remaining_accounts,
+            &[],
+        )
+        .unwrap();
+
+        assert!(!message.accounts[1].is_writable);
+    }
+}
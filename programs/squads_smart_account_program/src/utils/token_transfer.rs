@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+
+use crate::errors::*;
+
+/// Returns the fee the Token-2022 transfer fee extension of `mint` withholds from a transfer
+/// of `amount` in `epoch`, zero for mints without a transfer fee.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64, epoch: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    Ok(transfer_fee_config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or(SmartAccountError::InvalidMint)?)
+}
+
+/// Accounts of a `transfer_checked` CPI.
+pub struct TransferCheckedAccounts<'a, 'info> {
+    pub token_program: &'a AccountInfo<'info>,
+    pub from: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub to: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    /// Passed on to the token program after the accounts above,
+    /// e.g. the accounts required by the transfer hook of a Token-2022 mint.
+    pub extra_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Same as `token_interface::transfer_checked`, but forwards `extra_accounts` to the token
+/// program, so that transfers of mints with a Token-2022 transfer hook can be made.
+pub fn transfer_checked_with_extra_accounts(
+    accounts: TransferCheckedAccounts,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        accounts.token_program.key,
+        accounts.from.key,
+        accounts.mint.key,
+        accounts.to.key,
+        accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    ix.accounts
+        .extend(accounts.extra_accounts.iter().map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }));
+
+    let mut account_infos = vec![
+        accounts.from.clone(),
+        accounts.mint.clone(),
+        accounts.to.clone(),
+        accounts.authority.clone(),
+    ];
+    account_infos.extend_from_slice(accounts.extra_accounts);
+    account_infos.push(accounts.token_program.clone());
+
+    invoke_signed(&ix, &account_infos, signer_seeds).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use spl_token_2022::extension::transfer_fee::TransferFee;
+    use spl_token_2022::extension::{ExtensionType, StateWithExtensionsMut};
+    use spl_token_2022::state::Mint;
+
+    fn mint_with_transfer_fee(older_basis_points: u16, newer_basis_points: u16) -> Vec<u8> {
+        let len =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+                .unwrap();
+        let mut data = vec![0; len];
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let transfer_fee_config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+        transfer_fee_config.older_transfer_fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: 1_000.into(),
+            transfer_fee_basis_points: older_basis_points.into(),
+        };
+        transfer_fee_config.newer_transfer_fee = TransferFee {
+            epoch: 10.into(),
+            maximum_fee: 1_000.into(),
+            transfer_fee_basis_points: newer_basis_points.into(),
+        };
+        mint.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_transfer_fee() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = mint_with_transfer_fee(100, 200);
+        let mint = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &spl_token_2022::ID,
+            false,
+            0,
+        );
+
+        // 1% before epoch 10, 2% from then on, capped at the maximum fee.
+        assert_eq!(transfer_fee(&mint, 10_000, 9).unwrap(), 100);
+        assert_eq!(transfer_fee(&mint, 10_000, 10).unwrap(), 200);
+        assert_eq!(transfer_fee(&mint, 1_000_000, 10).unwrap(), 1_000);
+        assert_eq!(transfer_fee(&mint, 0, 10).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_no_transfer_fee() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0; Mint::LEN];
+        Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        }
+        .pack_into_slice(&mut data);
+
+        for owner in [spl_token_2022::ID, anchor_spl::token::ID] {
+            let mint = AccountInfo::new(
+                &key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+            assert_eq!(transfer_fee(&mint, 10_000, 10).unwrap(), 0);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Compares the compute units consumed by transactions created with a compressed message
+//! and with an uncompressed one, next to the rent of their `Transaction` accounts.
+//!
+//! Runs against the program built by `anchor build`, so it is only compiled with the `test-sbf`
+//! feature. `yarn test` runs it after the TypeScript suites:
+//!
+//! ```sh
+//! anchor build -- --features=testing
+//! SBF_OUT_DIR=$(pwd)/target/deploy cargo test -p squads-smart-account-program \
+//!     --features no-entrypoint,testing,test-sbf --test compressed_message_benchmark -- --nocapture
+//! ```
+
+use anchor_lang::{AccountSerialize, AnchorSerialize, InstructionData, ToAccountMetas};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+use squads_smart_account_program::{
+    accounts, instruction, CompiledInstruction, CreateProposalArgs, CreateTransactionV2Args,
+    Permissions, Settings, SettingsBuilder, TimeLockBypass, TransactionMessage, VoteOnProposalArgs,
+    ID, SEED_PREFIX, SEED_PROPOSAL, SEED_SETTINGS, SEED_SMART_ACCOUNT, SEED_TRANSACTION,
+};
+
+/// Number of identical transfers in the benchmarked message.
+/// Compression only stores their account indexes and data once.
+const TRANSFERS: usize = 10;
+
+/// Compute units that creating the transaction may consume, with either encoding of the message.
+/// Half of the default budget of an instruction, so it can share a transaction with others.
+const CREATE_COMPUTE_UNIT_BUDGET: u64 = 100_000;
+
+/// Compute units that executing the transaction may consume, with either encoding of the message.
+const EXECUTE_COMPUTE_UNIT_BUDGET: u64 = 100_000;
+
+struct Benchmark {
+    banks_client: BanksClient,
+    payer: Keypair,
+    creator: Keypair,
+    settings: Pubkey,
+    transaction_message: Vec<u8>,
+    message_accounts: Vec<AccountMeta>,
+}
+
+struct Measurement {
+    create_compute_units: u64,
+    execute_compute_units: u64,
+    transaction_size: usize,
+    transaction_rent: u64,
+}
+
+#[tokio::test]
+async fn compressed_message_benchmark() {
+    let creator = Keypair::new();
+    let recipient = Pubkey::new_unique();
+    let settings_seed = 1u128;
+    let (settings, settings_bump) = Pubkey::find_program_address(
+        &[SEED_PREFIX, SEED_SETTINGS, &settings_seed.to_le_bytes()],
+        &ID,
+    );
+    let (smart_account, _) = Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            settings.as_ref(),
+            SEED_SMART_ACCOUNT,
+            &0u8.to_le_bytes(),
+        ],
+        &ID,
+    );
+
+    let mut program_test = ProgramTest::new("squads_smart_account_program", ID, None);
+    program_test.prefer_bpf(true);
+    program_test.add_account(
+        creator.pubkey(),
+        Account::new(10 * LAMPORTS_PER_SOL, 0, &system_program::ID),
+    );
+    program_test.add_account(
+        smart_account,
+        Account::new(10 * LAMPORTS_PER_SOL, 0, &system_program::ID),
+    );
+    program_test.add_account(
+        settings,
+        settings_account(&creator, settings_seed, settings_bump),
+    );
+    let (banks_client, payer, _) = program_test.start().await;
+
+    let mut benchmark = Benchmark {
+        banks_client,
+        payer,
+        creator,
+        settings,
+        transaction_message: transfers_message(smart_account, recipient),
+        // The accounts of the message, in the order of its `account_keys`.
+        message_accounts: vec![
+            AccountMeta::new(smart_account, false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    };
+    let uncompressed = benchmark.measure(1, false).await;
+    let compressed = benchmark.measure(2, true).await;
+
+    println!(
+        "{TRANSFERS} transfers, {} byte message",
+        benchmark.transaction_message.len()
+    );
+    println!(
+        "{:<14}{:>12}{:>12}{:>12}{:>16}",
+        "", "create CU", "execute CU", "size", "rent (lamports)"
+    );
+    for (name, measurement) in [("uncompressed", &uncompressed), ("compressed", &compressed)] {
+        println!(
+            "{:<14}{:>12}{:>12}{:>12}{:>16}",
+            name,
+            measurement.create_compute_units,
+            measurement.execute_compute_units,
+            measurement.transaction_size,
+            measurement.transaction_rent
+        );
+    }
+    println!(
+        "{:<14}{:>+12}{:>+12}{:>+12}{:>+16}",
+        "difference",
+        compressed.create_compute_units as i64 - uncompressed.create_compute_units as i64,
+        compressed.execute_compute_units as i64 - uncompressed.execute_compute_units as i64,
+        compressed.transaction_size as i64 - uncompressed.transaction_size as i64,
+        compressed.transaction_rent as i64 - uncompressed.transaction_rent as i64
+    );
+
+    assert!(compressed.transaction_size < uncompressed.transaction_size);
+    assert!(compressed.transaction_rent < uncompressed.transaction_rent);
+    for measurement in [&uncompressed, &compressed] {
+        assert!(measurement.create_compute_units <= CREATE_COMPUTE_UNIT_BUDGET);
+        assert!(measurement.execute_compute_units <= EXECUTE_COMPUTE_UNIT_BUDGET);
+    }
+}
+
+fn settings_account(creator: &Keypair, seed: u128, bump: u8) -> Account {
+    let settings = SettingsBuilder::new()
+        .seed(seed)
+        .bump(bump)
+        .signer(creator.pubkey(), Permissions { mask: 0b111 })
+        .time_lock_bypass(TimeLockBypass::Disabled)
+        .build();
+
+    let mut data = Vec::with_capacity(Settings::size(1));
+    settings.try_serialize(&mut data).unwrap();
+    data.resize(Settings::size(1), 0);
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// The optional trailing program account the instructions log their events through.
+fn log_program() -> AccountMeta {
+    AccountMeta::new_readonly(ID, false)
+}
+
+/// Serialized `TransactionMessage` transferring from the `smart_account` to the
+/// `recipient` `TRANSFERS` times.
+fn transfers_message(smart_account: Pubkey, recipient: Pubkey) -> Vec<u8> {
+    let transfer = system_instruction::transfer(&smart_account, &recipient, LAMPORTS_PER_SOL / 100);
+
+    TransactionMessage {
+        num_signers: 1,
+        num_writable_signers: 1,
+        num_writable_non_signers: 1,
+        account_keys: vec![smart_account, recipient, system_program::ID].into(),
+        instructions: vec![
+            CompiledInstruction {
+                program_id_index: 2,
+                account_indexes: vec![0, 1].into(),
+                data: transfer.data.into(),
+            };
+            TRANSFERS
+        ]
+        .into(),
+        address_table_lookups: vec![].into(),
+    }
+    .try_to_vec()
+    .unwrap()
+}
+
+impl Benchmark {
+    /// Creates, approves and executes a transaction with the `transaction_message`.
+    async fn measure(&mut self, transaction_index: u64, compress_message: bool) -> Measurement {
+        let settings = self.settings;
+        let creator = self.creator.pubkey();
+        let (transaction, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                settings.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+            ],
+            &ID,
+        );
+        let (proposal, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                settings.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+                SEED_PROPOSAL,
+            ],
+            &ID,
+        );
+
+        let create_compute_units = self
+            .process(Instruction {
+                program_id: ID,
+                accounts: accounts::CreateTransactionV2 {
+                    settings,
+                    transaction,
+                    creator,
+                    rent_payer: creator,
+                    system_program: system_program::ID,
+                    program: ID,
+                }
+                .to_account_metas(None),
+                data: instruction::CreateTransactionV2 {
+                    args: CreateTransactionV2Args {
+                        account_index: 0,
+                        ephemeral_signers: 0,
+                        transaction_message: self.transaction_message.clone(),
+                        memo: None,
+                        compress_message,
+                        message_hash: None,
+                    },
+                }
+                .data(),
+            })
+            .await;
+        let transaction_account = self
+            .banks_client
+            .get_account(transaction)
+            .await
+            .unwrap()
+            .unwrap();
+
+        self.process(Instruction {
+            program_id: ID,
+            accounts: accounts::CreateProposal {
+                settings,
+                proposal,
+                creator,
+                rent_payer: creator,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None)
+            .into_iter()
+            .chain([log_program()])
+            .collect(),
+            data: instruction::CreateProposal {
+                args: CreateProposalArgs {
+                    transaction_index,
+                    draft: false,
+                },
+            }
+            .data(),
+        })
+        .await;
+        self.process(Instruction {
+            program_id: ID,
+            accounts: accounts::VoteOnProposal {
+                settings,
+                signer: creator,
+                proposal,
+                system_program: None,
+            }
+            .to_account_metas(None)
+            .into_iter()
+            .chain([log_program()])
+            .collect(),
+            data: instruction::ApproveProposal {
+                args: VoteOnProposalArgs { memo: None },
+            }
+            .data(),
+        })
+        .await;
+
+        let mut execute_accounts = accounts::ExecuteTransaction {
+            settings,
+            proposal,
+            transaction,
+            signer: creator,
+        }
+        .to_account_metas(None);
+        execute_accounts.extend_from_slice(&self.message_accounts);
+        execute_accounts.push(log_program());
+        let execute_compute_units = self
+            .process(Instruction {
+                program_id: ID,
+                accounts: execute_accounts,
+                data: instruction::ExecuteTransaction {}.data(),
+            })
+            .await;
+
+        Measurement {
+            create_compute_units,
+            execute_compute_units,
+            transaction_size: transaction_account.data.len(),
+            transaction_rent: transaction_account.lamports,
+        }
+    }
+
+    /// Processes the `instruction` signed by the `creator` and returns the compute units it consumed.
+    async fn process(&mut self, instruction: Instruction) -> u64 {
+        let recent_blockhash = self.banks_client.get_latest_blockhash().await.unwrap();
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.payer.pubkey()),
+            &[&self.payer, &self.creator],
+            recent_blockhash,
+        );
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await
+            .unwrap();
+        result.result.unwrap();
+        result.metadata.unwrap().compute_units_consumed
+    }
+}
@@ -24,8 +24,15 @@ const ignoredTypes = new Set([
   "RemoveSpendingLimitEvent",
   "UseSpendingLimitEvent",
   "SmartAccountEvent",
+  "TransactionContent",
 ]);
 
+// `AccountExtension` fields are missing from accounts created before they were introduced,
+// which beet can't represent, so they are left out and read past.
+const isAccountExtension = (field) =>
+  field.type.defined !== undefined &&
+  field.type.defined.startsWith("AccountExtension<");
+
 module.exports = {
   idlGenerator: "anchor",
   programName: PROGRAM_NAME,
@@ -37,6 +44,15 @@ module.exports = {
   idlHook: (idl) => {
     return {
       ...idl,
+      accounts: idl.accounts.map((account) => ({
+        ...account,
+        type: {
+          ...account.type,
+          fields: account.type.fields.filter(
+            (field) => !isAccountExtension(field)
+          ),
+        },
+      })),
       types: idl.types.filter((type) => {
         return !ignoredTypes.has(type.name);
       }),
@@ -12,6 +12,10 @@ import {
   SmartAccountSigner,
   smartAccountSignerBeet,
 } from '../types/SmartAccountSigner'
+import {
+  SettingsExtension,
+  settingsExtensionBeet,
+} from '../types/SettingsExtension'
 
 /**
  * Arguments used to create {@link Settings}
@@ -31,7 +35,7 @@ export type SettingsArgs = {
   signers: SmartAccountSigner[]
   accountUtilization: number
   reserved1: number
-  reserved2: number
+  extension: SettingsExtension
 }
 
 export const settingsDiscriminator = [223, 179, 163, 190, 177, 224, 67, 173]
@@ -56,7 +60,7 @@ export class Settings implements SettingsArgs {
     readonly signers: SmartAccountSigner[],
     readonly accountUtilization: number,
     readonly reserved1: number,
-    readonly reserved2: number
+    readonly extension: SettingsExtension
   ) {}
 
   /**
@@ -76,7 +80,7 @@ export class Settings implements SettingsArgs {
       args.signers,
       args.accountUtilization,
       args.reserved1,
-      args.reserved2
+      args.extension
     )
   }
 
@@ -237,7 +241,7 @@ export class Settings implements SettingsArgs {
       signers: this.signers,
       accountUtilization: this.accountUtilization,
       reserved1: this.reserved1,
-      reserved2: this.reserved2,
+      extension: this.extension.__kind,
     }
   }
 }
@@ -266,7 +270,7 @@ export const settingsBeet = new beet.FixableBeetStruct<
     ['signers', beet.array(smartAccountSignerBeet)],
     ['accountUtilization', beet.u8],
     ['reserved1', beet.u8],
-    ['reserved2', beet.u8],
+    ['extension', settingsExtensionBeet],
   ],
   Settings.fromArgs,
   'Settings'
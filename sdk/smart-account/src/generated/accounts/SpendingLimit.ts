@@ -194,7 +194,7 @@ export class SpendingLimit implements SpendingLimitArgs {
         }
         return x
       })(),
-      period: 'Period.' + Period[this.period],
+      period: this.period.__kind,
       remainingAmount: (() => {
         const x = <{ toNumber: () => number }>this.remainingAmount
         if (typeof x.toNumber === 'function') {
@@ -1,5 +1,6 @@
 export * from './Batch'
 export * from './BatchTransaction'
+export * from './EventSequence'
 export * from './ProgramConfig'
 export * from './Proposal'
 export * from './Settings'
@@ -10,6 +11,7 @@ export * from './TransactionBuffer'
 
 import { Batch } from './Batch'
 import { BatchTransaction } from './BatchTransaction'
+import { EventSequence } from './EventSequence'
 import { ProgramConfig } from './ProgramConfig'
 import { Proposal } from './Proposal'
 import { SettingsTransaction } from './SettingsTransaction'
@@ -21,6 +23,7 @@ import { Transaction } from './Transaction'
 export const accountProviders = {
   Batch,
   BatchTransaction,
+  EventSequence,
   ProgramConfig,
   Proposal,
   SettingsTransaction,
@@ -1014,7 +1014,7 @@ createErrorFromNameLookup.set(
 )
 
 /**
- * FinalBufferSizeExceeded: 'Final buffer size cannot exceed 4000 bytes'
+ * FinalBufferSizeExceeded: 'Final buffer size cannot exceed 7014 bytes'
  *
  * @category Errors
  * @category generated
@@ -1023,7 +1023,7 @@ export class FinalBufferSizeExceededError extends Error {
   readonly code: number = 0x179c
   readonly name: string = 'FinalBufferSizeExceeded'
   constructor() {
-    super('Final buffer size cannot exceed 4000 bytes')
+    super('Final buffer size cannot exceed 7014 bytes')
     if (typeof Error.captureStackTrace === 'function') {
       Error.captureStackTrace(this, FinalBufferSizeExceededError)
     }
@@ -1253,6 +1253,435 @@ export class NotImplementedError extends Error {
 createErrorFromCodeLookup.set(0x17a5, () => new NotImplementedError())
 createErrorFromNameLookup.set('NotImplemented', () => new NotImplementedError())
 
+/**
+ * InvalidTimeLockBypassThreshold: 'Invalid time lock bypass threshold, must be greater than the threshold and not exceed the number of voters'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTimeLockBypassThresholdError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'InvalidTimeLockBypassThreshold'
+  constructor() {
+    super(
+      'Invalid time lock bypass threshold, must be greater than the threshold and not exceed the number of voters'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTimeLockBypassThresholdError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a6,
+  () => new InvalidTimeLockBypassThresholdError()
+)
+createErrorFromNameLookup.set(
+  'InvalidTimeLockBypassThreshold',
+  () => new InvalidTimeLockBypassThresholdError()
+)
+
+/**
+ * SpendingLimitInvalidOutflow: 'Transaction moved assets out of the smart account that are not covered by the spending limit'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SpendingLimitInvalidOutflowError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'SpendingLimitInvalidOutflow'
+  constructor() {
+    super(
+      'Transaction moved assets out of the smart account that are not covered by the spending limit'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SpendingLimitInvalidOutflowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a7,
+  () => new SpendingLimitInvalidOutflowError()
+)
+createErrorFromNameLookup.set(
+  'SpendingLimitInvalidOutflow',
+  () => new SpendingLimitInvalidOutflowError()
+)
+
+/**
+ * SpendingLimitInvalidPeriod: 'Invalid spending limit period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SpendingLimitInvalidPeriodError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'SpendingLimitInvalidPeriod'
+  constructor() {
+    super('Invalid spending limit period')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SpendingLimitInvalidPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a8,
+  () => new SpendingLimitInvalidPeriodError()
+)
+createErrorFromNameLookup.set(
+  'SpendingLimitInvalidPeriod',
+  () => new SpendingLimitInvalidPeriodError()
+)
+
+/**
+ * InvalidPriceOracle: 'Invalid price oracle'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPriceOracleError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'InvalidPriceOracle'
+  constructor() {
+    super('Invalid price oracle')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPriceOracleError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new InvalidPriceOracleError())
+createErrorFromNameLookup.set(
+  'InvalidPriceOracle',
+  () => new InvalidPriceOracleError()
+)
+
+/**
+ * PriceOutOfBounds: 'Price is not trading, stale or too uncertain'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PriceOutOfBoundsError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'PriceOutOfBounds'
+  constructor() {
+    super('Price is not trading, stale or too uncertain')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceOutOfBoundsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new PriceOutOfBoundsError())
+createErrorFromNameLookup.set(
+  'PriceOutOfBounds',
+  () => new PriceOutOfBoundsError()
+)
+
+/**
+ * SpendingLimitPaused: 'Spending limit is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SpendingLimitPausedError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'SpendingLimitPaused'
+  constructor() {
+    super('Spending limit is paused')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SpendingLimitPausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new SpendingLimitPausedError())
+createErrorFromNameLookup.set(
+  'SpendingLimitPaused',
+  () => new SpendingLimitPausedError()
+)
+
+/**
+ * InvalidNft: 'NFT is not covered by the spending limit'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNftError extends Error {
+  readonly code: number = 0x17ac
+  readonly name: string = 'InvalidNft'
+  constructor() {
+    super('NFT is not covered by the spending limit')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNftError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new InvalidNftError())
+createErrorFromNameLookup.set('InvalidNft', () => new InvalidNftError())
+
+/**
+ * InvalidBufferChunk: 'Buffer chunk must start at a multiple of the chunk size and fill the chunk'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBufferChunkError extends Error {
+  readonly code: number = 0x17ad
+  readonly name: string = 'InvalidBufferChunk'
+  constructor() {
+    super(
+      'Buffer chunk must start at a multiple of the chunk size and fill the chunk'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBufferChunkError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new InvalidBufferChunkError())
+createErrorFromNameLookup.set(
+  'InvalidBufferChunk',
+  () => new InvalidBufferChunkError()
+)
+
+/**
+ * TransactionBufferIncomplete: 'Transaction buffer has chunks that haven't been written'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionBufferIncompleteError extends Error {
+  readonly code: number = 0x17ae
+  readonly name: string = 'TransactionBufferIncomplete'
+  constructor() {
+    super("Transaction buffer has chunks that haven't been written")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionBufferIncompleteError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17ae,
+  () => new TransactionBufferIncompleteError()
+)
+createErrorFromNameLookup.set(
+  'TransactionBufferIncomplete',
+  () => new TransactionBufferIncompleteError()
+)
+
+/**
+ * TransactionMessageHashMismatch: 'Transaction message doesn't match the hash the transaction was created with'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionMessageHashMismatchError extends Error {
+  readonly code: number = 0x17af
+  readonly name: string = 'TransactionMessageHashMismatch'
+  constructor() {
+    super(
+      "Transaction message doesn't match the hash the transaction was created with"
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionMessageHashMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17af,
+  () => new TransactionMessageHashMismatchError()
+)
+createErrorFromNameLookup.set(
+  'TransactionMessageHashMismatch',
+  () => new TransactionMessageHashMismatchError()
+)
+
+/**
+ * UnsupportedSettingsAction: 'Settings action can't be applied without accounts other than the settings'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnsupportedSettingsActionError extends Error {
+  readonly code: number = 0x17b0
+  readonly name: string = 'UnsupportedSettingsAction'
+  constructor() {
+    super(
+      "Settings action can't be applied without accounts other than the settings"
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnsupportedSettingsActionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17b0,
+  () => new UnsupportedSettingsActionError()
+)
+createErrorFromNameLookup.set(
+  'UnsupportedSettingsAction',
+  () => new UnsupportedSettingsActionError()
+)
+
+/**
+ * ProgramNotAllowed: 'Instruction invokes a program the spending limit doesn't allow'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramNotAllowedError extends Error {
+  readonly code: number = 0x17b1
+  readonly name: string = 'ProgramNotAllowed'
+  constructor() {
+    super("Instruction invokes a program the spending limit doesn't allow")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new ProgramNotAllowedError())
+createErrorFromNameLookup.set(
+  'ProgramNotAllowed',
+  () => new ProgramNotAllowedError()
+)
+
+/**
+ * SmartAccountModified: 'Transaction changed the owner or the data of the smart account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SmartAccountModifiedError extends Error {
+  readonly code: number = 0x17b2
+  readonly name: string = 'SmartAccountModified'
+  constructor() {
+    super('Transaction changed the owner or the data of the smart account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SmartAccountModifiedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new SmartAccountModifiedError())
+createErrorFromNameLookup.set(
+  'SmartAccountModified',
+  () => new SmartAccountModifiedError()
+)
+
+/**
+ * TokenAuthorityChange: 'Transaction delegates or reassigns the authority of a token account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenAuthorityChangeError extends Error {
+  readonly code: number = 0x17b3
+  readonly name: string = 'TokenAuthorityChange'
+  constructor() {
+    super('Transaction delegates or reassigns the authority of a token account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenAuthorityChangeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new TokenAuthorityChangeError())
+createErrorFromNameLookup.set(
+  'TokenAuthorityChange',
+  () => new TokenAuthorityChangeError()
+)
+
+/**
+ * TransactionMessageNotHashCommitted: 'Transaction message can only be revealed for transactions created with its hash'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionMessageNotHashCommittedError extends Error {
+  readonly code: number = 0x17b4
+  readonly name: string = 'TransactionMessageNotHashCommitted'
+  constructor() {
+    super(
+      'Transaction message can only be revealed for transactions created with its hash'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionMessageNotHashCommittedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17b4,
+  () => new TransactionMessageNotHashCommittedError()
+)
+createErrorFromNameLookup.set(
+  'TransactionMessageNotHashCommitted',
+  () => new TransactionMessageNotHashCommittedError()
+)
+
+/**
+ * InvalidUsageHistoryCapacity: 'Usage history capacity of the spending limit exceeds the maximum'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidUsageHistoryCapacityError extends Error {
+  readonly code: number = 0x17b5
+  readonly name: string = 'InvalidUsageHistoryCapacity'
+  constructor() {
+    super('Usage history capacity of the spending limit exceeds the maximum')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidUsageHistoryCapacityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17b5,
+  () => new InvalidUsageHistoryCapacityError()
+)
+createErrorFromNameLookup.set(
+  'InvalidUsageHistoryCapacity',
+  () => new InvalidUsageHistoryCapacityError()
+)
+
+/**
+ * UntrackedSmartAccountAsset: 'Transaction writes to an account under the authority of the smart account whose assets can't be measured'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UntrackedSmartAccountAssetError extends Error {
+  readonly code: number = 0x17b6
+  readonly name: string = 'UntrackedSmartAccountAsset'
+  constructor() {
+    super(
+      "Transaction writes to an account under the authority of the smart account whose assets can't be measured"
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UntrackedSmartAccountAssetError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17b6,
+  () => new UntrackedSmartAccountAssetError()
+)
+createErrorFromNameLookup.set(
+  'UntrackedSmartAccountAsset',
+  () => new UntrackedSmartAccountAssetError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -2,29 +2,41 @@ export * from './activateProposal'
 export * from './addSignerAsAuthority'
 export * from './addSpendingLimitAsAuthority'
 export * from './addTransactionToBatch'
+export * from './addTransactionToBatchFromBuffer'
 export * from './approveProposal'
 export * from './cancelProposal'
 export * from './changeThresholdAsAuthority'
 export * from './closeBatch'
 export * from './closeBatchTransaction'
+export * from './closeBatchTransactionV2'
+export * from './closeBatchV2'
 export * from './closeSettingsTransaction'
+export * from './closeSettingsTransactionV2'
 export * from './closeTransaction'
 export * from './closeTransactionBuffer'
+export * from './closeTransactionV2'
 export * from './createBatch'
 export * from './createProposal'
 export * from './createSettingsTransaction'
+export * from './createSettingsTransactionFromBuffer'
 export * from './createSmartAccount'
 export * from './createTransaction'
 export * from './createTransactionBuffer'
 export * from './createTransactionFromBuffer'
+export * from './createTransactionFromBufferV2'
+export * from './createTransactionV2'
 export * from './executeBatchTransaction'
 export * from './executeSettingsTransaction'
 export * from './executeSettingsTransactionSync'
 export * from './executeTransaction'
 export * from './executeTransactionSync'
+export * from './executeTransactionSyncV2'
+export * from './executeTransactionV2'
 export * from './extendTransactionBuffer'
+export * from './initializeEventSequence'
 export * from './initializeProgramConfig'
 export * from './logEvent'
+export * from './pauseSpendingLimit'
 export * from './rejectProposal'
 export * from './removeSignerAsAuthority'
 export * from './removeSpendingLimitAsAuthority'
@@ -34,4 +46,11 @@ export * from './setProgramConfigAuthority'
 export * from './setProgramConfigSmartAccountCreationFee'
 export * from './setProgramConfigTreasury'
 export * from './setTimeLockAsAuthority'
+export * from './setTimeLockBypassAsAuthority'
+export * from './updateSpendingLimitAsAuthority'
+export * from './useCompressedNftSpendingLimit'
+export * from './useNftSpendingLimit'
 export * from './useSpendingLimit'
+export * from './useSpendingLimitBatch'
+export * from './useSpendingLimitSync'
+export * from './writeTransactionBuffer'
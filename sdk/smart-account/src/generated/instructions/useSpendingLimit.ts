@@ -49,6 +49,9 @@ export const useSpendingLimitStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] smartAccountTokenAccount (optional)
  * @property [_writable_] destinationTokenAccount (optional)
  * @property [] program
+ * @property [_writable_, **signer**] rentPayer (optional)
+ * @property [] associatedTokenProgram (optional)
+ * @property [] priceAccount (optional)
  * @category Instructions
  * @category UseSpendingLimit
  * @category generated
@@ -65,6 +68,9 @@ export type UseSpendingLimitInstructionAccounts = {
   destinationTokenAccount?: web3.PublicKey
   tokenProgram?: web3.PublicKey
   program: web3.PublicKey
+  rentPayer?: web3.PublicKey
+  associatedTokenProgram?: web3.PublicKey
+  priceAccount?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -150,6 +156,21 @@ export function createUseSpendingLimitInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.rentPayer ?? programId,
+      isWritable: accounts.rentPayer != null,
+      isSigner: accounts.rentPayer != null,
+    },
+    {
+      pubkey: accounts.associatedTokenProgram ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.priceAccount ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
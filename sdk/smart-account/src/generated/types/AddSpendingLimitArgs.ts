@@ -9,6 +9,10 @@ import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { Period, periodBeet } from './Period'
+import {
+  SpendingLimitOptions,
+  spendingLimitOptionsBeet,
+} from './SpendingLimitOptions'
 export type AddSpendingLimitArgs = {
   seed: web3.PublicKey
   accountIndex: number
@@ -18,6 +22,7 @@ export type AddSpendingLimitArgs = {
   signers: web3.PublicKey[]
   destinations: web3.PublicKey[]
   expiration: beet.bignum
+  options: SpendingLimitOptions
   memo: beet.COption<string>
 }
 
@@ -36,6 +41,7 @@ export const addSpendingLimitArgsBeet =
       ['signers', beet.array(beetSolana.publicKey)],
       ['destinations', beet.array(beetSolana.publicKey)],
       ['expiration', beet.i64],
+      ['options', spendingLimitOptionsBeet],
       ['memo', beet.coption(beet.utf8String)],
     ],
     'AddSpendingLimitArgs'
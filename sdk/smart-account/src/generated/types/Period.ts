@@ -7,21 +7,86 @@
 
 import * as beet from '@metaplex-foundation/beet'
 /**
+ * This type is used to derive the {@link Period} type as well as the de/serializer.
+ * However don't refer to it in your code but use the {@link Period} type instead.
+ *
+ * @category userTypes
  * @category enums
  * @category generated
+ * @private
  */
-export enum Period {
-  OneTime,
-  Day,
-  Week,
-  Month,
+export type PeriodRecord = {
+  OneTime: void /* scalar variant */
+  Day: void /* scalar variant */
+  Week: void /* scalar variant */
+  Month: void /* scalar variant */
+  Custom: { seconds: beet.bignum }
+  CalendarMonth: { anchor: beet.bignum }
+  SlidingWindow: { seconds: beet.bignum }
 }
 
+/**
+ * Union type respresenting the Period data enum defined in Rust.
+ *
+ * NOTE: that it includes a `__kind` property which allows to narrow types in
+ * switch/if statements.
+ * Additionally `isPeriod*` type guards are exposed below to narrow to a specific variant.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ */
+export type Period = beet.DataEnumKeyAsKind<PeriodRecord>
+
+export const isPeriodOneTime = (x: Period): x is Period & {
+  __kind: 'OneTime'
+} => x.__kind === 'OneTime'
+export const isPeriodDay = (x: Period): x is Period & { __kind: 'Day' } =>
+  x.__kind === 'Day'
+export const isPeriodWeek = (x: Period): x is Period & { __kind: 'Week' } =>
+  x.__kind === 'Week'
+export const isPeriodMonth = (x: Period): x is Period & { __kind: 'Month' } =>
+  x.__kind === 'Month'
+export const isPeriodCustom = (x: Period): x is Period & { __kind: 'Custom' } =>
+  x.__kind === 'Custom'
+export const isPeriodCalendarMonth = (x: Period): x is Period & {
+  __kind: 'CalendarMonth'
+} => x.__kind === 'CalendarMonth'
+export const isPeriodSlidingWindow = (x: Period): x is Period & {
+  __kind: 'SlidingWindow'
+} => x.__kind === 'SlidingWindow'
+
 /**
  * @category userTypes
  * @category generated
  */
-export const periodBeet = beet.fixedScalarEnum(Period) as beet.FixedSizeBeet<
-  Period,
-  Period
->
+export const periodBeet = beet.dataEnum<PeriodRecord>([
+  ['OneTime', beet.unit],
+  ['Day', beet.unit],
+  ['Week', beet.unit],
+  ['Month', beet.unit],
+
+  [
+    'Custom',
+    new beet.BeetArgsStruct<PeriodRecord['Custom']>(
+      [['seconds', beet.i64]],
+      'PeriodRecord["Custom"]'
+    ),
+  ],
+
+  [
+    'CalendarMonth',
+    new beet.BeetArgsStruct<PeriodRecord['CalendarMonth']>(
+      [['anchor', beet.i64]],
+      'PeriodRecord["CalendarMonth"]'
+    ),
+  ],
+
+  [
+    'SlidingWindow',
+    new beet.BeetArgsStruct<PeriodRecord['SlidingWindow']>(
+      [['seconds', beet.i64]],
+      'PeriodRecord["SlidingWindow"]'
+    ),
+  ],
+]) as beet.FixableBeet<Period, Period>
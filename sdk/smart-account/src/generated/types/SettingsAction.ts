@@ -13,6 +13,15 @@ import {
   smartAccountSignerBeet,
 } from './SmartAccountSigner'
 import { Period, periodBeet } from './Period'
+import { TimeLockBypass, timeLockBypassBeet } from './TimeLockBypass'
+import {
+  SpendingLimitUpdate,
+  spendingLimitUpdateBeet,
+} from './SpendingLimitUpdate'
+import {
+  SpendingLimitOptions,
+  spendingLimitOptionsBeet,
+} from './SpendingLimitOptions'
 /**
  * This type is used to derive the {@link SettingsAction} type as well as the de/serializer.
  * However don't refer to it in your code but use the {@link SettingsAction} type instead.
@@ -39,6 +48,22 @@ export type SettingsActionRecord = {
   }
   RemoveSpendingLimit: { spendingLimit: web3.PublicKey }
   SetArchivalAuthority: { newArchivalAuthority: beet.COption<web3.PublicKey> }
+  SetTimeLockBypass: { newTimeLockBypass: TimeLockBypass }
+  UpdateSpendingLimit: {
+    spendingLimit: web3.PublicKey
+    update: SpendingLimitUpdate
+  }
+  AddSpendingLimitWithOptions: {
+    seed: web3.PublicKey
+    accountIndex: number
+    mint: web3.PublicKey
+    amount: beet.bignum
+    period: Period
+    signers: web3.PublicKey[]
+    destinations: web3.PublicKey[]
+    expiration: beet.bignum
+    options: SpendingLimitOptions
+  }
 }
 
 /**
@@ -80,6 +105,18 @@ export const isSettingsActionSetArchivalAuthority = (
   x: SettingsAction
 ): x is SettingsAction & { __kind: 'SetArchivalAuthority' } =>
   x.__kind === 'SetArchivalAuthority'
+export const isSettingsActionSetTimeLockBypass = (
+  x: SettingsAction
+): x is SettingsAction & { __kind: 'SetTimeLockBypass' } =>
+  x.__kind === 'SetTimeLockBypass'
+export const isSettingsActionUpdateSpendingLimit = (
+  x: SettingsAction
+): x is SettingsAction & { __kind: 'UpdateSpendingLimit' } =>
+  x.__kind === 'UpdateSpendingLimit'
+export const isSettingsActionAddSpendingLimitWithOptions = (
+  x: SettingsAction
+): x is SettingsAction & { __kind: 'AddSpendingLimitWithOptions' } =>
+  x.__kind === 'AddSpendingLimitWithOptions'
 
 /**
  * @category userTypes
@@ -152,4 +189,43 @@ export const settingsActionBeet = beet.dataEnum<SettingsActionRecord>([
       'SettingsActionRecord["SetArchivalAuthority"]'
     ),
   ],
+
+  [
+    'SetTimeLockBypass',
+    new beet.FixableBeetArgsStruct<SettingsActionRecord['SetTimeLockBypass']>(
+      [['newTimeLockBypass', timeLockBypassBeet]],
+      'SettingsActionRecord["SetTimeLockBypass"]'
+    ),
+  ],
+
+  [
+    'UpdateSpendingLimit',
+    new beet.FixableBeetArgsStruct<SettingsActionRecord['UpdateSpendingLimit']>(
+      [
+        ['spendingLimit', beetSolana.publicKey],
+        ['update', spendingLimitUpdateBeet],
+      ],
+      'SettingsActionRecord["UpdateSpendingLimit"]'
+    ),
+  ],
+
+  [
+    'AddSpendingLimitWithOptions',
+    new beet.FixableBeetArgsStruct<
+      SettingsActionRecord['AddSpendingLimitWithOptions']
+    >(
+      [
+        ['seed', beetSolana.publicKey],
+        ['accountIndex', beet.u8],
+        ['mint', beetSolana.publicKey],
+        ['amount', beet.u64],
+        ['period', periodBeet],
+        ['signers', beet.array(beetSolana.publicKey)],
+        ['destinations', beet.array(beetSolana.publicKey)],
+        ['expiration', beet.i64],
+        ['options', spendingLimitOptionsBeet],
+      ],
+      'SettingsActionRecord["AddSpendingLimitWithOptions"]'
+    ),
+  ],
 ]) as beet.FixableBeet<SettingsAction, SettingsAction>
@@ -1,34 +1,70 @@
 export * from './AddSignerArgs'
 export * from './AddSpendingLimitArgs'
 export * from './AddTransactionToBatchArgs'
+export * from './BatchEventType'
 export * from './ChangeThresholdArgs'
+export * from './CloseTransactionArgs'
+export * from './CompressedNftTransferRequest'
 export * from './CreateBatchArgs'
 export * from './CreateProposalArgs'
 export * from './CreateSettingsTransactionArgs'
 export * from './CreateSmartAccountArgs'
 export * from './CreateTransactionArgs'
 export * from './CreateTransactionBufferArgs'
+export * from './CreateTransactionV2Args'
+export * from './ExecuteTransactionV2Args'
 export * from './ExtendTransactionBufferArgs'
 export * from './InitProgramConfigArgs'
 export * from './LogEventArgs'
+export * from './MintAllowance'
+export * from './MintAmount'
+export * from './NftAllowance'
+export * from './NftCount'
+export * from './NftGroup'
+export * from './PauseSpendingLimitArgs'
 export * from './Period'
 export * from './Permissions'
+export * from './PriceOracle'
 export * from './ProgramConfigSetAuthorityArgs'
 export * from './ProgramConfigSetSmartAccountCreationFeeArgs'
 export * from './ProgramConfigSetTreasuryArgs'
+export * from './ProposalEventType'
 export * from './ProposalStatus'
 export * from './RemoveSignerArgs'
 export * from './RemoveSpendingLimitArgs'
 export * from './SetArchivalAuthorityArgs'
 export * from './SetNewSettingsAuthorityArgs'
 export * from './SetTimeLockArgs'
+export * from './SetTimeLockBypassArgs'
 export * from './SettingsAction'
+export * from './SettingsExtension'
+export * from './SignerAllowance'
+export * from './SignerAmount'
+export * from './SlidingWindowBucket'
 export * from './SmartAccountCompiledInstruction'
+export * from './SmartAccountEventKind'
 export * from './SmartAccountMessageAddressTableLookup'
 export * from './SmartAccountSigner'
 export * from './SmartAccountTransactionMessage'
+export * from './SpendingLimitExtension'
+export * from './SpendingLimitOptions'
+export * from './SpendingLimitTransfer'
+export * from './SpendingLimitUpdate'
+export * from './SpendingLimitUse'
 export * from './SyncSettingsTransactionArgs'
 export * from './SyncTransactionArgs'
+export * from './SyncTransactionV2Args'
+export * from './TimeLockBypass'
+export * from './TransactionBufferEventType'
+export * from './TransactionEventType'
+export * from './TransactionExtension'
+export * from './TransferFeeCharging'
+export * from './UpdateSpendingLimitArgs'
+export * from './UseCompressedNftSpendingLimitArgs'
+export * from './UseNftSpendingLimitArgs'
 export * from './UseSpendingLimitArgs'
+export * from './UseSpendingLimitBatchArgs'
+export * from './UseSpendingLimitSyncArgs'
 export * from './Vote'
 export * from './VoteOnProposalArgs'
+export * from './WriteTransactionBufferArgs'
@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { getProposalPda } from "../pda";
 import { createActivateProposalInstruction, PROGRAM_ID } from "../generated";
+import { logAccountMetas } from "../utils";
 
 export function activateProposal({
   settingsPda,
@@ -24,6 +25,7 @@ export function activateProposal({
       settings: settingsPda,
       proposal: proposalPda,
       signer,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     programId
   );
@@ -4,6 +4,7 @@ import {
   SmartAccountSigner,
   PROGRAM_ID,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function addSignerAsAuthority({
   settingsPda,
@@ -27,6 +28,9 @@ export function addSignerAsAuthority({
       rentPayer,
       systemProgram: SystemProgram.programId,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     { args: { newSigner, memo: memo ?? null } },
     programId
@@ -4,7 +4,23 @@ import {
   createAddSpendingLimitAsAuthorityInstruction,
   Period,
   PROGRAM_ID,
+  SpendingLimitOptions,
+  TransferFeeCharging,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
+
+/** The options of a spending limit that only uses the original layout's features. */
+const DEFAULT_SPENDING_LIMIT_OPTIONS: SpendingLimitOptions = {
+  additionalMints: [],
+  signerAllowances: [],
+  transferFeeCharging: TransferFeeCharging.Gross,
+  priceOracle: null,
+  pausers: [],
+  nftAllowances: [],
+  usageHistoryCapacity: 0,
+  threshold: 1,
+  allowedPrograms: [],
+};
 
 export function addSpendingLimitAsAuthority({
   settingsPda,
@@ -19,6 +35,7 @@ export function addSpendingLimitAsAuthority({
   signers,
   destinations,
   expiration,
+  options,
   memo,
   programId = PROGRAM_ID,
 }: {
@@ -34,6 +51,7 @@ export function addSpendingLimitAsAuthority({
   signers: PublicKey[];
   destinations: PublicKey[];
   expiration?: number;
+  options?: SpendingLimitOptions;
   memo?: string;
   programId?: PublicKey;
 }) {
@@ -45,6 +63,9 @@ export function addSpendingLimitAsAuthority({
       systemProgram: SystemProgram.programId,
       spendingLimit,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
@@ -58,6 +79,7 @@ export function addSpendingLimitAsAuthority({
         expiration: expiration
           ? new BN(expiration)
           : new BN("9223372036854775807"),
+        options: options ?? DEFAULT_SPENDING_LIMIT_OPTIONS,
         memo: memo ?? null,
       },
     },
@@ -10,7 +10,10 @@ import {
   getTransactionPda,
   getSmartAccountPda,
 } from "../pda";
-import { transactionMessageToMultisigTransactionMessageBytes } from "../utils";
+import {
+  transactionMessageToMultisigTransactionMessageBytes,
+  logAccountMetas,
+} from "../utils";
 
 export function addTransactionToBatch({
   accountIndex,
@@ -77,6 +80,7 @@ export function addTransactionToBatch({
       rentPayer: rentPayer ?? signer,
       batch: batchPda,
       transaction: batchTransactionPda,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     {
       args: {
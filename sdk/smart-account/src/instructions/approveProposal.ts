@@ -1,13 +1,14 @@
 import { getProposalPda } from "../pda";
-import { createApproveProposalInstruction } from "../generated";
+import { createApproveProposalInstruction, PROGRAM_ID } from "../generated";
 import { PublicKey } from "@solana/web3.js";
+import { logAccountMetas } from "../utils";
 
 export function approveProposal({
   settingsPda,
   transactionIndex,
   signer,
   memo,
-  programId,
+  programId = PROGRAM_ID,
 }: {
   settingsPda: PublicKey;
   transactionIndex: bigint;
@@ -22,7 +23,12 @@ export function approveProposal({
   });
 
   return createApproveProposalInstruction(
-    { settings: settingsPda, proposal: proposalPda, signer },
+    {
+      settings: settingsPda,
+      proposal: proposalPda,
+      signer,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+    },
     { args: { memo: memo ?? null } },
     programId
   );
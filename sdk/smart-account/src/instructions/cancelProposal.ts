@@ -1,6 +1,7 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { createCancelProposalInstruction, PROGRAM_ID } from "../generated";
 import { getProposalPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function cancelProposal({
   settingsPda,
@@ -22,7 +23,13 @@ export function cancelProposal({
   });
 
   return createCancelProposalInstruction(
-    { settings: settingsPda, proposal: proposalPda, signer, systemProgram: SystemProgram.programId },
+    {
+      settings: settingsPda,
+      proposal: proposalPda,
+      signer,
+      systemProgram: SystemProgram.programId,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+    },
     { args: { memo: memo ?? null } },
     programId
   );
@@ -3,6 +3,7 @@ import {
   createChangeThresholdAsAuthorityInstruction,
   PROGRAM_ID,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function changeThresholdAsAuthority({
   settingsPda,
@@ -25,6 +26,9 @@ export function changeThresholdAsAuthority({
       settingsAuthority,
       rentPayer,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
@@ -1,6 +1,11 @@
 import { PublicKey } from "@solana/web3.js";
-import { createCloseBatchInstruction, PROGRAM_ID } from "../generated";
+import {
+  createCloseBatchInstruction,
+  createCloseBatchV2Instruction,
+  PROGRAM_ID,
+} from "../generated";
 import { getProposalPda, getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 /**
  * Closes Batch and the corresponding Proposal accounts for proposals in terminal states:
@@ -13,12 +18,14 @@ export function closeBatch({
   settingsPda,
   batchRentCollector,
   batchIndex,
+  memo,
   programId = PROGRAM_ID,
   proposalRentCollector = batchRentCollector,
 }: {
   settingsPda: PublicKey;
   batchRentCollector: PublicKey;
   batchIndex: bigint;
+  memo?: string;
   programId?: PublicKey;
   proposalRentCollector?: PublicKey;
 }) {
@@ -33,14 +40,17 @@ export function closeBatch({
     programId,
   });
 
-  return createCloseBatchInstruction(
-    {
-      settings: settingsPda,
-      batchRentCollector,
-      proposalRentCollector,
-      proposal: proposalPda,
-      batch: batchPda,
-    },
-    programId
-  );
+  const accounts = {
+    settings: settingsPda,
+    batchRentCollector,
+    proposalRentCollector,
+    proposal: proposalPda,
+    batch: batchPda,
+    anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+  };
+
+  // Only the v2 instruction takes a memo.
+  return memo
+    ? createCloseBatchV2Instruction(accounts, { args: { memo } }, programId)
+    : createCloseBatchInstruction(accounts, programId);
 }
@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import {
   createCloseBatchTransactionInstruction,
+  createCloseBatchTransactionV2Instruction,
   PROGRAM_ID,
 } from "../generated";
 import {
@@ -8,6 +9,7 @@ import {
   getProposalPda,
   getTransactionPda,
 } from "../pda";
+import { logAccountMetas } from "../utils";
 
 /**
  * Closes a VaultBatchTransaction belonging to the Batch and Proposal defined by `batchIndex`.
@@ -21,12 +23,14 @@ export function closeBatchTransaction({
   transactionRentCollector,
   batchIndex,
   transactionIndex,
+  memo,
   programId = PROGRAM_ID,
 }: {
   settingsPda: PublicKey;
   transactionRentCollector: PublicKey;
   batchIndex: bigint;
   transactionIndex: number;
+  memo?: string;
   programId?: PublicKey;
 }) {
   const [proposalPda] = getProposalPda({
@@ -46,14 +50,21 @@ export function closeBatchTransaction({
     programId,
   });
 
-  return createCloseBatchTransactionInstruction(
-    {
-      settings: settingsPda,
-      transactionRentCollector,
-      proposal: proposalPda,
-      batch: batchPda,
-      transaction: batchTransactionPda,
-    },
-    programId
-  );
+  const accounts = {
+    settings: settingsPda,
+    transactionRentCollector,
+    proposal: proposalPda,
+    batch: batchPda,
+    transaction: batchTransactionPda,
+    anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+  };
+
+  // Only the v2 instruction takes a memo.
+  return memo
+    ? createCloseBatchTransactionV2Instruction(
+        accounts,
+        { args: { memo } },
+        programId
+      )
+    : createCloseBatchTransactionInstruction(accounts, programId);
 }
@@ -1,20 +1,24 @@
 import { PublicKey } from "@solana/web3.js";
 import {
   createCloseSettingsTransactionInstruction,
+  createCloseSettingsTransactionV2Instruction,
   PROGRAM_ID,
 } from "../generated";
 import { getProposalPda, getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function closeSettingsTransaction({
   settingsPda,
   transactionRentCollector,
   transactionIndex,
+  memo,
   programId = PROGRAM_ID,
   proposalRentCollector = transactionRentCollector,
 }: {
   settingsPda: PublicKey;
   transactionRentCollector: PublicKey;
   transactionIndex: bigint;
+  memo?: string;
   programId?: PublicKey;
   proposalRentCollector?: PublicKey;
 }) {
@@ -29,14 +33,21 @@ export function closeSettingsTransaction({
     programId,
   });
 
-  return createCloseSettingsTransactionInstruction(
-    {
-      settings: settingsPda,
-      transactionRentCollector,
-      proposal: proposalPda,
-      proposalRentCollector,
-      transaction: transactionPda,
-    },
-    programId
-  );
+  const accounts = {
+    settings: settingsPda,
+    transactionRentCollector,
+    proposal: proposalPda,
+    proposalRentCollector,
+    transaction: transactionPda,
+    anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+  };
+
+  // Only the v2 instruction takes a memo.
+  return memo
+    ? createCloseSettingsTransactionV2Instruction(
+        accounts,
+        { args: { memo } },
+        programId
+      )
+    : createCloseSettingsTransactionInstruction(accounts, programId);
 }
@@ -1,20 +1,24 @@
 import { PublicKey } from "@solana/web3.js";
 import {
   createCloseTransactionInstruction,
+  createCloseTransactionV2Instruction,
   PROGRAM_ID,
 } from "../generated";
 import { getProposalPda, getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function closeTransaction({
   settingsPda,
   transactionRentCollector,
   transactionIndex,
+  memo,
   programId = PROGRAM_ID,
   proposalRentCollector = transactionRentCollector,
 }: {
   settingsPda: PublicKey;
   transactionRentCollector: PublicKey;
   transactionIndex: bigint;
+  memo?: string;
   programId?: PublicKey;
   proposalRentCollector?: PublicKey;
 }) {
@@ -29,15 +33,21 @@ export function closeTransaction({
     programId,
   });
 
-  return createCloseTransactionInstruction(
-    {
-      settings: settingsPda,
+  const accounts = {
+    settings: settingsPda,
+    proposal: proposalPda,
+    proposalRentCollector,
+    transaction: transactionPda,
+    transactionRentCollector,
+    anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+  };
 
-      proposal: proposalPda,
-      proposalRentCollector,
-      transaction: transactionPda,
-      transactionRentCollector,
-    },
-    programId
-  );
+  // Only the v2 instruction takes a memo.
+  return memo
+    ? createCloseTransactionV2Instruction(
+        accounts,
+        { args: { memo } },
+        programId
+      )
+    : createCloseTransactionInstruction(accounts, programId);
 }
@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { createCreateBatchInstruction, PROGRAM_ID } from "../generated";
 import { getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function createBatch({
   settingsPda,
@@ -33,6 +34,7 @@ export function createBatch({
       creator,
       rentPayer: rentPayer ?? creator,
       batch: batchPda,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     { args: { accountIndex, memo: memo ?? null } },
     programId
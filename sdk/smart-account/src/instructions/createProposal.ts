@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { createCreateProposalInstruction, PROGRAM_ID } from "../generated";
 import { getProposalPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function createProposal({
   settingsPda,
@@ -35,6 +36,7 @@ export function createProposal({
       rentPayer: rentPayer ?? creator,
       settings: settingsPda,
       proposal: proposalPda,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     { args: { transactionIndex: Number(transactionIndex), draft: isDraft } },
     programId
@@ -5,6 +5,7 @@ import {
   PROGRAM_ID,
 } from "../generated";
 import { getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function createSettingsTransaction({
   settingsPda,
@@ -37,6 +38,7 @@ export function createSettingsTransaction({
       transaction: transactionPda,
       creator,
       rentPayer: rentPayer ?? creator,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     { args: { actions, memo: memo ?? null } },
     programId
@@ -9,6 +9,7 @@ import {
   SmartAccountSigner,
 } from "../generated";
 import { getProgramConfigPda } from "../pda";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function createSmartAccount({
   treasury,
@@ -50,6 +51,10 @@ export function createSmartAccount({
       anchorRemainingAccounts: [
         settingsAccountMeta,
         ...(remainingAccounts ?? []),
+        // The event sequence can only be derived from a known settings address.
+        ...(settings
+          ? [eventSequenceAccountMeta({ settingsPda: settings, programId })]
+          : []),
       ],
     },
     {
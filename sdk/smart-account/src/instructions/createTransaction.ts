@@ -8,7 +8,10 @@ import {
   TransactionMessage,
 } from "@solana/web3.js";
 import { getTransactionPda, getSmartAccountPda } from "../pda";
-import { transactionMessageToMultisigTransactionMessageBytes } from "../utils";
+import {
+  transactionMessageToMultisigTransactionMessageBytes,
+  logAccountMetas,
+} from "../utils";
 
 export function createTransaction({
   settingsPda,
@@ -61,6 +64,7 @@ export function createTransaction({
       transaction: transactionPda,
       creator,
       rentPayer: rentPayer ?? creator,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
     },
     {
       args: {
@@ -16,7 +16,7 @@ import {
   getTransactionPda,
   getSmartAccountPda,
 } from "../pda";
-import { accountsForTransactionExecute } from "../utils";
+import { accountsForTransactionExecute, logAccountMetas } from "../utils";
 
 export async function executeBatchTransaction({
   connection,
@@ -80,7 +80,10 @@ export async function executeBatchTransaction({
         proposal: proposalPda,
         batch: batchPda,
         transaction: batchTransactionPda,
-        anchorRemainingAccounts: accountMetas,
+        anchorRemainingAccounts: [
+          ...accountMetas,
+          ...logAccountMetas({ settingsPda, programId }),
+        ],
       },
       programId
     ),
@@ -4,6 +4,7 @@ import {
   PROGRAM_ID,
 } from "../generated";
 import { getProposalPda, getTransactionPda } from "../pda";
+import { logAccountMetas } from "../utils";
 
 export function executeSettingsTransaction({
   settingsPda,
@@ -40,11 +41,14 @@ export function executeSettingsTransaction({
       transaction: transactionPda,
       rentPayer: rentPayer ?? signer,
       systemProgram: SystemProgram.programId,
-      anchorRemainingAccounts: spendingLimits?.map((spendingLimit) => ({
-        pubkey: spendingLimit,
-        isWritable: true,
-        isSigner: false,
-      })),
+      anchorRemainingAccounts: [
+        ...(spendingLimits ?? []).map((spendingLimit) => ({
+          pubkey: spendingLimit,
+          isWritable: true,
+          isSigner: false,
+        })),
+        ...logAccountMetas({ settingsPda, programId }),
+      ],
     },
     programId
   );
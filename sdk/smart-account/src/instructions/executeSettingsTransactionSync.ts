@@ -1,5 +1,6 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { SettingsAction, createExecuteSettingsTransactionSyncInstruction, PROGRAM_ID } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function executeSettingsTransactionSync({
     settingsPda,
@@ -39,5 +40,6 @@ export function executeSettingsTransactionSync({
         isSigner: true,
         isWritable: false,
     })));
+    ix.keys.push(eventSequenceAccountMeta({ settingsPda, programId }));
     return ix;
 }
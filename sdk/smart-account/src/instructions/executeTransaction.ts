@@ -6,23 +6,31 @@ import {
 } from "@solana/web3.js";
 import {
   createExecuteTransactionInstruction,
+  createExecuteTransactionV2Instruction,
   PROGRAM_ID,
   Transaction,
 } from "../generated";
 import { getProposalPda, getSmartAccountPda, getTransactionPda } from "../pda";
-import { accountsForTransactionExecute } from "../utils";
+import {
+  accountsForTransactionExecute,
+  logAccountMetas,
+  transactionMessageBytesToSmartAccountTransactionMessage,
+} from "../utils";
 
 export async function executeTransaction({
   connection,
   settingsPda,
   transactionIndex,
   signer,
+  transactionMessage,
   programId = PROGRAM_ID,
 }: {
   connection: Connection;
   settingsPda: PublicKey;
   transactionIndex: bigint;
   signer: PublicKey;
+  /** The serialized `TransactionMessage` of a transaction created with only its hash. */
+  transactionMessage?: Uint8Array;
   programId?: PublicKey;
 }): Promise<{
   instruction: TransactionInstruction;
@@ -49,27 +57,52 @@ export async function executeTransaction({
     programId,
   });
 
+  // Hash-committed transactions don't store their message, which is revealed instead.
+  const message = transactionMessage
+    ? transactionMessageBytesToSmartAccountTransactionMessage(
+        transactionMessage
+      )
+    : transactionAccount.message;
+
   const { accountMetas, lookupTableAccounts } =
     await accountsForTransactionExecute({
       connection,
-      message: transactionAccount.message,
+      message,
       ephemeralSignerBumps: [...transactionAccount.ephemeralSignerBumps],
       smartAccountPda,
       transactionPda,
       programId,
     });
 
+  const accounts = {
+    settings: settingsPda,
+    signer,
+    proposal: proposalPda,
+    transaction: transactionPda,
+  };
+
   return {
-    instruction: createExecuteTransactionInstruction(
-      {
-        settings: settingsPda,
-        signer,
-        proposal: proposalPda,
-        transaction: transactionPda,
-        anchorRemainingAccounts: accountMetas,
-      },
-      programId
-    ),
+    // Only the v2 instruction takes a revealed message.
+    instruction: transactionMessage
+      ? createExecuteTransactionV2Instruction(
+          {
+            ...accounts,
+            program: programId,
+            anchorRemainingAccounts: accountMetas,
+          },
+          { args: { transactionMessage } },
+          programId
+        )
+      : createExecuteTransactionInstruction(
+          {
+            ...accounts,
+            anchorRemainingAccounts: [
+              ...accountMetas,
+              ...logAccountMetas({ settingsPda, programId }),
+            ],
+          },
+          programId
+        ),
     lookupTableAccounts,
   };
 }
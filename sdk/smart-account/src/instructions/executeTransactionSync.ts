@@ -3,6 +3,7 @@ import {
   createExecuteTransactionSyncInstruction,
   PROGRAM_ID,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function executeTransactionSync({
   settingsPda,
@@ -27,7 +28,10 @@ export function executeTransactionSync({
     {
       settings: settingsPda,
       program: programId,
-      anchorRemainingAccounts: instruction_accounts,
+      anchorRemainingAccounts: [
+        ...instruction_accounts,
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
@@ -0,0 +1,28 @@
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { getEventSequencePda } from "../pda";
+import {
+  createInitializeEventSequenceInstruction,
+  PROGRAM_ID,
+} from "../generated";
+
+export function initializeEventSequence({
+  settingsPda,
+  rentPayer,
+  programId = PROGRAM_ID,
+}: {
+  settingsPda: PublicKey;
+  rentPayer: PublicKey;
+  programId?: PublicKey;
+}) {
+  const [eventSequencePda] = getEventSequencePda({ settingsPda, programId });
+
+  return createInitializeEventSequenceInstruction(
+    {
+      settings: settingsPda,
+      eventSequence: eventSequencePda,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    },
+    programId
+  );
+}
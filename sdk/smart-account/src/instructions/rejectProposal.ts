@@ -1,6 +1,7 @@
 import { getProposalPda } from "../pda";
 import { createRejectProposalInstruction, PROGRAM_ID } from "../generated";
 import { PublicKey } from "@solana/web3.js";
+import { logAccountMetas } from "../utils";
 
 export function rejectProposal({
   settingsPda,
@@ -22,7 +23,12 @@ export function rejectProposal({
   });
 
   return createRejectProposalInstruction(
-    { settings: settingsPda, proposal: proposalPda, signer },
+    {
+      settings: settingsPda,
+      proposal: proposalPda,
+      signer,
+      anchorRemainingAccounts: logAccountMetas({ settingsPda, programId }),
+    },
     { args: { memo: memo ?? null } },
     programId
   );
@@ -3,6 +3,7 @@ import {
   createRemoveSignerAsAuthorityInstruction,
   PROGRAM_ID,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function removeSignerAsAuthority({
   settingsPda,
@@ -23,6 +24,9 @@ export function removeSignerAsAuthority({
       settingsAuthority,
       systemProgram: SystemProgram.programId,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     { args: { oldSigner, memo: memo ?? null } },
     programId
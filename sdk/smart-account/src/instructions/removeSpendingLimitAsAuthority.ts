@@ -5,6 +5,7 @@ import {
   Period,
   PROGRAM_ID,
 } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function removeSpendingLimitAsAuthority({
   settingsPda,
@@ -28,6 +29,9 @@ export function removeSpendingLimitAsAuthority({
       spendingLimit,
       rentCollector,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
@@ -1,5 +1,6 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { createSetArchivalAuthorityAsAuthorityInstruction, PROGRAM_ID } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function setArchivalAuthorityAsAuthority({
   settingsPda,
@@ -20,6 +21,9 @@ export function setArchivalAuthorityAsAuthority({
       settingsAuthority,
       systemProgram: SystemProgram.programId,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
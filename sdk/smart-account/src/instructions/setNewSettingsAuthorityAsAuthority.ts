@@ -1,5 +1,6 @@
 import { PublicKey } from "@solana/web3.js";
 import { createSetNewSettingsAuthorityAsAuthorityInstruction, PROGRAM_ID } from "../generated";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function setNewSettingsAuthorityAsAuthority({
   settingsPda,
@@ -19,6 +20,9 @@ export function setNewSettingsAuthorityAsAuthority({
       settings: settingsPda,
       settingsAuthority,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     {
       args: {
@@ -10,6 +10,7 @@ import {
 } from "@solana/spl-token";
 import { createUseSpendingLimitInstruction, PROGRAM_ID } from "../generated";
 import {  getSmartAccountPda } from "../pda";
+import { eventSequenceAccountMeta } from "../utils";
 
 export function useSpendingLimit({
   settingsPda,
@@ -72,6 +73,9 @@ export function useSpendingLimit({
       destinationTokenAccount,
       tokenProgram: mint ? tokenProgram : undefined,
       program: programId,
+      anchorRemainingAccounts: [
+        eventSequenceAccountMeta({ settingsPda, programId }),
+      ],
     },
     { args: { amount, decimals, memo: memo ?? null } },
     programId
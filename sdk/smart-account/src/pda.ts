@@ -12,6 +12,7 @@ const SEED_PROPOSAL = toUtfBytes("proposal");
 const SEED_BATCH_TRANSACTION = toUtfBytes("batch_transaction");
 const SEED_EPHEMERAL_SIGNER = toUtfBytes("ephemeral_signer");
 const SEED_SPENDING_LIMIT = toUtfBytes("spending_limit");
+const SEED_EVENT_SEQUENCE = toUtfBytes("event_sequence");
 
 export function getProgramConfigPda({
   programId = PROGRAM_ID,
@@ -166,3 +167,16 @@ export function getSpendingLimitPda({
     programId
   );
 }
+
+export function getEventSequencePda({
+  settingsPda,
+  programId = PROGRAM_ID,
+}: {
+  settingsPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, settingsPda.toBytes(), SEED_EVENT_SEQUENCE],
+    programId
+  );
+}
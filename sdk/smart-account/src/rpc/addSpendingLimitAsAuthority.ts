@@ -5,7 +5,7 @@ import {
   Signer,
   TransactionSignature,
 } from "@solana/web3.js";
-import { Period, SmartAccountSigner } from "../generated";
+import { Period, SmartAccountSigner, SpendingLimitOptions } from "../generated";
 import * as transactions from "../transactions/index";
 import { translateAndThrowAnchorError } from "../errors";
 
@@ -30,6 +30,7 @@ export async function addSpendingLimitAsAuthority({
   sendOptions,
   programId,
   expiration,
+  options,
 }: {
   connection: Connection;
   feePayer: Signer;
@@ -48,6 +49,7 @@ export async function addSpendingLimitAsAuthority({
   sendOptions?: SendOptions;
   programId?: PublicKey;
   expiration?: number;
+  options?: SpendingLimitOptions;
 }): Promise<TransactionSignature> {
   const blockhash = (await connection.getLatestBlockhash()).blockhash;
 
@@ -66,6 +68,7 @@ export async function addSpendingLimitAsAuthority({
     period,
     destinations,
     expiration,
+    options,
     memo,
     programId,
   });
@@ -23,6 +23,7 @@ export async function closeBatch({
   batchIndex,
   proposalRentCollector,
   sendOptions,
+  memo,
   programId,
 }: {
   connection: Connection;
@@ -32,6 +33,7 @@ export async function closeBatch({
   batchIndex: bigint;
   proposalRentCollector?: PublicKey;
   sendOptions?: SendOptions;
+  memo?: string;
   programId?: PublicKey;
 }): Promise<TransactionSignature> {
   const blockhash = (await connection.getLatestBlockhash()).blockhash;
@@ -43,6 +45,7 @@ export async function closeBatch({
     batchIndex,
     proposalRentCollector,
     settingsPda,
+    memo,
     programId,
   });
 
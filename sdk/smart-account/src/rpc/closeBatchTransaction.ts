@@ -23,6 +23,7 @@ export async function closeBatchTransaction({
   batchIndex,
   transactionIndex,
   sendOptions,
+  memo,
   programId,
 }: {
   connection: Connection;
@@ -32,6 +33,7 @@ export async function closeBatchTransaction({
   batchIndex: bigint;
   transactionIndex: number;
   sendOptions?: SendOptions;
+  memo?: string;
   programId?: PublicKey;
 }): Promise<TransactionSignature> {
   const blockhash = (await connection.getLatestBlockhash()).blockhash;
@@ -43,6 +45,7 @@ export async function closeBatchTransaction({
     batchIndex,
     transactionIndex,
     settingsPda,
+    memo,
     programId,
   });
 
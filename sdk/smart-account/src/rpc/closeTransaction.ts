@@ -18,6 +18,7 @@ export async function closeTransaction({
   transactionRentCollector,
   transactionIndex,
   sendOptions,
+  memo,
   programId,
   proposalRentCollector,
 }: {
@@ -27,6 +28,7 @@ export async function closeTransaction({
   transactionRentCollector: PublicKey;
   transactionIndex: bigint;
   sendOptions?: SendOptions;
+  memo?: string;
   programId?: PublicKey;
   proposalRentCollector?: PublicKey;
 }): Promise<TransactionSignature> {
@@ -38,6 +40,7 @@ export async function closeTransaction({
     transactionRentCollector,
     transactionIndex,
     settingsPda,
+    memo,
     programId,
     proposalRentCollector,
   });
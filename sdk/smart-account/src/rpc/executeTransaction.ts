@@ -19,6 +19,7 @@ export async function executeTransaction({
   transactionIndex,
   signer,
   signers,
+  transactionMessage,
   sendOptions,
   programId,
 }: {
@@ -28,6 +29,7 @@ export async function executeTransaction({
   transactionIndex: bigint;
   signer: PublicKey;
   signers?: Signer[];
+  transactionMessage?: Uint8Array;
   sendOptions?: SendOptions;
   programId?: PublicKey;
 }): Promise<TransactionSignature> {
@@ -40,6 +42,7 @@ export async function executeTransaction({
     settingsPda,
     transactionIndex,
     signer,
+    transactionMessage,
     programId,
   });
 
@@ -24,3 +24,4 @@ export * from "./setArchivalAuthorityAsAuthority.js";
 export * from "./setTimeLockAsAuthority.js";
 export * from "./useSpendingLimit.js";
 export * from "./activateProposal.js";
+export * from "./initializeEventSequence.js";
@@ -0,0 +1,48 @@
+import {
+  Connection,
+  PublicKey,
+  SendOptions,
+  Signer,
+  TransactionSignature,
+} from "@solana/web3.js";
+import * as transactions from "../transactions";
+import { translateAndThrowAnchorError } from "../errors";
+
+/** Create the event sequence of a smart account created before event sequences. */
+export async function initializeEventSequence({
+  connection,
+  feePayer,
+  settingsPda,
+  rentPayer,
+  sendOptions,
+  programId,
+}: {
+  connection: Connection;
+  feePayer: Signer;
+  settingsPda: PublicKey;
+  rentPayer?: Signer;
+  sendOptions?: SendOptions;
+  programId?: PublicKey;
+}): Promise<TransactionSignature> {
+  const blockhash = (await connection.getLatestBlockhash()).blockhash;
+
+  const tx = transactions.initializeEventSequence({
+    blockhash,
+    feePayer: feePayer.publicKey,
+    settingsPda,
+    rentPayer: rentPayer?.publicKey ?? feePayer.publicKey,
+    programId,
+  });
+
+  const allSigners = [feePayer];
+  if (rentPayer) {
+    allSigners.push(rentPayer);
+  }
+  tx.sign(allSigners);
+
+  try {
+    return await connection.sendTransaction(tx, sendOptions);
+  } catch (err) {
+    translateAndThrowAnchorError(err);
+  }
+}
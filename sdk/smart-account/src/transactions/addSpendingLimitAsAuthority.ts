@@ -3,7 +3,7 @@ import {
   TransactionMessage,
   VersionedTransaction,
 } from "@solana/web3.js";
-import { Period } from "../generated";
+import { Period, SpendingLimitOptions } from "../generated";
 import * as instructions from "../instructions/index";
 
 /**
@@ -27,6 +27,7 @@ export function addSpendingLimitAsAuthority({
   memo,
   programId,
   expiration,
+  options,
 }: {
   blockhash: string;
   feePayer: PublicKey;
@@ -44,6 +45,7 @@ export function addSpendingLimitAsAuthority({
   memo?: string;
   programId?: PublicKey;
   expiration?: number;
+  options?: SpendingLimitOptions;
 }): VersionedTransaction {
   const message = new TransactionMessage({
     payerKey: feePayer,
@@ -64,6 +66,7 @@ export function addSpendingLimitAsAuthority({
         memo,
         programId,
         expiration,
+        options,
       }),
     ],
   }).compileToV0Message();
@@ -18,6 +18,7 @@ export function closeBatch({
   settingsPda,
   batchRentCollector,
   batchIndex,
+  memo,
   programId,
   proposalRentCollector = batchRentCollector,
 }: {
@@ -27,6 +28,7 @@ export function closeBatch({
   batchRentCollector: PublicKey;
   batchIndex: bigint;
   proposalRentCollector?: PublicKey;
+  memo?: string;
   programId?: PublicKey;
 }): VersionedTransaction {
   const message = new TransactionMessage({
@@ -37,6 +39,7 @@ export function closeBatch({
         settingsPda,
         batchRentCollector,
         batchIndex,
+        memo,
         programId,
         proposalRentCollector
       }),
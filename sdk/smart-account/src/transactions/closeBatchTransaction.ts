@@ -19,6 +19,7 @@ export function closeBatchTransaction({
   transactionRentCollector,
   batchIndex,
   transactionIndex,
+  memo,
   programId,
 }: {
   blockhash: string;
@@ -27,6 +28,7 @@ export function closeBatchTransaction({
   transactionRentCollector: PublicKey;
   batchIndex: bigint;
   transactionIndex: number;
+  memo?: string;
   programId?: PublicKey;
 }): VersionedTransaction {
   const message = new TransactionMessage({
@@ -38,6 +40,7 @@ export function closeBatchTransaction({
         transactionRentCollector,
         batchIndex,
         transactionIndex,
+        memo,
         programId,
       }),
     ],
@@ -11,6 +11,7 @@ export function closeSettingsTransaction({
   settingsPda,
   transactionRentCollector,
   transactionIndex,
+  memo,
   programId,
   proposalRentCollector = transactionRentCollector,
 }: {
@@ -19,6 +20,7 @@ export function closeSettingsTransaction({
   settingsPda: PublicKey;
   transactionRentCollector: PublicKey;
   transactionIndex: bigint;
+  memo?: string;
   programId?: PublicKey;
   proposalRentCollector?: PublicKey;
 }): VersionedTransaction {
@@ -30,6 +32,7 @@ export function closeSettingsTransaction({
         settingsPda,
         transactionRentCollector,
         transactionIndex,
+        memo,
         programId,
         proposalRentCollector,
       }),
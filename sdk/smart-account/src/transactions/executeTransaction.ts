@@ -17,6 +17,7 @@ export async function executeTransaction({
   settingsPda,
   transactionIndex,
   signer,
+  transactionMessage,
   programId,
 }: {
   connection: Connection;
@@ -25,6 +26,7 @@ export async function executeTransaction({
   settingsPda: PublicKey;
   transactionIndex: bigint;
   signer: PublicKey;
+  transactionMessage?: Uint8Array;
   programId?: PublicKey;
 }): Promise<VersionedTransaction> {
   const { instruction, lookupTableAccounts } =
@@ -33,6 +35,7 @@ export async function executeTransaction({
       settingsPda,
       signer,
       transactionIndex,
+      transactionMessage,
       programId,
     });
 
@@ -15,6 +15,7 @@ export * from "./setNewSettingsAuthorityAsAuthority.js";
 export * from "./setArchivalAuthorityAsAuthority.js";
 export * from "./setTimeLockAsAuthority.js";
 export * from "./activateProposal.js";
+export * from "./initializeEventSequence.js";
 export * from "./approveProposal.js";
 export * from "./cancelProposal.js";
 export * from "./createProposal.js";
@@ -0,0 +1,38 @@
+import {
+  PublicKey,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import * as instructions from "../instructions/index.js";
+
+/**
+ * Returns unsigned `VersionedTransaction` that needs to be
+ * signed by `rentPayer` and `feePayer` before sending it.
+ */
+export function initializeEventSequence({
+  blockhash,
+  feePayer,
+  settingsPda,
+  rentPayer,
+  programId,
+}: {
+  blockhash: string;
+  feePayer: PublicKey;
+  settingsPda: PublicKey;
+  rentPayer: PublicKey;
+  programId?: PublicKey;
+}): VersionedTransaction {
+  const message = new TransactionMessage({
+    payerKey: feePayer,
+    recentBlockhash: blockhash,
+    instructions: [
+      instructions.initializeEventSequence({
+        settingsPda,
+        rentPayer,
+        programId,
+      }),
+    ],
+  }).compileToV0Message();
+
+  return new VersionedTransaction(message);
+}
@@ -11,11 +11,45 @@ import {
 import { Buffer } from "buffer";
 import invariant from "invariant";
 import { SmartAccountTransactionMessage } from "./generated";
-import { getEphemeralSignerPda } from "./pda";
+import { getEphemeralSignerPda, getEventSequencePda } from "./pda";
 import { transactionMessageBeet } from "./types";
 import { compileToSynchronousMessageAndAccounts } from "./utils/compileToSynchronousMessage";
 import { compileToWrappedMessageV0 } from "./utils/compileToWrappedMessageV0";
 
+/**
+ * Metas of the `EventSequence` of the settings and of the program account,
+ * which instructions that predate event logging accept as optional trailing
+ * accounts to log their events through, stamped with sequence numbers.
+ */
+export function logAccountMetas({
+  settingsPda,
+  programId,
+}: {
+  settingsPda: PublicKey;
+  programId: PublicKey;
+}): AccountMeta[] {
+  return [
+    eventSequenceAccountMeta({ settingsPda, programId }),
+    { pubkey: programId, isWritable: false, isSigner: false },
+  ];
+}
+
+/**
+ * Meta of the `EventSequence` of the settings, which instructions taking the
+ * program account accept as an optional trailing account to sequence their
+ * events.
+ */
+export function eventSequenceAccountMeta({
+  settingsPda,
+  programId,
+}: {
+  settingsPda: PublicKey;
+  programId: PublicKey;
+}): AccountMeta {
+  const [eventSequencePda] = getEventSequencePda({ settingsPda, programId });
+  return { pubkey: eventSequencePda, isWritable: true, isSigner: false };
+}
+
 export function toUtfBytes(str: string): Uint8Array {
   return new TextEncoder().encode(str);
 }
@@ -154,6 +188,33 @@ export function transactionMessageToMultisigTransactionMessageBytes({
   return transactionMessageBytes;
 }
 
+/** Decodes `transaction_message` bytes into the message layout stored in `Transaction` accounts. */
+export function transactionMessageBytesToSmartAccountTransactionMessage(
+  transactionMessageBytes: Uint8Array
+): SmartAccountTransactionMessage {
+  const [message] = transactionMessageBeet.deserialize(
+    Buffer.from(transactionMessageBytes)
+  );
+
+  return {
+    ...message,
+    instructions: message.instructions.map((ix) => {
+      return {
+        programIdIndex: ix.programIdIndex,
+        accountIndexes: new Uint8Array(ix.accountIndexes),
+        data: new Uint8Array(ix.data),
+      };
+    }),
+    addressTableLookups: message.addressTableLookups.map((lookup) => {
+      return {
+        accountKey: lookup.accountKey,
+        writableIndexes: new Uint8Array(lookup.writableIndexes),
+        readonlyIndexes: new Uint8Array(lookup.readonlyIndexes),
+      };
+    }),
+  };
+}
+
 export function instructionsToSynchronousTransactionDetails({
   vaultPda,
   members,
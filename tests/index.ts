@@ -7,6 +7,7 @@ import "./suites/examples/spending-limits";
 import "./suites/examples/transaction-buffer";
 import "./suites/instructions/batchAccountsClose";
 import "./suites/instructions/cancelRealloc";
+import "./suites/instructions/eventSequenceInit";
 import "./suites/instructions/settingsTransactionAccountsClose";
 import "./suites/instructions/settingsTransactionExecute";
 import "./suites/instructions/settingsTransactionSynchronous";
@@ -18,6 +19,7 @@ import "./suites/instructions/transactionBufferExtend";
 import "./suites/instructions/batchTransactionAccountClose";
 import "./suites/instructions/transactionAccountsClose";
 import "./suites/instructions/transactionCreateFromBuffer";
+import "./suites/instructions/transactionExecute";
 import "./suites/instructions/transactionSynchronous";
 import "./suites/smart-account-sdk";
 // // // Uncomment to enable the heapTest instruction testing
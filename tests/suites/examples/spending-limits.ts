@@ -30,7 +30,6 @@ import {
 } from "../../utils";
 
 const { SpendingLimit } = smartAccount.accounts;
-const { Period } = smartAccount.types;
 
 const programId = getTestProgramId();
 
@@ -69,7 +68,7 @@ describe("Examples / Spending Limits", () => {
       // This means this Spending Limit is for SOL tokens.
       mint: PublicKey.default,
       amount: 10 * LAMPORTS_PER_SOL,
-      period: Period.OneTime,
+      period: { __kind: "OneTime" },
       signers: [members.almighty.publicKey, nonMember.publicKey],
       destinations: [
         Keypair.generate().publicKey,
@@ -109,7 +108,7 @@ describe("Examples / Spending Limits", () => {
       accountIndex: 0,
       mint: splMint,
       amount: 10 * 10 ** mintDecimals,
-      period: Period.OneTime,
+      period: { __kind: "OneTime" },
       signers: [members.almighty.publicKey, nonMember.publicKey],
       destinations: [
         Keypair.generate().publicKey,
@@ -299,7 +298,7 @@ describe("Examples / Spending Limits", () => {
       solSpendingLimitAccount.amount.toString(),
       solSpendingLimitParams.amount.toString()
     );
-    assert.strictEqual(
+    assert.deepStrictEqual(
       solSpendingLimitAccount.period,
       solSpendingLimitParams.period
     );
@@ -0,0 +1,169 @@
+import {
+  PublicKey,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import * as smartAccount from "@sqds/smart-account";
+import assert from "assert";
+import {
+  createAutonomousSmartAccountV2,
+  createLocalhostConnection,
+  fundKeypair,
+  generateSmartAccountSigners,
+  getNextAccountIndex,
+  getTestAccountCreationAuthority,
+  getTestProgramId,
+  getTestProgramTreasury,
+  TestMembers,
+} from "../../utils";
+
+const { EventSequence } = smartAccount.accounts;
+const { Permissions } = smartAccount.types;
+
+const connection = createLocalhostConnection();
+
+const programId = getTestProgramId();
+
+async function getNextSequence(settingsPda: PublicKey) {
+  const [eventSequencePda] = smartAccount.getEventSequencePda({
+    settingsPda,
+    programId,
+  });
+  const eventSequence = await EventSequence.fromAccountAddress(
+    connection,
+    eventSequencePda
+  );
+  return eventSequence.nextSequence.toString();
+}
+
+describe("Instructions / initialize_event_sequence", () => {
+  let members: TestMembers;
+
+  before(async () => {
+    members = await generateSmartAccountSigners(connection);
+  });
+
+  it("sequences the events of a new smart account from its creation", async () => {
+    const accountIndex = await getNextAccountIndex(connection, programId);
+    const [settingsPda] = await createAutonomousSmartAccountV2({
+      connection,
+      accountIndex,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector: null,
+      programId,
+    });
+
+    // The creation event took the first sequence number.
+    assert.strictEqual(await getNextSequence(settingsPda), "1");
+
+    const signature = await smartAccount.rpc.createSettingsTransaction({
+      connection,
+      feePayer: members.proposer,
+      settingsPda,
+      transactionIndex: 1n,
+      creator: members.proposer.publicKey,
+      actions: [{ __kind: "SetTimeLock", newTimeLock: 300 }],
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+
+    assert.strictEqual(await getNextSequence(settingsPda), "2");
+
+    // The event sequence already exists.
+    await assert.rejects(
+      () =>
+        smartAccount.rpc.initializeEventSequence({
+          connection,
+          feePayer: members.proposer,
+          settingsPda,
+          programId,
+        }),
+      /already in use/
+    );
+  });
+
+  it("initializes the event sequence of a smart account created without one", async () => {
+    const creator = getTestAccountCreationAuthority();
+    await fundKeypair(connection, creator);
+
+    const accountIndex = await getNextAccountIndex(connection, programId);
+    const [settingsPda] = smartAccount.getSettingsPda({
+      accountIndex,
+      programId,
+    });
+
+    // Create the smart account the way clients predating event sequences do.
+    const message = new TransactionMessage({
+      payerKey: creator.publicKey,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [
+        smartAccount.generated.createCreateSmartAccountInstruction(
+          {
+            programConfig: smartAccount.getProgramConfigPda({ programId })[0],
+            treasury: getTestProgramTreasury(),
+            creator: creator.publicKey,
+            program: programId,
+            anchorRemainingAccounts: [
+              { pubkey: settingsPda, isSigner: false, isWritable: true },
+            ],
+          },
+          {
+            args: {
+              settingsAuthority: null,
+              threshold: 1,
+              signers: [
+                {
+                  key: members.almighty.publicKey,
+                  permissions: Permissions.all(),
+                },
+              ],
+              timeLock: 0,
+              rentCollector: null,
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+    }).compileToV0Message();
+    const tx = new VersionedTransaction(message);
+    tx.sign([creator]);
+    await connection.confirmTransaction(
+      await connection.sendTransaction(tx)
+    );
+
+    const [eventSequencePda] = smartAccount.getEventSequencePda({
+      settingsPda,
+      programId,
+    });
+    assert.strictEqual(
+      await connection.getAccountInfo(eventSequencePda),
+      null
+    );
+
+    let signature = await smartAccount.rpc.initializeEventSequence({
+      connection,
+      feePayer: members.proposer,
+      settingsPda,
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+
+    assert.strictEqual(await getNextSequence(settingsPda), "0");
+
+    signature = await smartAccount.rpc.createSettingsTransaction({
+      connection,
+      feePayer: members.almighty,
+      settingsPda,
+      transactionIndex: 1n,
+      creator: members.almighty.publicKey,
+      actions: [{ __kind: "SetTimeLock", newTimeLock: 300 }],
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+
+    assert.strictEqual(await getNextSequence(settingsPda), "1");
+  });
+});
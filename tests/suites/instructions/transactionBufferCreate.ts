@@ -627,7 +627,7 @@ describe("Instructions / transaction_buffer_create", () => {
           bufferIndex: bufferIndex,
           accountIndex: 0,
           finalBufferHash: Array.from(messageHash),
-          finalBufferSize: 4001,
+          finalBufferSize: 7015,
           buffer: largeBuffer,
         } as CreateTransactionBufferArgs,
       } as CreateTransactionBufferInstructionArgs,
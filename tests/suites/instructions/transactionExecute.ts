@@ -0,0 +1,263 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import * as smartAccount from "@sqds/smart-account";
+import assert from "assert";
+import * as crypto from "crypto";
+import {
+  TestMembers,
+  createAutonomousSmartAccountV2,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateSmartAccountSigners,
+  getNextAccountIndex,
+  getTestProgramId,
+} from "../../utils";
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / transaction_execute", () => {
+  let members: TestMembers;
+  let settingsPda: PublicKey;
+  let vaultPda: PublicKey;
+
+  before(async () => {
+    members = await generateSmartAccountSigners(connection);
+    const accountIndex = await getNextAccountIndex(connection, programId);
+
+    [settingsPda] = await createAutonomousSmartAccountV2({
+      connection,
+      accountIndex,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector: null,
+      programId,
+    });
+    vaultPda = smartAccount.getSmartAccountPda({
+      settingsPda,
+      accountIndex: 0,
+      programId,
+    })[0];
+
+    // Airdrop some SOL to the vault
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  async function transferMessageBytes(payee: PublicKey) {
+    const message = new TransactionMessage({
+      payerKey: vaultPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [
+        createTestTransferInstruction(vaultPda, payee, 1 * LAMPORTS_PER_SOL),
+      ],
+    });
+
+    // Serialize the message. Must be done with this util function
+    const messageBytes =
+      smartAccount.utils.transactionMessageToMultisigTransactionMessageBytes({
+        message,
+        addressLookupTableAccounts: [],
+        smartAccountPda: vaultPda,
+      });
+
+    return messageBytes;
+  }
+
+  // Creates and approves a transaction storing only the hash of `messageBytes`.
+  async function createHashCommittedTransaction(
+    transactionIndex: bigint,
+    messageBytes: Uint8Array
+  ) {
+    const [transactionPda] = smartAccount.getTransactionPda({
+      settingsPda,
+      transactionIndex,
+      programId,
+    });
+    const messageHash = crypto
+      .createHash("sha256")
+      .update(messageBytes)
+      .digest();
+
+    const ix = smartAccount.generated.createCreateTransactionV2Instruction(
+      {
+        settings: settingsPda,
+        transaction: transactionPda,
+        creator: members.proposer.publicKey,
+        rentPayer: members.proposer.publicKey,
+        program: programId,
+      },
+      {
+        args: {
+          accountIndex: 0,
+          ephemeralSigners: 0,
+          // The message is only revealed at execution.
+          transactionMessage: new Uint8Array(),
+          memo: null,
+          compressMessage: false,
+          messageHash: Array.from(messageHash),
+        },
+      },
+      programId
+    );
+    const message = new TransactionMessage({
+      payerKey: members.proposer.publicKey,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [ix],
+    }).compileToV0Message();
+    const tx = new VersionedTransaction(message);
+    tx.sign([members.proposer]);
+    const signature = await connection.sendTransaction(tx);
+    await connection.confirmTransaction(signature);
+
+    await approveTransaction(transactionIndex);
+  }
+
+  async function approveTransaction(transactionIndex: bigint) {
+    let signature = await smartAccount.rpc.createProposal({
+      connection,
+      feePayer: members.proposer,
+      settingsPda,
+      transactionIndex,
+      creator: members.proposer,
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+
+    signature = await smartAccount.rpc.approveProposal({
+      connection,
+      feePayer: members.voter,
+      settingsPda,
+      transactionIndex,
+      signer: members.voter,
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+  }
+
+  it("execute a transaction created with only the hash of its message", async () => {
+    const transactionIndex = 1n;
+    const payee = Keypair.generate().publicKey;
+    const messageBytes = await transferMessageBytes(payee);
+    await createHashCommittedTransaction(transactionIndex, messageBytes);
+
+    const signature = await smartAccount.rpc.executeTransaction({
+      connection,
+      feePayer: members.executor,
+      settingsPda,
+      transactionIndex,
+      signer: members.executor.publicKey,
+      signers: [members.executor],
+      transactionMessage: messageBytes,
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+
+    assert.strictEqual(await connection.getBalance(payee), LAMPORTS_PER_SOL);
+    const [proposalPda] = smartAccount.getProposalPda({
+      settingsPda,
+      transactionIndex,
+      programId,
+    });
+    const proposalAccount =
+      await smartAccount.accounts.Proposal.fromAccountAddress(
+        connection,
+        proposalPda
+      );
+    assert.ok(
+      smartAccount.types.isProposalStatusExecuted(proposalAccount.status)
+    );
+  });
+
+  it("error: revealed message doesn't match the hash", async () => {
+    const transactionIndex = 2n;
+    const messageBytes = await transferMessageBytes(
+      Keypair.generate().publicKey
+    );
+    await createHashCommittedTransaction(transactionIndex, messageBytes);
+
+    // Same layout, another payee.
+    const otherMessageBytes = await transferMessageBytes(
+      Keypair.generate().publicKey
+    );
+
+    await assert.rejects(
+      () =>
+        smartAccount.rpc.executeTransaction({
+          connection,
+          feePayer: members.executor,
+          settingsPda,
+          transactionIndex,
+          signer: members.executor.publicKey,
+          signers: [members.executor],
+          transactionMessage: otherMessageBytes,
+          programId,
+        }),
+      /TransactionMessageHashMismatch/
+    );
+  });
+
+  it("error: message revealed for a transaction that stores it", async () => {
+    const transactionIndex = 3n;
+    const payee = Keypair.generate().publicKey;
+    const message = new TransactionMessage({
+      payerKey: vaultPda,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [
+        createTestTransferInstruction(vaultPda, payee, 1 * LAMPORTS_PER_SOL),
+      ],
+    });
+
+    let signature = await smartAccount.rpc.createTransaction({
+      connection,
+      feePayer: members.proposer,
+      settingsPda,
+      transactionIndex,
+      accountIndex: 0,
+      transactionMessage: message,
+      ephemeralSigners: 0,
+      creator: members.proposer.publicKey,
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+    await approveTransaction(transactionIndex);
+
+    const messageBytes = await transferMessageBytes(payee);
+    await assert.rejects(
+      () =>
+        smartAccount.rpc.executeTransaction({
+          connection,
+          feePayer: members.executor,
+          settingsPda,
+          transactionIndex,
+          signer: members.executor.publicKey,
+          signers: [members.executor],
+          transactionMessage: messageBytes,
+          programId,
+        }),
+      /TransactionMessageNotHashCommitted/
+    );
+
+    // Executed with the message it stores.
+    signature = await smartAccount.rpc.executeTransaction({
+      connection,
+      feePayer: members.executor,
+      settingsPda,
+      transactionIndex,
+      signer: members.executor.publicKey,
+      signers: [members.executor],
+      programId,
+    });
+    await connection.confirmTransaction(signature);
+    assert.strictEqual(await connection.getBalance(payee), LAMPORTS_PER_SOL);
+  });
+});
@@ -812,7 +812,7 @@ describe("Smart Account SDK", () => {
             rentPayer: feePayer,
             amount: BigInt(1000000000),
             settingsAuthority: members.voter,
-            period: smartAccount.generated.Period.Day,
+            period: { __kind: "Day" },
             mint: Keypair.generate().publicKey,
             destinations: [Keypair.generate().publicKey],
             signers: [members.almighty.publicKey],
@@ -836,7 +836,7 @@ describe("Smart Account SDK", () => {
             // Must be positive.
             amount: BigInt(0),
             settingsAuthority: members.almighty,
-            period: smartAccount.generated.Period.Day,
+            period: { __kind: "Day" },
             mint: Keypair.generate().publicKey,
             destinations: [Keypair.generate().publicKey],
             signers: [members.almighty.publicKey],
@@ -859,7 +859,7 @@ describe("Smart Account SDK", () => {
         rentPayer: feePayer,
         amount: BigInt(1000000000),
         settingsAuthority: members.almighty,
-        period: smartAccount.generated.Period.Day,
+        period: { __kind: "Day" },
         mint: Keypair.generate().publicKey,
         destinations: [Keypair.generate().publicKey],
         signers: [members.almighty.publicKey, nonMember.publicKey],
@@ -921,7 +921,7 @@ describe("Smart Account SDK", () => {
         rentPayer: feePayer,
         amount: BigInt(1000000000),
         settingsAuthority: members.almighty,
-        period: smartAccount.generated.Period.Day,
+        period: { __kind: "Day" },
         mint: Keypair.generate().publicKey,
         destinations: [Keypair.generate().publicKey],
         signers: [members.almighty.publicKey],
@@ -981,7 +981,7 @@ describe("Smart Account SDK", () => {
           rentPayer: feePayer,
           amount: BigInt(1000000000),
           settingsAuthority: members.almighty,
-          period: smartAccount.generated.Period.Day,
+          period: { __kind: "Day" },
           mint: Keypair.generate().publicKey,
           destinations: [Keypair.generate().publicKey],
           signers: [members.almighty.publicKey],